@@ -17,20 +17,40 @@ pub struct Account {
     droplet_limit: usize,
     /// The total number of floating IPs the user may have.
     floating_ip_limit: usize,
+    /// The total number of volumes the user may have.
+    volume_limit: usize,
     /// The email the user has registered for Digital Ocean with.
     email: String,
     /// The universal identifier for this user.
     uuid: String,
     /// If true, the user has verified their account via email. False otherwise.
     email_verified: bool,
-    /// This value is one of "active", "warning" or "locked".
-    status: String,
+    /// The status of the account.
+    status: AccountStatus,
     /// A human-readable message giving more details about the status of the
     /// account.
     status_message: String,
 }
 
+string_enum!(
+    /// The status of an [`Account`](struct.Account.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#account)
+    AccountStatus {
+        /// The account may be used.
+        Active => "active",
+        /// The account has an issue which needs attention, such as an unpaid balance.
+        Warning => "warning",
+        /// The account is locked, and resources may not be created.
+        Locked => "locked",
+    }
+);
+
 impl Account {
+    /// The account the API key belongs to. This is a cheap way of checking
+    /// that an API key is valid, and of checking the limits on resources
+    /// before creating them.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-user-information)
     pub fn get() -> AccountRequest<Get, Account> {
         let mut url = ROOT_URL.clone();
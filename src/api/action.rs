@@ -1,10 +1,19 @@
 use super::{ApiLinks, ApiMeta};
 use super::{HasPagination, HasResponse, HasValue};
+#[cfg(feature = "async")]
+use crate::client::AsyncHttpClient;
+use crate::client::HttpClient;
+use crate::error::ErrorKind;
 use crate::method::{Get, List};
 use crate::request::ActionRequest;
-use crate::request::Request;
+#[cfg(feature = "async")]
+use crate::request::ExecutableAsync;
+use crate::request::{Executable, Request};
+use crate::wait::{self, WaitPolicy};
+use crate::DigitalOcean;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use chrono::{DateTime, Utc};
+use failure::Error;
 use getset::{Getters, Setters};
 use url::Url;
 
@@ -30,11 +39,17 @@ pub struct Action {
     /// A unique identifier for each Droplet action event. This is used to
     /// reference a specific action that was requested.
     id: usize,
-    /// The current status of the action. The value of this attribute will be
-    /// "in-progress", "completed", or "errored".
-    status: String,
-    /// The type of action that the event is executing (reboot, power_off,
-    /// etc.).
+    /// The current status of the action.
+    status: ActionStatus,
+    /// This is the type of action that the object represents. For example,
+    /// this could be "transfer" to represent the state of an image transfer
+    /// action.
+    ///
+    /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+    #[serde(rename = "type")]
+    kind: ActionType,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the action was initiated.
     started_at: DateTime<Utc>,
     /// A time value given in ISO8601 combined date and time format that
     /// represents when the action was completed.
@@ -52,6 +67,79 @@ pub struct Action {
     region_slug: Option<String>,
 }
 
+identified_by!(Action, id);
+
+string_enum!(
+    /// The status of an [`Action`](struct.Action.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#actions)
+    ActionStatus {
+        /// The action has not finished yet.
+        InProgress => "in-progress",
+        /// The action finished successfully.
+        Completed => "completed",
+        /// The action failed.
+        Errored => "errored",
+    }
+);
+
+string_enum!(
+    /// The type of an [`Action`](struct.Action.html), such as `Reboot` for a
+    /// Droplet or `Transfer` for an image.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#actions)
+    ActionType {
+        /// A resource is being created.
+        Create => "create",
+        /// A resource is being destroyed.
+        Destroy => "destroy",
+        /// Backups are being enabled on a Droplet.
+        EnableBackups => "enable_backups",
+        /// Backups are being disabled on a Droplet.
+        DisableBackups => "disable_backups",
+        /// A Droplet is being rebooted.
+        Reboot => "reboot",
+        /// A Droplet is being power cycled.
+        PowerCycle => "power_cycle",
+        /// A Droplet is being shut down gracefully.
+        Shutdown => "shutdown",
+        /// A Droplet is being powered off.
+        PowerOff => "power_off",
+        /// A Droplet is being powered on.
+        PowerOn => "power_on",
+        /// A Droplet is being restored from a backup or snapshot.
+        Restore => "restore",
+        /// The root password of a Droplet is being reset.
+        PasswordReset => "password_reset",
+        /// A Droplet or volume is being resized.
+        Resize => "resize",
+        /// A Droplet is being rebuilt from an image.
+        Rebuild => "rebuild",
+        /// A Droplet is being renamed.
+        Rename => "rename",
+        /// The kernel of a Droplet is being changed.
+        ChangeKernel => "change_kernel",
+        /// IPv6 is being enabled on a Droplet.
+        EnableIpv6 => "enable_ipv6",
+        /// Private networking is being enabled on a Droplet.
+        EnablePrivateNetworking => "enable_private_networking",
+        /// A snapshot is being taken of a Droplet or volume.
+        Snapshot => "snapshot",
+        /// An image is being transferred to another region.
+        Transfer => "transfer",
+        /// A backup is being converted to a snapshot.
+        Convert => "convert",
+        /// A floating or reserved IP is being assigned to a Droplet.
+        AssignIp => "assign_ip",
+        /// A floating or reserved IP is being unassigned from a Droplet.
+        UnassignIp => "unassign_ip",
+        /// A volume is being attached to a Droplet.
+        Attach => "attach",
+        /// A volume is being detached from a Droplet.
+        Detach => "detach",
+    }
+);
+
 impl Action {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-action)
     pub fn get(id: usize) -> ActionRequest<Get, Action> {
@@ -63,6 +151,9 @@ impl Action {
 
         Request::new(url)
     }
+    /// Every action in the account, whichever resource it was taken on,
+    /// newest first.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-actions)
     pub fn list() -> ActionRequest<List, Vec<Action>> {
         let mut url = ROOT_URL.clone();
@@ -72,6 +163,53 @@ impl Action {
 
         Request::new(url)
     }
+    /// Polls the action until its status is "completed", returning the
+    /// completed action. Fails with `ErrorKind::ActionFailed` if the status
+    /// becomes "errored", or `ErrorKind::Timeout` if it is still in progress
+    /// when the policy's timeout runs out.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-action)
+    pub fn wait<C>(&self, instance: &DigitalOcean<C>, policy: WaitPolicy) -> Result<Action, Error>
+    where
+        C: HttpClient,
+    {
+        wait::poll(
+            self.clone(),
+            policy,
+            |action| Action::get(action.id).execute(instance),
+            Action::finished,
+        )
+    }
+    /// Polls the action without blocking the calling thread. This mirrors
+    /// [`wait`](#method.wait).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-action)
+    #[cfg(feature = "async")]
+    pub async fn wait_async<C>(
+        &self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<Action, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        wait::poll_async(
+            self.clone(),
+            policy,
+            |action| Action::get(action.id).execute_async(instance),
+            Action::finished,
+        )
+        .await
+    }
+
+    /// Whether the action has completed, failing if it errored.
+    fn finished(&self) -> Result<bool, Error> {
+        match self.status {
+            ActionStatus::Completed => Ok(true),
+            ActionStatus::Errored => Err(ErrorKind::ActionFailed(self.id).into()),
+            _ => Ok(false),
+        }
+    }
 }
 
 /// Response type returned from Digital Ocean.
@@ -95,8 +233,11 @@ impl HasResponse for Action {
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ActionListResponse {
     actions: Vec<Action>,
+    // Acting on tagged Droplets returns the actions without pagination.
+    #[serde(default)]
     links: ApiLinks,
-    meta: ApiMeta,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
 }
 
 impl HasResponse for Vec<Action> {
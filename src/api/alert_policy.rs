@@ -0,0 +1,364 @@
+use self::alert_policy_fields::{Alerts, SlackDetails};
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::AlertPolicyRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const MONITORING_SEGMENT: &str = "monitoring";
+const ALERTS_SEGMENT: &str = "alerts";
+
+/// Alert policies notify you by email or Slack when a metric of your
+/// Droplets, such as CPU or memory utilization, crosses a threshold for a
+/// period of time.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#monitoring)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct AlertPolicy {
+    /// A unique identifier generated for the alert policy.
+    uuid: String,
+    /// The metric the policy alerts on, such as "v1/insights/droplet/cpu".
+    ///
+    /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+    #[serde(rename = "type")]
+    kind: String,
+    /// A human-readable description of the alert policy.
+    description: String,
+    /// How the metric is compared to `value`. This may be "GreaterThan" or
+    /// "LessThan".
+    compare: String,
+    /// The threshold of the metric which triggers the alert.
+    value: f64,
+    /// The period of time the threshold must be crossed for, such as "5m",
+    /// "10m", "30m", or "1h".
+    window: String,
+    /// The IDs of the Droplets the policy applies to.
+    entities: Vec<String>,
+    /// The tags of the Droplets the policy applies to.
+    tags: Vec<String>,
+    /// Where notifications are sent when the alert triggers.
+    alerts: Alerts,
+    /// If true, notifications are sent when the alert triggers.
+    enabled: bool,
+}
+
+identified_by!(AlertPolicy, uuid);
+
+/// Fields which exists inside alert policies.
+pub mod alert_policy_fields {
+    /// This exists in the `alerts` field of an alert policy.
+    #[derive(Deserialize, Serialize, Debug, Clone, Default)]
+    pub struct Alerts {
+        /// The email addresses notified when the alert triggers.
+        #[serde(default)]
+        pub email: Vec<String>,
+        /// The Slack channels notified when the alert triggers.
+        #[serde(default)]
+        pub slack: Vec<SlackDetails>,
+    }
+
+    /// This exists in the `slack` field of an alert policy's alerts.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct SlackDetails {
+        /// The Slack channel to send notifications to, such as
+        /// "#production-alerts".
+        pub channel: String,
+        /// The Slack webhook URL notifications are sent with.
+        pub url: String,
+    }
+    impl SlackDetails {
+        pub fn new<S>(channel: S, url: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            SlackDetails {
+                channel: channel.as_ref().to_string(),
+                url: url.as_ref().to_string(),
+            }
+        }
+    }
+    impl<S> From<(S, S)> for SlackDetails
+    where
+        S: AsRef<str>,
+    {
+        fn from(val: (S, S)) -> Self {
+            SlackDetails::new(val.0, val.1)
+        }
+    }
+}
+
+impl AlertPolicy {
+    /// Creates an enabled alert policy without any notification targets or
+    /// Droplets, which are added by chaining `.email()`, `.slack()`,
+    /// `.entities()`, and `.tags()` onto this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-new-alert-policy)
+    pub fn create<S>(
+        kind: S,
+        description: S,
+        compare: S,
+        value: f64,
+        window: S,
+    ) -> AlertPolicyRequest<Create, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(MONITORING_SEGMENT)
+            .push(ALERTS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(policy_body(kind, description, compare, value, window));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-alert-policy)
+    pub fn get<S>(uuid: S) -> AlertPolicyRequest<Get, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(MONITORING_SEGMENT)
+            .push(ALERTS_SEGMENT)
+            .push(uuid.as_ref());
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-alert-policies)
+    pub fn list() -> AlertPolicyRequest<List, Vec<AlertPolicy>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(MONITORING_SEGMENT)
+            .push(ALERTS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// **Note:** The whole policy is replaced, so be sure to chain every
+    /// notification target and Droplet onto this as with `create()`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert-policy)
+    pub fn update<S>(
+        uuid: S,
+        kind: S,
+        description: S,
+        compare: S,
+        value: f64,
+        window: S,
+    ) -> AlertPolicyRequest<Update, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(MONITORING_SEGMENT)
+            .push(ALERTS_SEGMENT)
+            .push(uuid.as_ref());
+
+        let mut req = Request::new(url);
+        req.set_body(policy_body(kind, description, compare, value, window));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-an-alert-policy)
+    pub fn delete<S>(uuid: S) -> AlertPolicyRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(MONITORING_SEGMENT)
+            .push(ALERTS_SEGMENT)
+            .push(uuid.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl AlertPolicyRequest<Create, AlertPolicy> {
+    /// The IDs of the Droplets the policy applies to.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-new-alert-policy)
+    pub fn entities<S>(mut self, val: &[S]) -> AlertPolicyRequest<Create, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["entities"] = json!(val);
+        self
+    }
+    /// The tags of the Droplets the policy applies to.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-new-alert-policy)
+    pub fn tags<S>(mut self, val: &[S]) -> AlertPolicyRequest<Create, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["tags"] = json!(val);
+        self
+    }
+    /// The email addresses notified when the alert triggers.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-new-alert-policy)
+    pub fn email<S>(mut self, val: &[S]) -> AlertPolicyRequest<Create, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["alerts"]["email"] = json!(val);
+        self
+    }
+    /// Adds a Slack channel notified when the alert triggers. Accepts a
+    /// [`SlackDetails`](alert_policy_fields/struct.SlackDetails.html) or a
+    /// tuple matching `(channel, url)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-new-alert-policy)
+    pub fn slack<T>(mut self, val: T) -> AlertPolicyRequest<Create, AlertPolicy>
+    where
+        T: Into<SlackDetails>,
+    {
+        push_slack(self.body_mut(), val.into());
+        self
+    }
+    /// If false, the policy is created without sending notifications.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-new-alert-policy)
+    pub fn enabled(mut self, val: bool) -> AlertPolicyRequest<Create, AlertPolicy> {
+        self.body_mut()["enabled"] = json!(val);
+        self
+    }
+}
+
+impl AlertPolicyRequest<Update, AlertPolicy> {
+    /// The IDs of the Droplets the policy applies to.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert-policy)
+    pub fn entities<S>(mut self, val: &[S]) -> AlertPolicyRequest<Update, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["entities"] = json!(val);
+        self
+    }
+    /// The tags of the Droplets the policy applies to.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert-policy)
+    pub fn tags<S>(mut self, val: &[S]) -> AlertPolicyRequest<Update, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["tags"] = json!(val);
+        self
+    }
+    /// The email addresses notified when the alert triggers.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert-policy)
+    pub fn email<S>(mut self, val: &[S]) -> AlertPolicyRequest<Update, AlertPolicy>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["alerts"]["email"] = json!(val);
+        self
+    }
+    /// Adds a Slack channel notified when the alert triggers. Accepts a
+    /// [`SlackDetails`](alert_policy_fields/struct.SlackDetails.html) or a
+    /// tuple matching `(channel, url)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert-policy)
+    pub fn slack<T>(mut self, val: T) -> AlertPolicyRequest<Update, AlertPolicy>
+    where
+        T: Into<SlackDetails>,
+    {
+        push_slack(self.body_mut(), val.into());
+        self
+    }
+    /// If false, notifications are no longer sent when the alert triggers.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert-policy)
+    pub fn enabled(mut self, val: bool) -> AlertPolicyRequest<Update, AlertPolicy> {
+        self.body_mut()["enabled"] = json!(val);
+        self
+    }
+}
+
+/// The body shared by creating and updating a policy.
+fn policy_body<S>(kind: S, description: S, compare: S, value: f64, window: S) -> serde_json::Value
+where
+    S: AsRef<str> + Serialize + Display,
+{
+    json!({
+        "type": kind,
+        "description": description,
+        "compare": compare,
+        "value": value,
+        "window": window,
+        "entities": [],
+        "tags": [],
+        "alerts": {
+            "email": [],
+            "slack": [],
+        },
+        "enabled": true,
+    })
+}
+
+fn push_slack(body: &mut serde_json::Value, slack: SlackDetails) {
+    if !body["alerts"]["slack"].is_array() {
+        body["alerts"]["slack"] = json!([]);
+    }
+
+    body["alerts"]["slack"]
+        .as_array_mut()
+        .expect("slack should always be an array.")
+        .push(json!(slack));
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AlertPolicyResponse {
+    policy: AlertPolicy,
+}
+
+impl HasResponse for AlertPolicy {
+    type Response = AlertPolicyResponse;
+}
+
+impl HasValue for AlertPolicyResponse {
+    type Value = AlertPolicy;
+    fn value(self) -> AlertPolicy {
+        self.policy
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AlertPolicyListResponse {
+    policies: Vec<AlertPolicy>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<AlertPolicy> {
+    type Response = AlertPolicyListResponse;
+}
+
+impl HasPagination for AlertPolicyListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for AlertPolicyListResponse {
+    type Value = Vec<AlertPolicy>;
+    fn value(self) -> Vec<AlertPolicy> {
+        self.policies
+    }
+}
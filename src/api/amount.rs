@@ -0,0 +1,172 @@
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// The number of decimal places an `Amount` keeps.
+const PLACES: u32 = 6;
+const SCALE: i64 = 1_000_000;
+
+/// An amount of money in US dollars, such as a balance or a price.
+///
+/// The API sends amounts as decimal strings (such as `"23.44"`), which are
+/// kept exactly as a fixed-point number with six decimal places instead of
+/// being rounded into a float.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Amount {
+    micros: i64,
+}
+
+impl Amount {
+    /// The amount in millionths of a dollar.
+    pub fn from_micros(micros: i64) -> Self {
+        Amount { micros }
+    }
+    /// The amount in millionths of a dollar.
+    pub fn micros(&self) -> i64 {
+        self.micros
+    }
+    /// The amount in cents, rounded to the nearest cent (with halves rounded
+    /// away from zero).
+    pub fn cents(&self) -> i64 {
+        let per_cent = SCALE / 100;
+        let half = per_cent / 2;
+        if self.micros < 0 {
+            (self.micros - half) / per_cent
+        } else {
+            (self.micros + half) / per_cent
+        }
+    }
+}
+
+impl FromStr for Amount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid amount: {:?}", s);
+        let (negative, digits) = match s.trim() {
+            t if t.starts_with('-') => (true, &t[1..]),
+            t => (false, t),
+        };
+        let (whole, fraction) = match digits.find('.') {
+            Some(i) => (&digits[..i], &digits[i + 1..]),
+            None => (digits, ""),
+        };
+        if (whole.is_empty() && fraction.is_empty())
+            || fraction.len() > PLACES as usize
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let whole: i64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| invalid())?
+        };
+        let fraction: i64 = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse::<i64>().map_err(|_| invalid())?
+                * 10i64.pow(PLACES - fraction.len() as u32)
+        };
+        let micros = whole
+            .checked_mul(SCALE)
+            .and_then(|w| w.checked_add(fraction))
+            .ok_or_else(invalid)?;
+
+        Ok(Amount {
+            micros: if negative { -micros } else { micros },
+        })
+    }
+}
+
+impl Amount {
+    /// Parses a decimal (such as the shortest representation of a float, like
+    /// `"0.00743999984115362"` or `"1e-7"`), rounding places beyond the sixth
+    /// to the nearest micro-dollar (with halves rounded away from zero).
+    pub(crate) fn parse_rounded(s: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid amount: {:?}", s);
+        let (negative, number) = match s.trim() {
+            t if t.starts_with('-') => (true, &t[1..]),
+            t => (false, t),
+        };
+        let (mantissa, exponent) = match number.find(['e', 'E']) {
+            Some(i) => (
+                &number[..i],
+                number[i + 1..].parse::<i32>().map_err(|_| invalid())?,
+            ),
+            None => (number, 0),
+        };
+        let (whole, fraction) = match mantissa.find('.') {
+            Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+            None => (mantissa, ""),
+        };
+        let digits: Vec<u8> = whole.bytes().chain(fraction.bytes()).collect();
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(invalid());
+        }
+
+        // The digits which make up the whole number of micros, and the first
+        // digit after them, which decides the rounding.
+        let kept = whole.len() as i64 + i64::from(exponent) + i64::from(PLACES);
+        let mut micros: i64 = 0;
+        for i in 0..kept.max(0) {
+            let digit = digits.get(i as usize).map_or(0, |d| i64::from(d - b'0'));
+            micros = micros
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(digit))
+                .ok_or_else(invalid)?;
+        }
+        let round_up = kept >= 0 && matches!(digits.get(kept as usize), Some(d) if *d >= b'5');
+        if round_up {
+            micros = micros.checked_add(1).ok_or_else(invalid)?;
+        }
+
+        Ok(Amount {
+            micros: if negative { -micros } else { micros },
+        })
+    }
+}
+
+impl fmt::Display for Amount {
+    /// Formats with at least two decimal places, such as `"23.44"` or
+    /// `"0.00744"`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.micros < 0 { "-" } else { "" };
+        let micros = self.micros.unsigned_abs();
+        let scale = SCALE as u64;
+        let fraction = format!("{:06}", micros % scale);
+        let fraction = fraction.trim_end_matches('0');
+        write!(f, "{}{}.{:0<2}", sign, micros / scale, fraction)
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    /// Accepts either a decimal string or a JSON number. Numbers are floats,
+    /// so they are rounded to the nearest micro-dollar.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(s) => s.parse().map_err(de::Error::custom),
+            serde_json::Value::Number(n) => {
+                Amount::parse_rounded(&n.to_string()).map_err(de::Error::custom)
+            }
+            other => Err(de::Error::custom(format!("invalid amount: {}", other))),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use super::account::Account;
+use super::amount::Amount;
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Get, List};
+use crate::request::Request;
+use crate::request::{BalanceRequest, BillingEventRequest};
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use url::Url;
+
+const CUSTOMERS_SEGMENT: &str = "customers";
+const MY_SEGMENT: &str = "my";
+const BALANCE_SEGMENT: &str = "balance";
+const BILLING_HISTORY_SEGMENT: &str = "billing_history";
+
+/// The balance of the account for the current billing period.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#balance)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Balance {
+    /// The balance as of `generated_at`, including usage and invoices in the
+    /// current billing period.
+    month_to_date_balance: Amount,
+    /// The balance of the account as of the last invoice, after payments.
+    account_balance: Amount,
+    /// The charges for usage in the current billing period.
+    #[serde(default)]
+    month_to_date_usage: Amount,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the balance was generated.
+    generated_at: DateTime<Utc>,
+}
+
+/// An entry in the billing history of the account, such as an invoice or a
+/// payment.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#billing-history)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct BillingEvent {
+    /// A description of the entry, such as "Invoice for May 2018".
+    description: String,
+    /// The amount of the entry. Payments and credits are negative.
+    amount: Amount,
+    /// The id of the invoice, for invoice entries.
+    #[serde(default)]
+    invoice_id: Option<String>,
+    /// The UUID of the invoice, for invoice entries.
+    #[serde(default)]
+    invoice_uuid: Option<String>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the entry occurred.
+    date: DateTime<Utc>,
+    /// The kind of entry. This may be "ACHFailure", "Adjustment",
+    /// "AttemptFailed", "Chargeback", "Credit", "CreditExpiration",
+    /// "Invoice", "Payment", "Refund", or "Reversal".
+    ///
+    /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+impl Account {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-customer-balance)
+    pub fn balance() -> BalanceRequest<Get, Balance> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CUSTOMERS_SEGMENT)
+            .push(MY_SEGMENT)
+            .push(BALANCE_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-billing-history)
+    pub fn billing_history() -> BillingEventRequest<List, Vec<BillingEvent>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CUSTOMERS_SEGMENT)
+            .push(MY_SEGMENT)
+            .push(BILLING_HISTORY_SEGMENT);
+
+        Request::new(url)
+    }
+}
+
+// The balance is not wrapped in an object.
+impl HasResponse for Balance {
+    type Response = Balance;
+}
+
+impl HasValue for Balance {
+    type Value = Balance;
+    fn value(self) -> Balance {
+        self
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BillingEventListResponse {
+    billing_history: Vec<BillingEvent>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<BillingEvent> {
+    type Response = BillingEventListResponse;
+}
+
+impl HasPagination for BillingEventListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for BillingEventListResponse {
+    type Value = Vec<BillingEvent>;
+    fn value(self) -> Vec<BillingEvent> {
+        self.billing_history
+    }
+}
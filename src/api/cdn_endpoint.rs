@@ -0,0 +1,257 @@
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::CdnEndpointRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const CDN_SEGMENT: &str = "cdn";
+const ENDPOINTS_SEGMENT: &str = "endpoints";
+const CACHE_SEGMENT: &str = "cache";
+
+/// Content hosted in DigitalOcean's object storage solution, Spaces, can
+/// optionally be served by a globally distributed Content Delivery Network
+/// (CDN). Each CDN endpoint serves the content of a single Space origin.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#cdn-endpoints)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct CdnEndpoint {
+    /// A unique ID that can be used to identify and reference a CDN
+    /// endpoint.
+    id: String,
+    /// The fully qualified domain name (FQDN) for the origin server which
+    /// provides the content for the CDN. This is currently restricted to a
+    /// Space.
+    origin: String,
+    /// The fully qualified domain name (FQDN) from which the CDN-backed
+    /// content is served.
+    endpoint: String,
+    /// The amount of time the content is cached by the CDN's edge servers
+    /// in seconds.
+    ttl: usize,
+    /// The ID of a DigitalOcean managed TLS certificate used for SSL when a
+    /// custom subdomain is provided.
+    #[serde(default)]
+    certificate_id: Option<String>,
+    /// The fully qualified domain name (FQDN) of the custom subdomain used
+    /// with the CDN endpoint.
+    #[serde(default)]
+    custom_domain: Option<String>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the CDN endpoint was created.
+    created_at: DateTime<Utc>,
+}
+
+identified_by!(CdnEndpoint, id);
+
+impl CdnEndpoint {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-cdn-endpoint)
+    pub fn create<S>(origin: S) -> CdnEndpointRequest<Create, CdnEndpoint>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CDN_SEGMENT)
+            .push(ENDPOINTS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "origin": origin,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-cdn-endpoint)
+    pub fn get<S>(id: S) -> CdnEndpointRequest<Get, CdnEndpoint>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CDN_SEGMENT)
+            .push(ENDPOINTS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-cdn-endpoints)
+    pub fn list() -> CdnEndpointRequest<List, Vec<CdnEndpoint>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CDN_SEGMENT)
+            .push(ENDPOINTS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-cdn-endpoint)
+    pub fn update<S>(id: S) -> CdnEndpointRequest<Update, CdnEndpoint>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CDN_SEGMENT)
+            .push(ENDPOINTS_SEGMENT)
+            .push(id.as_ref());
+
+        let mut req = Request::new(url);
+        req.set_body(json!({}));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-cdn-endpoint)
+    pub fn delete<S>(id: S) -> CdnEndpointRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CDN_SEGMENT)
+            .push(ENDPOINTS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl CdnEndpointRequest<Create, CdnEndpoint> {
+    /// The amount of time the content is cached by the CDN's edge servers in
+    /// seconds. This may be 60, 600, 3600, 86400, or 604800. Defaults to
+    /// 3600 (one hour) when excluded.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-cdn-endpoint)
+    pub fn ttl(mut self, ttl: usize) -> CdnEndpointRequest<Create, CdnEndpoint> {
+        self.body_mut()["ttl"] = json!(ttl);
+        self
+    }
+    /// The ID of a DigitalOcean managed TLS certificate used for SSL when a
+    /// custom subdomain is provided.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-cdn-endpoint)
+    pub fn certificate_id<S>(mut self, id: S) -> CdnEndpointRequest<Create, CdnEndpoint>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["certificate_id"] = json!(id);
+        self
+    }
+    /// The fully qualified domain name (FQDN) of the custom subdomain used
+    /// with the CDN endpoint. When used, a `certificate_id` must be provided.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-cdn-endpoint)
+    pub fn custom_domain<S>(mut self, domain: S) -> CdnEndpointRequest<Create, CdnEndpoint>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["custom_domain"] = json!(domain);
+        self
+    }
+}
+
+impl CdnEndpointRequest<Update, CdnEndpoint> {
+    /// The amount of time the content is cached by the CDN's edge servers in
+    /// seconds. This may be 60, 600, 3600, 86400, or 604800.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-cdn-endpoint)
+    pub fn ttl(mut self, ttl: usize) -> CdnEndpointRequest<Update, CdnEndpoint> {
+        self.body_mut()["ttl"] = json!(ttl);
+        self
+    }
+    /// The ID of a DigitalOcean managed TLS certificate used for SSL when a
+    /// custom subdomain is provided.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-cdn-endpoint)
+    pub fn certificate_id<S>(mut self, id: S) -> CdnEndpointRequest<Update, CdnEndpoint>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["certificate_id"] = json!(id);
+        self
+    }
+    /// The fully qualified domain name (FQDN) of the custom subdomain used
+    /// with the CDN endpoint. An empty string removes the custom subdomain.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-cdn-endpoint)
+    pub fn custom_domain<S>(mut self, domain: S) -> CdnEndpointRequest<Update, CdnEndpoint>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["custom_domain"] = json!(domain);
+        self
+    }
+}
+
+impl CdnEndpointRequest<Get, CdnEndpoint> {
+    /// Purge cached content from the CDN endpoint. Accepts paths to the
+    /// files to purge, which may include a wildcard such as "path/to/css/*",
+    /// or "*" to purge all content.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#purge-the-cache-for-an-existing-cdn-endpoint)
+    pub fn purge_cache<S>(mut self, files: &[S]) -> CdnEndpointRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CACHE_SEGMENT);
+
+        self.set_body(json!({
+            "files": files,
+        }));
+
+        self.transmute()
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CdnEndpointResponse {
+    endpoint: CdnEndpoint,
+}
+
+impl HasResponse for CdnEndpoint {
+    type Response = CdnEndpointResponse;
+}
+
+impl HasValue for CdnEndpointResponse {
+    type Value = CdnEndpoint;
+    fn value(self) -> CdnEndpoint {
+        self.endpoint
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CdnEndpointListResponse {
+    endpoints: Vec<CdnEndpoint>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<CdnEndpoint> {
+    type Response = CdnEndpointListResponse;
+}
+
+impl HasPagination for CdnEndpointListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for CdnEndpointListResponse {
+    type Value = Vec<CdnEndpoint>;
+    fn value(self) -> Vec<CdnEndpoint> {
+        self.endpoints
+    }
+}
@@ -6,6 +6,7 @@ use crate::request::Request;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use chrono::{DateTime, Utc};
 use getset::{Getters, Setters};
+use serde::de::{self, Deserialize, Deserializer};
 use serde::Serialize;
 use std::fmt::Display;
 use url::Url;
@@ -25,17 +26,94 @@ pub struct Certificate {
     /// A unique human-readable name referring to a certificate.
     name: String,
     /// A time value given in ISO8601 combined date and time format that
-    /// represents the certificate's expiration date.
-    not_after: DateTime<Utc>,
+    /// represents the certificate's expiration date. This is not known until
+    /// a Let's Encrypt certificate has been issued.
+    #[serde(default, deserialize_with = "deserialize_not_after")]
+    not_after: Option<DateTime<Utc>>,
     /// A unique identifier generated from the SHA-1 fingerprint of the
     /// certificate.
+    #[serde(default)]
     sha1_fingerprint: String,
+    /// The current state of the certificate. This is `Unknown("")` if the
+    /// API did not return one.
+    #[serde(default)]
+    state: CertificateState,
+    /// The type of the certificate, either a user-uploaded certificate or one
+    /// automatically generated with Let's Encrypt. This is `Unknown("")` if
+    /// the API did not return one.
+    ///
+    /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+    #[serde(default, rename = "type")]
+    kind: CertificateType,
+    /// An array of fully qualified domain names (FQDNs) for which the
+    /// certificate was issued.
+    #[serde(default)]
+    dns_names: Vec<String>,
     /// A time value given in ISO8601 combined date and time format that
     /// represents when the certificate was created.
     created_at: DateTime<Utc>,
 }
 
+identified_by!(Certificate, id);
+
+string_enum!(
+    /// The state of a [`Certificate`](struct.Certificate.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#certificates)
+    CertificateState {
+        /// The certificate is being issued.
+        Pending => "pending",
+        /// The certificate has been issued and may be used.
+        Verified => "verified",
+        /// Issuing the certificate failed.
+        Error => "error",
+    }
+);
+
+// The API may omit the state of a certificate, which is kept as an empty
+// string as it was before the state was an enum.
+impl Default for CertificateState {
+    fn default() -> Self {
+        CertificateState::Unknown(String::new())
+    }
+}
+
+string_enum!(
+    /// The type of a [`Certificate`](struct.Certificate.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#certificates)
+    CertificateType {
+        /// The certificate was uploaded by the user.
+        Custom => "custom",
+        /// The certificate is generated automatically with Let's Encrypt.
+        LetsEncrypt => "lets_encrypt",
+    }
+);
+
+// The API may omit the type of a certificate, which is kept as an empty string
+// as it was before the type was an enum.
+impl Default for CertificateType {
+    fn default() -> Self {
+        CertificateType::Unknown(String::new())
+    }
+}
+
+// Let's Encrypt certificates which are still pending have no expiration date,
+// which is returned as an empty string.
+fn deserialize_not_after<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(ref date) if !date.is_empty() => date.parse().map(Some).map_err(de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
 impl Certificate {
+    /// Creates a custom certificate from a user-provided private key and
+    /// leaf certificate.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-certificate)
     pub fn create<S>(
         name: S,
@@ -61,6 +139,33 @@ impl Certificate {
         req
     }
 
+    /// Creates a certificate which is automatically generated, and renewed,
+    /// by Let's Encrypt. The domains must be managed using DigitalOcean's DNS.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-certificate)
+    pub fn create_lets_encrypt<S>(
+        name: S,
+        dns_names: Vec<String>,
+    ) -> CertificateRequest<Create, Certificate>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CERTIFICATES_SEGMENT);
+
+        let mut req = Request::new(url);
+
+        req.set_body(json!({
+            "name": name,
+            "type": "lets_encrypt",
+            "dns_names": dns_names,
+        }));
+
+        req
+    }
+
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-certificates)
     pub fn list() -> CertificateRequest<List, Vec<Certificate>> {
         let mut url = ROOT_URL.clone();
@@ -1,3 +1,4 @@
+use super::image::{Image, ImageType};
 use super::{HasResponse, HasValue};
 use crate::method::Create;
 use crate::request::CustomImageRequest;
@@ -17,6 +18,7 @@ const IMAGES_SEGMENT: &str = "images";
 ///
 /// [Digital Ocean Documentation.](https://www.digitalocean.com/docs/images/custom-images/)
 #[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
 pub struct CustomImage {
     /// A unique number that can be used to identify and reference a specific
     /// image.
@@ -26,12 +28,13 @@ pub struct CustomImage {
     /// in question.
     name: String,
     /// The kind of image, describing the duration of how long the image is
-    /// stored. This is either "snapshot" or "backup".
+    /// stored.
     ///
     /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
     #[serde(rename = "type")]
-    kind: String, // 'type' is reserved in Rust.
+    kind: ImageType, // 'type' is reserved in Rust.
     /// This attribute describes the base distribution used for this image.
+    #[serde(default)]
     distribution: String,
     /// This attribute is an array of the regions that the image is available
     /// in. The regions are represented by their identifying slug values.
@@ -43,11 +46,33 @@ pub struct CustomImage {
     /// represents when the Image was created.
     created_at: DateTime<Utc>,
     /// A brief description about the image
+    #[serde(default)]
     description: String,
-    /// The status of the image
-    status: String,
+    /// The status of importing the image.
+    status: CustomImageStatus,
+    /// A description of why importing the image failed, if it did.
+    #[serde(default)]
+    error_message: String,
 }
 
+identified_by!(CustomImage, id);
+
+string_enum!(
+    /// The status of importing a [`CustomImage`](struct.CustomImage.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#custom-images)
+    CustomImageStatus {
+        /// The image has not been imported yet.
+        New => "NEW",
+        /// The image is being imported.
+        Pending => "pending",
+        /// The image was imported and may be used.
+        Available => "available",
+        /// The image has been deleted.
+        Deleted => "deleted",
+    }
+);
+
 impl CustomImage {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-custom-image)
     pub fn create<S>(
@@ -58,6 +83,27 @@ impl CustomImage {
         desc: S,
         tags: Vec<S>,
     ) -> CustomImageRequest<Create, CustomImage>
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        Image::create_custom(name, image_url, region)
+            .distribution(distribution)
+            .description(desc)
+            .tags(tags)
+    }
+}
+
+impl Image {
+    /// Imports a custom image from `image_url`, which is made available in
+    /// `region` once its `status` becomes "available". The other attributes
+    /// are optional, and may be set by chaining onto this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-custom-image)
+    pub fn create_custom<S>(
+        name: S,
+        image_url: S,
+        region: S,
+    ) -> CustomImageRequest<Create, CustomImage>
     where
         S: AsRef<str> + Display + Serialize,
     {
@@ -71,14 +117,44 @@ impl CustomImage {
             "name": name,
             "url": image_url,
             "region": region,
-            "distribution": distribution,
-            "description": desc,
-            "tags": tags
         }));
         req
     }
 }
 
+impl CustomImageRequest<Create, CustomImage> {
+    /// The base distribution of the image, such as "Ubuntu" or "Arch Linux".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-custom-image)
+    pub fn distribution<S>(mut self, val: S) -> CustomImageRequest<Create, CustomImage>
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        self.body_mut()["distribution"] = json!(val);
+        self
+    }
+    /// A free-form description of the image.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-custom-image)
+    pub fn description<S>(mut self, val: S) -> CustomImageRequest<Create, CustomImage>
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        self.body_mut()["description"] = json!(val);
+        self
+    }
+    /// Tags to apply to the image.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-custom-image)
+    pub fn tags<S>(mut self, val: Vec<S>) -> CustomImageRequest<Create, CustomImage>
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        self.body_mut()["tags"] = json!(val);
+        self
+    }
+}
+
 /// Response type returned from Digital Ocean.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct CustomImageResponse {
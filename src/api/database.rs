@@ -0,0 +1,366 @@
+use self::database_fields::{Connection, MaintenanceWindow};
+use super::{collect_strings, HasPagination, HasResponse, HasValue};
+use super::{ApiLinks, ApiMeta};
+use crate::method::{Create, Delete, Get, List};
+use crate::request::DatabaseClusterRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const DATABASES_SEGMENT: &str = "databases";
+
+/// A managed database cluster.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#databases)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct DatabaseCluster {
+    /// A unique ID that can be used to identify and reference a database
+    /// cluster.
+    id: String,
+    /// A unique, human-readable name referring to a database cluster.
+    name: String,
+    /// A slug representing the database engine used for the cluster. The
+    /// possible values are: "pg" for PostgreSQL, "mysql" for MySQL, "redis"
+    /// for Redis, and "mongodb" for MongoDB.
+    engine: String,
+    /// A string representing the version of the database engine in use for
+    /// the cluster.
+    version: String,
+    /// Details required to connect to the database cluster over the public
+    /// internet.
+    connection: Connection,
+    /// Details required to connect to the database cluster from within the
+    /// cluster's VPC.
+    private_connection: Option<Connection>,
+    /// The users of the database cluster.
+    #[serde(default)]
+    users: Option<Vec<DatabaseUser>>,
+    /// The names of the databases in the cluster.
+    #[serde(default)]
+    db_names: Option<Vec<String>>,
+    /// The number of nodes in the database cluster.
+    num_nodes: usize,
+    /// The slug identifier representing the size of the nodes in the
+    /// database cluster.
+    size: String,
+    /// The slug identifier for the region where the database cluster is
+    /// located.
+    region: String,
+    /// The current status of the database cluster.
+    status: DatabaseStatus,
+    /// The ID of the VPC where the database cluster is located.
+    #[serde(default)]
+    private_network_uuid: String,
+    /// The window during which the cluster may be automatically updated.
+    maintenance_window: Option<MaintenanceWindow>,
+    /// The tags applied to the database cluster.
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the database cluster was created.
+    created_at: DateTime<Utc>,
+}
+
+identified_by!(DatabaseCluster, id);
+
+string_enum!(
+    /// The status of a [`DatabaseCluster`](struct.DatabaseCluster.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#databases)
+    DatabaseStatus {
+        /// The cluster is being created.
+        Creating => "creating",
+        /// The cluster is running.
+        Online => "online",
+        /// The cluster is being resized.
+        Resizing => "resizing",
+        /// The cluster is being migrated to another region.
+        Migrating => "migrating",
+        /// The cluster is being forked from another cluster.
+        Forking => "forking",
+    }
+);
+
+/// A user of a database cluster.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#databases)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct DatabaseUser {
+    /// The name of the database user.
+    name: String,
+    /// A string representing the database user's role. The value will be
+    /// either "primary" or "normal".
+    role: String,
+    /// A randomly generated password for the database user.
+    #[serde(default)]
+    password: String,
+}
+
+/// A logical database within a database cluster.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#databases)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Database {
+    /// The name of the database.
+    name: String,
+}
+
+/// A PgBouncer connection pool of a PostgreSQL database cluster.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#databases)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct ConnectionPool {
+    /// A unique name for the connection pool.
+    name: String,
+    /// The PgBouncer transaction mode for the connection pool. This may be
+    /// "session", "transaction", or "statement".
+    mode: String,
+    /// The desired size of the PgBouncer connection pool.
+    size: usize,
+    /// The database for use with the connection pool.
+    db: String,
+    /// The name of the user for use with the connection pool.
+    user: String,
+    /// Details required to connect to the connection pool over the public
+    /// internet.
+    connection: Connection,
+    /// Details required to connect to the connection pool from within the
+    /// cluster's VPC.
+    private_connection: Option<Connection>,
+}
+
+/// Fields which exists inside database clusters.
+pub mod database_fields {
+    use chrono::{DateTime, Utc};
+
+    /// This exists in the `connection` and `private_connection` fields of a
+    /// database cluster.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Connection {
+        /// A connection string in the format accepted by the `psql` command,
+        /// including the password.
+        pub uri: String,
+        /// The name of the default database.
+        #[serde(default)]
+        pub database: String,
+        /// The FQDN pointing to the database cluster's current primary node.
+        pub host: String,
+        /// The port on which the database cluster is listening.
+        pub port: u16,
+        /// The default user for the database.
+        pub user: String,
+        /// The randomly generated password for the default user.
+        pub password: String,
+        /// Whether the connection requires SSL.
+        pub ssl: bool,
+    }
+
+    /// This exists in the `maintenance_window` field of a database cluster.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct MaintenanceWindow {
+        /// The day of the week on which to apply maintenance updates.
+        pub day: String,
+        /// The hour in UTC at which maintenance updates will be applied, in
+        /// 24 hour format, such as "16:00:00".
+        pub hour: String,
+        /// Whether any maintenance updates are pending.
+        #[serde(default)]
+        pub pending: bool,
+        /// Descriptions of the pending maintenance updates.
+        #[serde(default)]
+        pub description: Vec<String>,
+    }
+
+    /// A rule of the firewall (trusted sources) of a database cluster.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct FirewallRule {
+        /// A unique ID for the firewall rule itself.
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        pub uuid: String,
+        /// A unique ID for the database cluster to which the rule is applied.
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        pub cluster_uuid: String,
+        /// The type of resource that the firewall rule allows to access the
+        /// database cluster. This may be "droplet", "k8s", "ip_addr", or
+        /// "tag".
+        ///
+        /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+        #[serde(rename = "type")]
+        pub kind: String,
+        /// The ID of the specific resource, the name of a tag applied to a
+        /// group of resources, or the IP address that the firewall rule
+        /// allows to access the database cluster.
+        pub value: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub created_at: Option<DateTime<Utc>>,
+    }
+    impl FirewallRule {
+        pub fn new<S>(kind: S, value: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            FirewallRule {
+                uuid: String::new(),
+                cluster_uuid: String::new(),
+                kind: kind.as_ref().to_string(),
+                value: value.as_ref().to_string(),
+                created_at: None,
+            }
+        }
+    }
+    impl<S> From<(S, S)> for FirewallRule
+    where
+        S: AsRef<str>,
+    {
+        fn from(val: (S, S)) -> Self {
+            FirewallRule::new(val.0, val.1)
+        }
+    }
+}
+
+impl DatabaseCluster {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-database-cluster)
+    pub fn create<S>(
+        name: S,
+        engine: S,
+        version: S,
+        size: S,
+        region: S,
+        num_nodes: usize,
+    ) -> DatabaseClusterRequest<Create, DatabaseCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DATABASES_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "engine": engine,
+            "version": version,
+            "size": size,
+            "region": region,
+            "num_nodes": num_nodes,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-database-cluster)
+    pub fn get<S>(id: S) -> DatabaseClusterRequest<Get, DatabaseCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DATABASES_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-database-clusters)
+    pub fn list() -> DatabaseClusterRequest<List, Vec<DatabaseCluster>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DATABASES_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#destroy-a-database-cluster)
+    pub fn delete<S>(id: S) -> DatabaseClusterRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DATABASES_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl DatabaseClusterRequest<Create, DatabaseCluster> {
+    /// The tags to apply to the database cluster. Accepts any collection of `&str` or
+    /// `String`, such as a slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-database-cluster)
+    pub fn tags<I, S>(mut self, tags: I) -> DatabaseClusterRequest<Create, DatabaseCluster>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(tags));
+        self
+    }
+    /// The ID of the VPC where the database cluster will be located.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-database-cluster)
+    pub fn private_network<S>(
+        mut self,
+        private_network_uuid: S,
+    ) -> DatabaseClusterRequest<Create, DatabaseCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["private_network_uuid"] = json!(private_network_uuid);
+        self
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatabaseClusterResponse {
+    database: DatabaseCluster,
+}
+
+impl HasResponse for DatabaseCluster {
+    type Response = DatabaseClusterResponse;
+}
+
+impl HasValue for DatabaseClusterResponse {
+    type Value = DatabaseCluster;
+    fn value(self) -> DatabaseCluster {
+        self.database
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatabaseClusterListResponse {
+    databases: Vec<DatabaseCluster>,
+    // Database clusters are not paginated, but may be in the future.
+    #[serde(default)]
+    links: ApiLinks,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+impl HasResponse for Vec<DatabaseCluster> {
+    type Response = DatabaseClusterListResponse;
+}
+
+impl HasPagination for DatabaseClusterListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for DatabaseClusterListResponse {
+    type Value = Vec<DatabaseCluster>;
+    fn value(self) -> Vec<DatabaseCluster> {
+        self.databases
+    }
+}
@@ -0,0 +1,343 @@
+use super::database::{ConnectionPool, Database, DatabaseCluster, DatabaseUser};
+use super::database_fields::FirewallRule;
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::{
+    ConnectionPoolRequest, DatabaseClusterRequest, DatabaseRequest, DatabaseUserRequest,
+};
+use crate::STATIC_URL_ERROR;
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const USERS_SEGMENT: &str = "users";
+const DBS_SEGMENT: &str = "dbs";
+const POOLS_SEGMENT: &str = "pools";
+const FIREWALL_SEGMENT: &str = "firewall";
+
+impl DatabaseClusterRequest<Get, DatabaseCluster> {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-database-users)
+    pub fn users(mut self) -> DatabaseUserRequest<List, Vec<DatabaseUser>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(USERS_SEGMENT);
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-database-user)
+    pub fn user<S>(mut self, name: S) -> DatabaseUserRequest<Get, DatabaseUser>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(USERS_SEGMENT)
+            .push(name.as_ref());
+
+        self.transmute()
+    }
+    /// The returned user includes its randomly generated password.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#add-a-database-user)
+    pub fn create_user<S>(mut self, name: S) -> DatabaseUserRequest<Create, DatabaseUser>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(USERS_SEGMENT);
+
+        self.set_body(json!({
+            "name": name,
+        }));
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#remove-a-database-user)
+    pub fn delete_user<S>(mut self, name: S) -> DatabaseUserRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(USERS_SEGMENT)
+            .push(name.as_ref());
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-databases)
+    pub fn databases(mut self) -> DatabaseRequest<List, Vec<Database>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DBS_SEGMENT);
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#add-a-new-database)
+    pub fn create_database<S>(mut self, name: S) -> DatabaseRequest<Create, Database>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DBS_SEGMENT);
+
+        self.set_body(json!({
+            "name": name,
+        }));
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-database)
+    pub fn delete_database<S>(mut self, name: S) -> DatabaseRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DBS_SEGMENT)
+            .push(name.as_ref());
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-connection-pools-(postgresql))
+    pub fn pools(mut self) -> ConnectionPoolRequest<List, Vec<ConnectionPool>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(POOLS_SEGMENT);
+
+        self.transmute()
+    }
+    /// The `mode` may be "session", "transaction", or "statement".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#add-a-new-connection-pool-(postgresql))
+    pub fn create_pool<S>(
+        mut self,
+        name: S,
+        mode: S,
+        size: usize,
+        db: S,
+        user: S,
+    ) -> ConnectionPoolRequest<Create, ConnectionPool>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(POOLS_SEGMENT);
+
+        self.set_body(json!({
+            "name": name,
+            "mode": mode,
+            "size": size,
+            "db": db,
+            "user": user,
+        }));
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-connection-pool-(postgresql))
+    pub fn delete_pool<S>(mut self, name: S) -> ConnectionPoolRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(POOLS_SEGMENT)
+            .push(name.as_ref());
+
+        self.transmute()
+    }
+    /// The firewall (trusted sources) rules of the database cluster.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-firewall-rules-(trusted-sources)-for-a-database-cluster)
+    pub fn firewall_rules(mut self) -> DatabaseClusterRequest<Get, Vec<FirewallRule>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(FIREWALL_SEGMENT);
+
+        self.transmute()
+    }
+    /// **Note:** This replaces the entire set of firewall (trusted sources)
+    /// rules of the database cluster. Any existing rule which is not
+    /// included will be removed, and an empty set removes all of them.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-firewall-rules-(trusted-sources)-for-a-database)
+    pub fn set_firewall_rules<T>(mut self, rules: Vec<T>) -> DatabaseClusterRequest<Update, ()>
+    where
+        T: Into<FirewallRule>,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(FIREWALL_SEGMENT);
+
+        let rules: Vec<FirewallRule> = rules.into_iter().map(Into::into).collect();
+        self.set_body(json!({
+            "rules": rules,
+        }));
+
+        self.transmute()
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatabaseUserResponse {
+    user: DatabaseUser,
+}
+
+impl HasResponse for DatabaseUser {
+    type Response = DatabaseUserResponse;
+}
+
+impl HasValue for DatabaseUserResponse {
+    type Value = DatabaseUser;
+    fn value(self) -> DatabaseUser {
+        self.user
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatabaseUserListResponse {
+    users: Vec<DatabaseUser>,
+    #[serde(default)]
+    links: ApiLinks,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+impl HasResponse for Vec<DatabaseUser> {
+    type Response = DatabaseUserListResponse;
+}
+
+impl HasPagination for DatabaseUserListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for DatabaseUserListResponse {
+    type Value = Vec<DatabaseUser>;
+    fn value(self) -> Vec<DatabaseUser> {
+        self.users
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatabaseResponse {
+    db: Database,
+}
+
+impl HasResponse for Database {
+    type Response = DatabaseResponse;
+}
+
+impl HasValue for DatabaseResponse {
+    type Value = Database;
+    fn value(self) -> Database {
+        self.db
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatabaseListResponse {
+    dbs: Vec<Database>,
+    #[serde(default)]
+    links: ApiLinks,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+impl HasResponse for Vec<Database> {
+    type Response = DatabaseListResponse;
+}
+
+impl HasPagination for DatabaseListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for DatabaseListResponse {
+    type Value = Vec<Database>;
+    fn value(self) -> Vec<Database> {
+        self.dbs
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ConnectionPoolResponse {
+    pool: ConnectionPool,
+}
+
+impl HasResponse for ConnectionPool {
+    type Response = ConnectionPoolResponse;
+}
+
+impl HasValue for ConnectionPoolResponse {
+    type Value = ConnectionPool;
+    fn value(self) -> ConnectionPool {
+        self.pool
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ConnectionPoolListResponse {
+    pools: Vec<ConnectionPool>,
+    #[serde(default)]
+    links: ApiLinks,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+impl HasResponse for Vec<ConnectionPool> {
+    type Response = ConnectionPoolListResponse;
+}
+
+impl HasPagination for ConnectionPoolListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for ConnectionPoolListResponse {
+    type Value = Vec<ConnectionPool>;
+    fn value(self) -> Vec<ConnectionPool> {
+        self.pools
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct FirewallRuleListResponse {
+    rules: Vec<FirewallRule>,
+}
+
+impl HasResponse for Vec<FirewallRule> {
+    type Response = FirewallRuleListResponse;
+}
+
+impl HasValue for FirewallRuleListResponse {
+    type Value = Vec<FirewallRule>;
+    fn value(self) -> Vec<FirewallRule> {
+        self.rules
+    }
+}
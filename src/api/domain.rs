@@ -1,9 +1,17 @@
 use super::{ApiLinks, ApiMeta};
 use super::{HasPagination, HasResponse, HasValue};
+#[cfg(feature = "async")]
+use crate::client::AsyncHttpClient;
+use crate::client::HttpClient;
+use crate::error::ErrorKind;
 use crate::method::{Create, Delete, Get, List};
 use crate::request::DomainRequest;
-use crate::request::Request;
+#[cfg(feature = "async")]
+use crate::request::ExecutableAsync;
+use crate::request::{Executable, Request};
+use crate::DigitalOcean;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
+use failure::Error;
 use getset::{Getters, Setters};
 use serde::Serialize;
 use std::fmt::Display;
@@ -34,7 +42,12 @@ pub struct Domain {
     zone_file: Option<String>,
 }
 
+identified_by!(Domain, name);
+
 impl Domain {
+    /// Creates the domain along with an A record pointing its apex at
+    /// `ip_address` (ex: the public address of a Droplet), in a single call.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-domain)
     pub fn create<N, I>(name: N, ip_address: I) -> DomainRequest<Create, Domain>
     where
@@ -93,6 +106,47 @@ impl Domain {
     }
 }
 
+impl DomainRequest<Get, Domain> {
+    /// Fetches the domain, returning only the complete contents of its zone
+    /// file (ex: to migrate it to another DNS provider).
+    ///
+    /// Fails with `ErrorKind::NotFound` if the domain does not exist, or if
+    /// its zone file has not been generated yet, as happens for a short while
+    /// after the domain is created.
+    ///
+    /// ```rust,no_run
+    /// # extern crate digitalocean;
+    /// # use digitalocean::prelude::*;
+    /// # let client = DigitalOcean::new("token").unwrap();
+    /// let zone = Domain::get("example.com").zone_file(&client).unwrap();
+    /// println!("{}", zone);
+    /// ```
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-domain)
+    pub fn zone_file<C>(self, instance: &DigitalOcean<C>) -> Result<String, Error>
+    where
+        C: HttpClient,
+    {
+        self.execute(instance)?
+            .zone_file
+            .ok_or_else(|| ErrorKind::NotFound.into())
+    }
+    /// Fetches the zone file without blocking the calling thread. This
+    /// mirrors [`zone_file`](#method.zone_file).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-domain)
+    #[cfg(feature = "async")]
+    pub async fn zone_file_async<C>(self, instance: &DigitalOcean<C>) -> Result<String, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        self.execute_async(instance)
+            .await?
+            .zone_file
+            .ok_or_else(|| ErrorKind::NotFound.into())
+    }
+}
+
 /// Response type returned from Digital Ocean.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct DomainResponse {
@@ -29,7 +29,7 @@ pub struct DomainRecord {
     ///
     /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
     #[serde(rename = "type")]
-    kind: String, // 'type' is reserved in Rust.
+    kind: DnsRecordType, // 'type' is reserved in Rust.
     /// The name to use for the DNS record.
     name: String,
     /// The value to use for the DNS record.
@@ -46,6 +46,39 @@ pub struct DomainRecord {
     weight: Option<usize>,
 }
 
+identified_by!(DomainRecord, id);
+
+string_enum!(
+    /// The type of a [`DomainRecord`](struct.DomainRecord.html).
+    ///
+    /// Requests accept either this or the name of the type (ex: `"CNAME"`),
+    /// so that types this crate does not know about may still be used.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#domain-records)
+    DnsRecordType {
+        /// Maps a name to an IPv4 address.
+        A => "A",
+        /// Maps a name to an IPv6 address.
+        Aaaa => "AAAA",
+        /// Restricts which certificate authorities may issue certificates
+        /// for the name.
+        Caa => "CAA",
+        /// Makes the name an alias of another name.
+        Cname => "CNAME",
+        /// Routes mail for the name to a mail server, by `priority`.
+        Mx => "MX",
+        /// Delegates the name to a name server.
+        Ns => "NS",
+        /// Describes the zone itself. It is created automatically with the
+        /// domain.
+        Soa => "SOA",
+        /// Locates a service on a host, by `priority`, `weight` and `port`.
+        Srv => "SRV",
+        /// Holds arbitrary text, such as verification tokens.
+        Txt => "TXT",
+    }
+);
+
 impl DomainRequest<Get, Domain> {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-domain-records)
     pub fn records(mut self) -> DomainRecordRequest<List, Vec<DomainRecord>> {
@@ -56,23 +89,94 @@ impl DomainRequest<Get, Domain> {
 
         self.transmute()
     }
+
+    /// A single record of the domain. It can be updated or deleted by
+    /// chaining `.update()` or `.delete()` onto this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-domain-record)
+    pub fn record(mut self, id: usize) -> DomainRecordRequest<Get, DomainRecord> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DOMAIN_RECORDS_SEGMENT)
+            .push(&id.to_string());
+
+        self.transmute()
+    }
+}
+
+impl DomainRecordRequest<Get, DomainRecord> {
+    /// Only the fields set on the returned request are changed.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-domain-record)
+    pub fn update(self) -> DomainRecordRequest<Update, DomainRecord> {
+        self.transmute()
+    }
+
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-domain-record)
+    pub fn delete(self) -> DomainRecordRequest<Delete, ()> {
+        self.transmute()
+    }
 }
 
 impl DomainRecordRequest<List, Vec<DomainRecord>> {
+    /// Only list records of the given type (ex: A, CNAME, TXT, ...). This
+    /// may be combined with `filter_name`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-domain-records)
+    pub fn filter_type<K>(mut self, kind: K) -> Self
+    where
+        K: Into<DnsRecordType>,
+    {
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("type", kind.into().as_str());
+
+        self
+    }
+
+    /// Only list records with the given fully qualified name (ex:
+    /// `www.example.com`). This may be combined with `filter_type`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-domain-records)
+    pub fn filter_name<S>(mut self, name: S) -> Self
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("name", name.as_ref());
+
+        self
+    }
+
+    /// The type of the record may be given as a
+    /// [`DnsRecordType`](../api/enum.DnsRecordType.html) or by its name.
+    ///
+    /// ```rust
+    /// # extern crate digitalocean;
+    /// # use digitalocean::api::{DnsRecordType, Domain};
+    /// let req = Domain::get("example.com")
+    ///     .records()
+    ///     .create(DnsRecordType::Mx, "@", "mail.example.com.")
+    ///     .priority(Some(10));
+    /// ```
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-domain-record)
-    pub fn create<S>(
+    pub fn create<K, S>(
         mut self,
-        kind: S,
+        kind: K,
         name: S,
         data: S,
     ) -> DomainRecordRequest<Create, DomainRecord>
     where
+        K: Into<DnsRecordType>,
         S: AsRef<str> + Display + Serialize,
     {
         self.url_mut().path_segments_mut().expect(STATIC_URL_ERROR);
 
         self.set_body(json!({
-            "type": kind,
+            "type": kind.into(),
             "name": name,
             "data": data,
         }));
@@ -148,11 +252,11 @@ impl DomainRecordRequest<Update, DomainRecord> {
     /// The record type (A, MX, CNAME, etc).
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#domain-records)
-    pub fn kind<S>(mut self, val: S) -> Self
+    pub fn kind<K>(mut self, val: K) -> Self
     where
-        S: AsRef<str> + Display + Serialize,
+        K: Into<DnsRecordType>,
     {
-        self.body_mut()["type"] = json!(val);
+        self.body_mut()["type"] = json!(val.into());
         self
     }
     /// The host name, alias, or service being defined by the record.
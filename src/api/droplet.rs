@@ -1,24 +1,37 @@
-use self::droplet_fields::{Kernel, Networks, NextBackupWindow};
+use self::droplet_fields::{Kernel, NetworkType, Networks, NextBackupWindow};
+use super::id::{DropletId, VolumeId};
 use super::snapshot::Snapshot;
+use super::{collect_strings, HasPagination, HasResponse, HasValue};
 use super::{ApiLinks, ApiMeta};
-use super::{HasPagination, HasResponse, HasValue};
 use super::{Image, Region, Size};
+#[cfg(feature = "async")]
+use crate::client::AsyncHttpClient;
+use crate::client::HttpClient;
+use crate::error::ErrorKind;
 use crate::method::{Create, Delete, Get, List};
-use crate::request::Request;
+#[cfg(feature = "async")]
+use crate::request::ExecutableAsync;
 use crate::request::{DropletRequest, SnapshotRequest};
+use crate::request::{Executable, Request};
+use crate::wait::{self, WaitPolicy};
+use crate::DigitalOcean;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use chrono::{DateTime, Utc};
+use failure::Error;
 use getset::{Getters, Setters};
 use serde::Serialize;
 use std::fmt::Display;
+use std::net::Ipv4Addr;
 use url::Url;
 
 const DROPLETS_SEGMENT: &str = "droplets";
 const REPORTS_SEGMENT: &str = "reports";
 const DROPLET_NEIGHBORS_SEGMENT: &str = "droplet_neighbors";
+const DROPLET_NEIGHBORS_IDS_SEGMENT: &str = "droplet_neighbors_ids";
 const NEIGHBORS_SEGMENT: &str = "neighbors";
 const SNAPSHOTS_SEGMENT: &str = "snapshots";
 const BACKUPS_SEGMENT: &str = "backups";
+const KERNELS_SEGMENT: &str = "kernels";
 
 /// A Droplet is a DigitalOcean virtual machine. By sending requests to the
 /// Droplet endpoint, you can list, create, or delete Droplets.
@@ -34,7 +47,7 @@ const BACKUPS_SEGMENT: &str = "backups";
 pub struct Droplet {
     /// A unique identifier for each Droplet instance. This is automatically
     /// generated upon Droplet creation.
-    id: usize,
+    id: DropletId,
     /// The human-readable name set for the Droplet instance.
     name: String,
     /// Memory of the Droplet in megabytes.
@@ -49,9 +62,8 @@ pub struct Droplet {
     /// A time value given in ISO8601 combined date and time format that
     /// represents when the Droplet was created.
     created_at: DateTime<Utc>,
-    /// A status string indicating the state of the Droplet instance. This may
-    /// be "new", "active", "off", or "archive".
-    status: String,
+    /// The state of the Droplet instance.
+    status: DropletStatus,
     /// An array of backup IDs of any backups that have been taken of the
     /// Droplet instance. Droplet backups are enabled at the time of the
     /// instance creation.
@@ -60,7 +72,7 @@ pub struct Droplet {
     /// instance.
     snapshot_ids: Vec<usize>,
     /// An array of features enabled on this Droplet.
-    features: Vec<String>,
+    features: Vec<DropletFeature>,
     /// The region that the Droplet instance is deployed in. When setting a
     /// region, the value should be the slug identifier for the region. When
     /// you query a Droplet, the entire region object will be returned.
@@ -95,42 +107,81 @@ pub struct Droplet {
     tags: Vec<String>,
     /// A flat array including the unique identifier for each Block Storage
     /// volume attached to the Droplet.
-    volume_ids: Vec<String>,
+    volume_ids: Vec<VolumeId>,
 }
 
+identified_by!(Droplet, id);
+
 /// Fields which exists inside Droplets.
 pub mod droplet_fields {
+    use super::super::id::ImageId;
     use chrono::{DateTime, Utc};
+    use std::fmt;
     use std::net::{Ipv4Addr, Ipv6Addr};
     /// This exists in the `networks` field of a droplet.
     #[derive(Deserialize, Serialize, Debug, Clone)]
     pub struct Networks {
+        /// The IPv4 addresses of the droplet.
         pub v4: Vec<NetworkV4>,
+        /// The IPv6 addresses of the droplet.
         pub v6: Vec<NetworkV6>,
     }
 
+    impl Networks {
+        /// The first IPv4 network of the given type, if there is one.
+        pub fn v4_of(&self, kind: NetworkType) -> Option<&NetworkV4> {
+            self.v4.iter().find(|network| network.kind == kind)
+        }
+
+        /// The first IPv6 network of the given type, if there is one.
+        pub fn v6_of(&self, kind: NetworkType) -> Option<&NetworkV6> {
+            self.v6.iter().find(|network| network.kind == kind)
+        }
+    }
+
     /// These exist in the `networks` field of a droplet.
     #[derive(Deserialize, Serialize, Debug, Clone)]
     pub struct NetworkV4 {
+        /// The gateway of the network.
         pub gateway: Ipv4Addr,
+        /// The address of the droplet on the network.
         pub ip_address: Ipv4Addr,
+        /// The netmask of the network.
         pub netmask: Ipv4Addr,
+        /// Whether the network is public or private.
+        ///
         /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
         #[serde(rename = "type")]
-        pub kind: String,
+        pub kind: NetworkType,
     }
 
     /// These exist in the `networks` field of a droplet.
     #[derive(Deserialize, Serialize, Debug, Clone)]
     pub struct NetworkV6 {
+        /// The gateway of the network.
         pub gateway: Ipv6Addr,
+        /// The address of the droplet on the network.
         pub ip_address: Ipv6Addr,
+        /// The prefix length of the network (ex: 64).
         pub netmask: usize,
+        /// Whether the network is public or private.
+        ///
         /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
         #[serde(rename = "type")]
-        pub kind: String,
+        pub kind: NetworkType,
     }
 
+    string_enum!(
+        /// The type of a droplet's [`NetworkV4`](struct.NetworkV4.html) or
+        /// [`NetworkV6`](struct.NetworkV6.html).
+        NetworkType {
+            /// The address is reachable from the internet.
+            Public => "public",
+            /// The address is only reachable from within the region or VPC.
+            Private => "private",
+        }
+    );
+
     /// This exists in the `next_backup_window` field of a droplet.
     #[derive(Deserialize, Serialize, Debug, Clone)]
     pub struct NextBackupWindow {
@@ -145,9 +196,165 @@ pub mod droplet_fields {
         pub name: String,
         pub version: String,
     }
+
+    /// Identifies an image by either its slug or its numeric id, as accepted
+    /// by actions such as rebuilding a droplet.
+    #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(untagged)]
+    pub enum ImageRef {
+        /// A public image's slug, such as "ubuntu-20-04-x64".
+        Slug(String),
+        /// The id of an image, snapshot, or backup.
+        Id(usize),
+    }
+    impl fmt::Display for ImageRef {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ImageRef::Slug(slug) => write!(f, "{}", slug),
+                ImageRef::Id(id) => write!(f, "{}", id),
+            }
+        }
+    }
+    impl From<usize> for ImageRef {
+        fn from(val: usize) -> Self {
+            ImageRef::Id(val)
+        }
+    }
+    impl From<ImageId> for ImageRef {
+        fn from(val: ImageId) -> Self {
+            ImageRef::Id(val.0)
+        }
+    }
+    impl From<&str> for ImageRef {
+        fn from(val: &str) -> Self {
+            ImageRef::Slug(val.to_string())
+        }
+    }
+    impl From<String> for ImageRef {
+        fn from(val: String) -> Self {
+            ImageRef::Slug(val)
+        }
+    }
 }
 
+string_enum!(
+    /// The state of a [`Droplet`](struct.Droplet.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#droplets)
+    DropletStatus {
+        /// The Droplet is being created.
+        New => "new",
+        /// The Droplet is running.
+        Active => "active",
+        /// The Droplet is powered off.
+        Off => "off",
+        /// The Droplet has been archived.
+        Archive => "archive",
+    }
+);
+
+string_enum!(
+    /// A feature which may be enabled on a [`Droplet`](struct.Droplet.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#droplets)
+    DropletFeature {
+        /// Backups are taken of the Droplet weekly.
+        Backups => "backups",
+        /// The Droplet has an IPv6 address.
+        Ipv6 => "ipv6",
+        /// The Droplet has a private network interface.
+        PrivateNetworking => "private_networking",
+        /// The monitoring agent is installed on the Droplet.
+        Monitoring => "monitoring",
+        /// The Droplet uses virtio devices.
+        Virtio => "virtio",
+        /// The Droplet agent (for the web console) is installed on the Droplet.
+        DropletAgent => "droplet_agent",
+    }
+);
+
+/// The ids of Droplets which share a physical server, with one group of ids
+/// per server.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-droplet-neighbors)
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct NeighborIds(pub Vec<Vec<DropletId>>);
+
 impl Droplet {
+    /// Whether `feature` is enabled on the droplet.
+    pub fn has_feature(&self, feature: &DropletFeature) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Whether the droplet has been tagged with `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// The first public IPv4 address of the droplet, if it has one yet.
+    pub fn public_ipv4(&self) -> Option<Ipv4Addr> {
+        self.networks
+            .v4_of(NetworkType::Public)
+            .map(|network| network.ip_address)
+    }
+
+    /// The first private IPv4 address of the droplet, if it has one.
+    pub fn private_ipv4(&self) -> Option<Ipv4Addr> {
+        self.networks
+            .v4_of(NetworkType::Private)
+            .map(|network| network.ip_address)
+    }
+
+    /// Polls the droplet until its status is "active", returning the droplet
+    /// with its networks assigned. Fails with `ErrorKind::Timeout` if it is
+    /// still not active when the policy's timeout runs out.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-droplet-by-id)
+    pub fn wait_active<C>(
+        &self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<Droplet, Error>
+    where
+        C: HttpClient,
+    {
+        wait::poll(
+            self.clone(),
+            policy,
+            |droplet| Droplet::get(droplet.id).execute(instance),
+            Droplet::is_active,
+        )
+    }
+
+    /// Polls the droplet without blocking the calling thread. This mirrors
+    /// [`wait_active`](#method.wait_active).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-droplet-by-id)
+    #[cfg(feature = "async")]
+    pub async fn wait_active_async<C>(
+        &self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<Droplet, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        wait::poll_async(
+            self.clone(),
+            policy,
+            |droplet| Droplet::get(droplet.id).execute_async(instance),
+            Droplet::is_active,
+        )
+        .await
+    }
+
+    /// Whether the droplet has become active. Droplets have no failed
+    /// status to stop waiting on.
+    fn is_active(&self) -> Result<bool, Error> {
+        Ok(self.status == DropletStatus::Active)
+    }
+
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
     pub fn create<S, D>(name: S, region: S, size: S, image: D) -> DropletRequest<Create, Droplet>
     where
@@ -169,14 +376,20 @@ impl Droplet {
         req
     }
 
+    /// Creates a Droplet for each of `names` (up to ten) in a single request,
+    /// all with the same configuration. Accepts any collection of `&str` or
+    /// `String`, such as a slice, `Vec`, or array.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-multiple-droplets)
-    pub fn create_multiple<S, D>(
-        names: Vec<S>,
+    pub fn create_multiple<I, N, S, D>(
+        names: I,
         region: S,
         size: S,
         image: D,
     ) -> DropletRequest<Create, Vec<Droplet>>
     where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
         S: AsRef<str> + Serialize + Display,
         D: Serialize + Display,
     {
@@ -187,7 +400,7 @@ impl Droplet {
 
         let mut req = Request::new(url);
         req.set_body(json!({
-            "names": names,
+            "names": collect_strings(names),
             "region": region,
             "size": size,
             "image": format!("{}", image),
@@ -196,12 +409,15 @@ impl Droplet {
     }
 
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-droplet-by-id)
-    pub fn get(id: usize) -> DropletRequest<Get, Droplet> {
+    pub fn get<I>(id: I) -> DropletRequest<Get, Droplet>
+    where
+        I: Into<DropletId>,
+    {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(DROPLETS_SEGMENT)
-            .push(&id.to_string());
+            .push(&id.into().to_string());
 
         Request::new(url)
     }
@@ -216,6 +432,8 @@ impl Droplet {
         Request::new(url)
     }
 
+    /// Only the Droplets with the tag `name` are listed, across every page.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#listing-droplets-by-tag)
     pub fn list_by_tag<S>(name: S) -> DropletRequest<List, Vec<Droplet>>
     where
@@ -231,17 +449,46 @@ impl Droplet {
         Request::new(url)
     }
 
+    /// The first Droplet named exactly `name`, or `ErrorKind::NotFound` if
+    /// there is none.
+    ///
+    /// The API cannot look Droplets up by name, so this lists them page by
+    /// page until one matches. It makes a request per page of Droplets
+    /// scanned, so it takes longer the more Droplets the account has.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-droplets)
+    pub fn get_by_name<C, S>(instance: &DigitalOcean<C>, name: S) -> Result<Droplet, Error>
+    where
+        C: HttpClient,
+        S: AsRef<str>,
+    {
+        for page in Droplet::list().paginate(instance) {
+            if let Some(droplet) = page?.into_iter().find(|d| d.name == name.as_ref()) {
+                return Ok(droplet);
+            }
+        }
+        Err(ErrorKind::NotFound.into())
+    }
+
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-droplet)
-    pub fn delete(id: usize) -> DropletRequest<Delete, ()> {
+    pub fn delete<I>(id: I) -> DropletRequest<Delete, ()>
+    where
+        I: Into<DropletId>,
+    {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(DROPLETS_SEGMENT)
-            .push(&id.to_string());
+            .push(&id.into().to_string());
 
         Request::new(url)
     }
 
+    /// **Warning:** This deletes *every* Droplet with the tag `name`, along
+    /// with their backups, in a single call. It can not be undone, so be sure
+    /// the tag is only applied to Droplets which should be destroyed. Use
+    /// `delete()` to delete a single Droplet.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#deleting-droplets-by-tag)
     pub fn delete_by_tag<S>(name: S) -> DropletRequest<Delete, ()>
     where
@@ -267,18 +514,67 @@ impl Droplet {
 
         Request::new(url)
     }
+
+    /// The ids of Droplets which share a physical server, with one group of
+    /// ids per server. Unlike `neighbors()` only the ids are returned.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-droplet-neighbors)
+    pub fn neighbor_ids() -> DropletRequest<Get, NeighborIds> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(REPORTS_SEGMENT)
+            .push(DROPLET_NEIGHBORS_IDS_SEGMENT);
+
+        Request::new(url)
+    }
 }
 
 impl DropletRequest<Create, Droplet> {
+    /// Creates the droplet, then waits for it to become active as
+    /// [`Droplet::wait_active`](../api/struct.Droplet.html#method.wait_active)
+    /// does.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
+    pub fn create_and_wait<C>(
+        self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<Droplet, Error>
+    where
+        C: HttpClient,
+    {
+        self.execute(instance)?.wait_active(instance, policy)
+    }
+    /// Creates the droplet without blocking the calling thread. This mirrors
+    /// [`create_and_wait`](#method.create_and_wait).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
+    #[cfg(feature = "async")]
+    pub async fn create_and_wait_async<C>(
+        self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<Droplet, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        self.execute_async(instance)
+            .await?
+            .wait_active_async(instance, policy)
+            .await
+    }
     /// An array containing the IDs or fingerprints of the SSH keys that you
-    /// wish to embed in the Droplet's root account upon creation.
+    /// wish to embed in the Droplet's root account upon creation. Accepts
+    /// any collection, such as a slice, `Vec`, or array.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn ssh_keys<D>(mut self, val: Vec<D>) -> Self
+    pub fn ssh_keys<I, D>(mut self, val: I) -> Self
     where
+        I: IntoIterator<Item = D>,
         D: Display + Serialize,
     {
-        self.body_mut()["ssh_keys"] = json!(val);
+        self.body_mut()["ssh_keys"] = json!(val.into_iter().collect::<Vec<_>>());
         self
     }
     /// A boolean indicating whether automated backups should be enabled for
@@ -310,8 +606,18 @@ impl DropletRequest<Create, Droplet> {
     /// Droplet on first boot, often a 'cloud-config' file or Bash script.
     /// It must be plain text and may not exceed 64 KiB in size.
     ///
+    /// ```rust,no_run
+    /// # use digitalocean::prelude::*;
+    /// # use std::fs;
+    /// let req = Droplet::create("example", "nyc3", "s-1vcpu-1gb", "ubuntu-20-04-x64")
+    ///     .user_data(fs::read_to_string("cloud-config.yaml").unwrap());
+    /// ```
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn user_data(mut self, val: bool) -> Self {
+    pub fn user_data<S>(mut self, val: S) -> Self
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
         self.body_mut()["user_data"] = json!(val);
         self
     }
@@ -323,35 +629,73 @@ impl DropletRequest<Create, Droplet> {
         self.body_mut()["monitoring"] = json!(val);
         self
     }
+    /// A boolean indicating whether to install the DigitalOcean agent used
+    /// for providing access to the Droplet web console in the control panel.
+    /// By default, the agent is installed on new Droplets but installation
+    /// errors are ignored. Set it to `false` to prevent it from being
+    /// installed, or to `true` to make installation errors fatal.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
+    pub fn with_droplet_agent(mut self, val: bool) -> Self {
+        self.body_mut()["with_droplet_agent"] = json!(val);
+        self
+    }
+    /// The unique identifier of the VPC to place the Droplet in. If not
+    /// given, the Droplet is placed in the region's default VPC.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
+    pub fn vpc<S>(mut self, uuid: S) -> Self
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        self.body_mut()["vpc_uuid"] = json!(uuid);
+        self
+    }
     /// A flat array including the unique string identifier for each Block
     /// Storage volume to be attached to the Droplet. At the moment a volume
-    /// can only be attached to a single Droplet.
+    /// can only be attached to a single Droplet. Accepts any collection of
+    /// `&str`, `String`, or [`VolumeId`](../api/struct.VolumeId.html), such
+    /// as a slice, `Vec`, or array.
+    ///
+    /// Volumes given here are attached before the Droplet boots, unlike ones
+    /// attached after it is created.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn volumes(mut self, val: Vec<String>) -> Self {
-        self.body_mut()["volumes"] = json!(val);
+    pub fn volumes<I, S>(mut self, val: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["volumes"] = json!(collect_strings(val));
         self
     }
     /// A flat array of tag names as strings to apply to the Droplet after it
-    /// is created. Tag names can either be existing or new tags.
+    /// is created. Tag names can either be existing or new tags. Accepts any
+    /// collection of `&str` or `String`, such as a slice, `Vec`, or array.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn tags(mut self, val: Vec<String>) -> Self {
-        self.body_mut()["tags"] = json!(val);
+    pub fn tags<I, S>(mut self, val: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(val));
         self
     }
 }
 
 impl DropletRequest<Create, Vec<Droplet>> {
     /// An array containing the IDs or fingerprints of the SSH keys that you
-    /// wish to embed in the Droplet's root account upon creation.
+    /// wish to embed in the Droplet's root account upon creation. Accepts
+    /// any collection, such as a slice, `Vec`, or array.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn ssh_keys<D>(mut self, val: Vec<D>) -> Self
+    pub fn ssh_keys<I, D>(mut self, val: I) -> Self
     where
+        I: IntoIterator<Item = D>,
         D: Display + Serialize,
     {
-        self.body_mut()["ssh_keys"] = json!(val);
+        self.body_mut()["ssh_keys"] = json!(val.into_iter().collect::<Vec<_>>());
         self
     }
     /// A boolean indicating whether automated backups should be enabled for
@@ -384,7 +728,10 @@ impl DropletRequest<Create, Vec<Droplet>> {
     /// It must be plain text and may not exceed 64 KiB in size.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn user_data(mut self, val: bool) -> Self {
+    pub fn user_data<S>(mut self, val: S) -> Self
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
         self.body_mut()["user_data"] = json!(val);
         self
     }
@@ -396,21 +743,57 @@ impl DropletRequest<Create, Vec<Droplet>> {
         self.body_mut()["monitoring"] = json!(val);
         self
     }
+    /// A boolean indicating whether to install the DigitalOcean agent used
+    /// for providing access to the Droplet web console in the control panel.
+    /// By default, the agent is installed on new Droplets but installation
+    /// errors are ignored. Set it to `false` to prevent it from being
+    /// installed, or to `true` to make installation errors fatal.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
+    pub fn with_droplet_agent(mut self, val: bool) -> Self {
+        self.body_mut()["with_droplet_agent"] = json!(val);
+        self
+    }
+    /// The unique identifier of the VPC to place the Droplet in. If not
+    /// given, the Droplet is placed in the region's default VPC.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
+    pub fn vpc<S>(mut self, uuid: S) -> Self
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        self.body_mut()["vpc_uuid"] = json!(uuid);
+        self
+    }
     /// A flat array including the unique string identifier for each Block
     /// Storage volume to be attached to the Droplet. At the moment a volume
-    /// can only be attached to a single Droplet.
+    /// can only be attached to a single Droplet. Accepts any collection of
+    /// `&str`, `String`, or [`VolumeId`](../api/struct.VolumeId.html), such
+    /// as a slice, `Vec`, or array.
+    ///
+    /// Volumes given here are attached before the Droplet boots, unlike ones
+    /// attached after it is created.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn volumes(mut self, val: Vec<String>) -> Self {
-        self.body_mut()["volumes"] = json!(val);
+    pub fn volumes<I, S>(mut self, val: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["volumes"] = json!(collect_strings(val));
         self
     }
     /// A flat array of tag names as strings to apply to the Droplet after it
-    /// is created. Tag names can either be existing or new tags.
+    /// is created. Tag names can either be existing or new tags. Accepts any
+    /// collection of `&str` or `String`, such as a slice, `Vec`, or array.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-droplet)
-    pub fn tags(mut self, val: Vec<String>) -> Self {
-        self.body_mut()["tags"] = json!(val);
+    pub fn tags<I, S>(mut self, val: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(val));
         self
     }
 }
@@ -443,6 +826,19 @@ impl DropletRequest<Get, Droplet> {
 
         self.transmute()
     }
+    /// The kernels which the Droplet can be changed to with
+    /// `change_kernel()`. This only applies to Droplets which do not manage
+    /// their own kernel.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-available-kernels-for-a-droplet)
+    pub fn kernels(mut self) -> DropletRequest<List, Vec<Kernel>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(KERNELS_SEGMENT);
+
+        self.transmute()
+    }
 }
 
 /// Response type returned from Digital Ocean.
@@ -466,8 +862,11 @@ impl HasValue for DropletResponse {
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct DropletListResponse {
     droplets: Vec<Droplet>,
+    // Listing the neighbors of a Droplet responds without pagination.
+    #[serde(default)]
     links: ApiLinks,
-    meta: ApiMeta,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
 }
 
 impl HasResponse for Vec<Droplet> {
@@ -503,3 +902,45 @@ impl HasValue for DropletNeighborsResponse {
         self.neighbors
     }
 }
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DropletNeighborIdsResponse {
+    neighbor_ids: NeighborIds,
+}
+
+impl HasResponse for NeighborIds {
+    type Response = DropletNeighborIdsResponse;
+}
+
+impl HasValue for DropletNeighborIdsResponse {
+    type Value = NeighborIds;
+    fn value(self) -> NeighborIds {
+        self.neighbor_ids
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct KernelListResponse {
+    kernels: Vec<Kernel>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<Kernel> {
+    type Response = KernelListResponse;
+}
+
+impl HasPagination for KernelListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for KernelListResponse {
+    type Value = Vec<Kernel>;
+    fn value(self) -> Vec<Kernel> {
+        self.kernels
+    }
+}
@@ -1,13 +1,39 @@
 use super::action::Action;
+use super::droplet::droplet_fields::ImageRef;
 use super::droplet::Droplet;
+use super::id::ImageId;
 use crate::method::{Create, Get, List};
-use crate::request::{DropletActionRequest, DropletRequest};
-use crate::STATIC_URL_ERROR;
+use crate::request::{DropletActionRequest, DropletRequest, Request};
+use crate::{ROOT_URL, STATIC_URL_ERROR};
 use serde::Serialize;
+use serde_json::Value;
 use std::fmt::Display;
+use url::Url;
 
+const DROPLETS_SEGMENT: &str = "droplets";
 const DROPLET_ACTIONS_SEGMENT: &str = "actions";
 
+// The bodies of the actions which may be taken both on a single Droplet and
+// on every Droplet with a tag.
+
+/// The body of an action which takes no arguments.
+fn action_body(kind: &str) -> Value {
+    json!({
+        "type": kind,
+    })
+}
+
+fn power_body(val: bool) -> Value {
+    action_body(if val { "power_on" } else { "power_off" })
+}
+
+fn snapshot_body(name: &str) -> Value {
+    json!({
+        "type": "snapshot",
+        "name": name,
+    })
+}
+
 impl DropletRequest<Get, Droplet> {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-actions-for-a-droplet)
     pub fn actions(mut self) -> DropletActionRequest<List, Vec<Action>> {
@@ -19,232 +45,240 @@ impl DropletRequest<Get, Droplet> {
         self.transmute()
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#enable-backups)
-    pub fn enable_backups(mut self) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": "enable_backups",
-        }));
-
-        self.transmute()
+    pub fn enable_backups(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("enable_backups"))
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#disable-backups)
-    pub fn disable_backups(mut self) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": "disable_backups",
-        }));
-
-        self.transmute()
+    pub fn disable_backups(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("disable_backups"))
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#reboot-a-droplet)
-    pub fn reboot(mut self) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": "reboot",
-        }));
-
-        self.transmute()
+    pub fn reboot(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("reboot"))
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-cycle-a-droplet)
-    pub fn power_cycle(mut self) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": "power_cycle",
-        }));
-
-        self.transmute()
+    pub fn power_cycle(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("power_cycle"))
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#shutdown-a-droplet)
-    pub fn shutdown(mut self) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": "shutdown",
-        }));
-
-        self.transmute()
+    pub fn shutdown(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("shutdown"))
     }
+    /// Powers the Droplet on when `val` is true, and off otherwise.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-off-a-droplet)
-    pub fn power(mut self, val: bool) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": if val { "power_on" } else { "power_off" },
-        }));
-
-        self.transmute()
+    pub fn power(self, val: bool) -> DropletActionRequest<Create, Action> {
+        self.act(power_body(val))
     }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-on-a-droplet)
+    pub fn power_on(self) -> DropletActionRequest<Create, Action> {
+        self.power(true)
+    }
+    /// A hard shutdown, like pulling the power cord. Prefer `shutdown()`
+    /// when the Droplet should be shut down gracefully.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-off-a-droplet)
+    pub fn power_off(self) -> DropletActionRequest<Create, Action> {
+        self.power(false)
+    }
+    /// Restores the Droplet from one of its backups or snapshots, by the id
+    /// of the backup or snapshot image.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#restore-a-droplet)
-    pub fn restore<D>(mut self, image: D) -> DropletActionRequest<Create, Action>
+    pub fn restore<I>(self, image: I) -> DropletActionRequest<Create, Action>
     where
-        D: Display,
+        I: Into<ImageId>,
     {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
+        self.act(json!({
             "type": "restore",
-            "image": format!("{}", image),
-        }));
-
-        self.transmute()
+            "image": image.into(),
+        }))
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#password-reset-a-droplet)
-    pub fn password_reset(mut self) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": "password_reset",
-        }));
-
-        self.transmute()
+    pub fn password_reset(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("password_reset"))
     }
+    /// Resizes the Droplet to the size with the slug `size`. When `disk` is
+    /// true the disk is resized too, which is permanent as disks can not be
+    /// shrunk. Otherwise only CPU and RAM are changed, so the resize can be
+    /// reversed.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#resize-a-droplet)
-    pub fn resize<S>(mut self, size: S, disk: bool) -> DropletActionRequest<Create, Action>
+    pub fn resize<S>(self, size: S, disk: bool) -> DropletActionRequest<Create, Action>
     where
         S: AsRef<str> + Serialize + Display,
     {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
+        self.act(json!({
             "type": "resize",
             "disk": disk,
             "size": size.as_ref(),
-        }));
-
-        self.transmute()
+        }))
     }
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#resize-a-droplet)
-    pub fn rebuild<S>(mut self, image: S) -> DropletActionRequest<Create, Action>
+    /// Rebuilds the Droplet from an image, erasing its disk. Accepts an
+    /// [`ImageRef`](droplet_fields/enum.ImageRef.html), a slug, or an id.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#rebuild-a-droplet)
+    pub fn rebuild<I>(self, image: I) -> DropletActionRequest<Create, Action>
     where
-        S: AsRef<str> + Serialize + Display,
+        I: Into<ImageRef>,
     {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
+        self.act(json!({
             "type": "rebuild",
-            "image": image.as_ref(),
-        }));
-
-        self.transmute()
+            "image": image.into(),
+        }))
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#rename-a-droplet)
-    pub fn rename<S>(mut self, name: S) -> DropletActionRequest<Create, Action>
+    pub fn rename<S>(self, name: S) -> DropletActionRequest<Create, Action>
     where
         S: AsRef<str> + Serialize + Display,
     {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
+        self.act(json!({
             "type": "rename",
             "name": name.as_ref(),
-        }));
-
-        self.transmute()
+        }))
     }
+    /// Changes the kernel to the one with the id `kernel`, as listed by
+    /// `kernels()`.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#change-the-kernel)
-    pub fn kernel(mut self, kernel: usize) -> DropletActionRequest<Create, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
+    pub fn change_kernel(self, kernel: usize) -> DropletActionRequest<Create, Action> {
+        self.act(json!({
             "type": "change_kernel",
             "kernel": kernel,
-        }));
-
-        self.transmute()
+        }))
+    }
+    /// The same as `change_kernel()`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#change-the-kernel)
+    pub fn kernel(self, kernel: usize) -> DropletActionRequest<Create, Action> {
+        self.change_kernel(kernel)
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#enable-ipv6)
-    pub fn enable_ipv6(mut self) -> DropletActionRequest<Create, Action> {
+    pub fn enable_ipv6(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("enable_ipv6"))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#enable-private-networking)
+    pub fn enable_private_networking(self) -> DropletActionRequest<Create, Action> {
+        self.act(action_body("enable_private_networking"))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#snapshot-a-droplet)
+    pub fn snapshot<S>(self, name: S) -> DropletActionRequest<Create, Action>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.act(snapshot_body(name.as_ref()))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-a-droplet-action)
+    pub fn action(mut self, id: usize) -> DropletActionRequest<Get, Action> {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT);
-
-        self.set_body(json!({
-            "type": "enable_ipv6",
-        }));
+            .push(DROPLET_ACTIONS_SEGMENT)
+            .push(&id.to_string());
 
         self.transmute()
     }
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#enable-private-networking)
-    pub fn enable_private_networking(mut self) -> DropletActionRequest<Create, Action> {
+
+    /// Takes the action described by `body` on the Droplet.
+    fn act(mut self, body: Value) -> DropletActionRequest<Create, Action> {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(DROPLET_ACTIONS_SEGMENT);
 
-        self.set_body(json!({
-            "type": "enable_private_networking",
-        }));
+        self.set_body(body);
 
         self.transmute()
     }
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#snapshot-a-droplet)
-    pub fn snapshot<S>(mut self, name: S) -> DropletActionRequest<Create, Action>
+}
+
+/// The actions which may be taken on every Droplet with a tag at once, from
+/// [`Droplet::action_by_tag()`](struct.Droplet.html#method.action_by_tag).
+/// Nothing can be executed until one of them is chosen.
+#[derive(Debug, Clone)]
+pub struct TaggedDropletAction {
+    url: Url,
+}
+
+impl Droplet {
+    /// Acts on every Droplet with the tag `name` at once, with one of the
+    /// actions of the returned builder (such as `power_off()`). Only those
+    /// actions may be taken on tagged Droplets. An action is created for each
+    /// Droplet.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#acting-on-tagged-droplets)
+    pub fn action_by_tag<S>(name: S) -> TaggedDropletAction
     where
         S: AsRef<str> + Serialize + Display,
     {
-        self.url_mut()
-            .path_segments_mut()
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
+            .push(DROPLETS_SEGMENT)
             .push(DROPLET_ACTIONS_SEGMENT);
 
-        self.set_body(json!({
-            "type": "snapshot",
-            "name": name.as_ref(),
-        }));
+        url.query_pairs_mut().append_pair("tag_name", name.as_ref());
 
-        self.transmute()
+        TaggedDropletAction { url }
     }
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-a-droplet-action)
-    pub fn action(mut self, id: usize) -> DropletActionRequest<Get, Action> {
-        self.url_mut()
-            .path_segments_mut()
-            .expect(STATIC_URL_ERROR)
-            .push(DROPLET_ACTIONS_SEGMENT)
-            .push(&id.to_string());
+}
 
-        self.transmute()
+impl TaggedDropletAction {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#enable-backups)
+    pub fn enable_backups(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.act(action_body("enable_backups"))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#disable-backups)
+    pub fn disable_backups(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.act(action_body("disable_backups"))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-cycle-a-droplet)
+    pub fn power_cycle(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.act(action_body("power_cycle"))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#shutdown-a-droplet)
+    pub fn shutdown(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.act(action_body("shutdown"))
+    }
+    /// Powers the Droplets on when `val` is true, and off otherwise.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-off-a-droplet)
+    pub fn power(self, val: bool) -> DropletActionRequest<Create, Vec<Action>> {
+        self.act(power_body(val))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-on-a-droplet)
+    pub fn power_on(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.power(true)
+    }
+    /// A hard shutdown, like pulling the power cord. Prefer `shutdown()`
+    /// when the Droplets should be shut down gracefully.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#power-off-a-droplet)
+    pub fn power_off(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.power(false)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#enable-ipv6)
+    pub fn enable_ipv6(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.act(action_body("enable_ipv6"))
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#enable-private-networking)
+    pub fn enable_private_networking(self) -> DropletActionRequest<Create, Vec<Action>> {
+        self.act(action_body("enable_private_networking"))
+    }
+    /// Every Droplet is snapshotted with the same `name`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#snapshot-a-droplet)
+    pub fn snapshot<S>(self, name: S) -> DropletActionRequest<Create, Vec<Action>>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.act(snapshot_body(name.as_ref()))
     }
-}
 
-// TODO: https://developers.digitalocean.com/documentation/v2/#acting-on-tagged-droplets
+    /// Takes the action described by `body` on the tagged Droplets.
+    fn act(self, body: Value) -> DropletActionRequest<Create, Vec<Action>> {
+        let mut req = Request::new(self.url);
+        req.set_body(body);
+        req
+    }
+}
@@ -0,0 +1,268 @@
+use super::droplet::Droplet;
+use super::id::DropletId;
+use super::{HasResponse, HasValue};
+use crate::method::Get;
+use crate::request::DropletMetricsRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, TimeZone, Utc};
+use getset::{Getters, Setters};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+const MONITORING_SEGMENT: &str = "monitoring";
+const METRICS_SEGMENT: &str = "metrics";
+const DROPLET_SEGMENT: &str = "droplet";
+const CPU_SEGMENT: &str = "cpu";
+const BANDWIDTH_SEGMENT: &str = "bandwidth";
+const MEMORY_AVAILABLE_SEGMENT: &str = "memory_available";
+const MEMORY_FREE_SEGMENT: &str = "memory_free";
+const MEMORY_TOTAL_SEGMENT: &str = "memory_total";
+const FILESYSTEM_FREE_SEGMENT: &str = "filesystem_free";
+const FILESYSTEM_SIZE_SEGMENT: &str = "filesystem_size";
+const LOAD_1_SEGMENT: &str = "load_1";
+const LOAD_5_SEGMENT: &str = "load_5";
+const LOAD_15_SEGMENT: &str = "load_15";
+
+/// A time series of a Droplet metric, as collected by the DigitalOcean
+/// monitoring agent.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#droplet-metrics)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct MetricSeries {
+    /// The labels identifying the series, such as `host_id` and, for CPU
+    /// metrics, the `mode` of the CPU time.
+    metric: HashMap<String, String>,
+    /// The samples of the series, as pairs of when the sample was taken and
+    /// its value.
+    #[serde(
+        deserialize_with = "deserialize_values",
+        serialize_with = "serialize_values"
+    )]
+    values: Vec<(DateTime<Utc>, f64)>,
+}
+
+impl Droplet {
+    /// Metrics of a Droplet, which are chosen by chaining a metric such as
+    /// `.cpu()` onto this.
+    ///
+    /// **Note:** Metrics are only collected for Droplets with monitoring
+    /// enabled.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#droplet-metrics)
+    pub fn metrics<I>(id: I) -> DropletMetricsRequest<Get, ()>
+    where
+        I: Into<DropletId>,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(MONITORING_SEGMENT)
+            .push(METRICS_SEGMENT)
+            .push(DROPLET_SEGMENT);
+
+        url.query_pairs_mut()
+            .append_pair("host_id", &id.into().to_string());
+
+        Request::new(url)
+    }
+}
+
+impl DropletMetricsRequest<Get, ()> {
+    /// The CPU time spent in each mode (such as "user", "system", and
+    /// "idle"), with one series per mode.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-cpu-metrics)
+    pub fn cpu(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(CPU_SEGMENT, start, end)
+    }
+    /// The bandwidth of a network interface. The `interface` may be
+    /// "public" or "private", and the `direction` may be "inbound" or
+    /// "outbound".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-bandwidth-metrics)
+    pub fn bandwidth<S>(
+        mut self,
+        direction: S,
+        interface: S,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("interface", interface.as_ref())
+            .append_pair("direction", direction.as_ref());
+
+        self.metric(BANDWIDTH_SEGMENT, start, end)
+    }
+    /// The memory available for starting new applications, in bytes.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-available-memory-metrics)
+    pub fn memory_available(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(MEMORY_AVAILABLE_SEGMENT, start, end)
+    }
+    /// The memory not used at all, in bytes.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-free-memory-metrics)
+    pub fn memory_free(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(MEMORY_FREE_SEGMENT, start, end)
+    }
+    /// The total memory, in bytes.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-total-memory-metrics)
+    pub fn memory_total(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(MEMORY_TOTAL_SEGMENT, start, end)
+    }
+    /// The free space of each filesystem, in bytes, with one series per
+    /// device and mount point.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-free-filesystem-metrics)
+    pub fn filesystem_free(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(FILESYSTEM_FREE_SEGMENT, start, end)
+    }
+    /// The size of each filesystem, in bytes, with one series per device and
+    /// mount point.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-filesystem-size-metrics)
+    pub fn filesystem_size(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(FILESYSTEM_SIZE_SEGMENT, start, end)
+    }
+    /// The load average over 1 minute.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-load-1-metrics)
+    pub fn load_1(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(LOAD_1_SEGMENT, start, end)
+    }
+    /// The load average over 5 minutes.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-load-5-metrics)
+    pub fn load_5(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(LOAD_5_SEGMENT, start, end)
+    }
+    /// The load average over 15 minutes.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-droplet-load-15-metrics)
+    pub fn load_15(
+        self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.metric(LOAD_15_SEGMENT, start, end)
+    }
+
+    fn metric(
+        mut self,
+        segment: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DropletMetricsRequest<Get, Vec<MetricSeries>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(segment);
+
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("start", &start.timestamp().to_string())
+            .append_pair("end", &end.timestamp().to_string());
+
+        self.transmute()
+    }
+}
+
+/// Samples are sent as `[timestamp, "value"]` pairs, with the timestamp in
+/// (possibly fractional) seconds since the epoch and the value as a string.
+fn deserialize_values<'de, D>(deserializer: D) -> Result<Vec<(DateTime<Utc>, f64)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<(f64, String)>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(timestamp, value)| {
+            let secs = timestamp.trunc() as i64;
+            let nanos = (timestamp.fract() * 1_000_000_000.0).round() as u32;
+            let timestamp = Utc
+                .timestamp_opt(secs, nanos)
+                .single()
+                .ok_or_else(|| de::Error::custom("timestamp out of range"))?;
+            let value = value.parse().map_err(de::Error::custom)?;
+            Ok((timestamp, value))
+        })
+        .collect()
+}
+
+fn serialize_values<S>(values: &[(DateTime<Utc>, f64)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(values.len()))?;
+    for (timestamp, value) in values {
+        seq.serialize_element(&(timestamp.timestamp(), value.to_string()))?;
+    }
+    seq.end()
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MetricsResponse {
+    status: String,
+    data: MetricsData,
+}
+
+/// The `data` of a metrics response.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MetricsData {
+    #[serde(rename = "resultType")]
+    result_type: String,
+    result: Vec<MetricSeries>,
+}
+
+impl HasResponse for Vec<MetricSeries> {
+    type Response = MetricsResponse;
+}
+
+impl HasValue for MetricsResponse {
+    type Value = Vec<MetricSeries>;
+    fn value(self) -> Vec<MetricSeries> {
+        self.data.result
+    }
+}
@@ -0,0 +1,568 @@
+use self::firewall_fields::{InboundRule, OutboundRule, PendingChange};
+use super::id::DropletId;
+use super::{collect_droplet_ids, collect_strings, HasPagination, HasResponse, HasValue};
+use super::{ApiLinks, ApiMeta};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::FirewallRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const FIREWALLS_SEGMENT: &str = "firewalls";
+const RULES_SEGMENT: &str = "rules";
+const DROPLETS_SEGMENT: &str = "droplets";
+const TAGS_SEGMENT: &str = "tags";
+
+/// Cloud Firewalls provide the ability to restrict network access to and from
+/// a Droplet allowing you to define which ports will accept inbound or
+/// outbound connections.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#firewalls)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Firewall {
+    /// A unique ID that can be used to identify and reference a Firewall.
+    id: String,
+    /// The current state of the Firewall.
+    status: FirewallStatus,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the Firewall was created.
+    created_at: DateTime<Utc>,
+    /// An array of objects each containing the fields "droplet_id",
+    /// "removing", and "status". It is provided to detail exactly which
+    /// Droplets are having their security policies updated.
+    pending_changes: Vec<PendingChange>,
+    /// A human-readable name for a Firewall.
+    name: String,
+    /// The inbound access rules of the Firewall.
+    inbound_rules: Vec<InboundRule>,
+    /// The outbound access rules of the Firewall.
+    outbound_rules: Vec<OutboundRule>,
+    /// An array containing the IDs of the Droplets assigned to the Firewall.
+    droplet_ids: Vec<DropletId>,
+    /// An array containing the names of the Tags assigned to the Firewall.
+    tags: Vec<String>,
+}
+
+identified_by!(Firewall, id);
+
+string_enum!(
+    /// The status of a [`Firewall`](struct.Firewall.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#firewalls)
+    FirewallStatus {
+        /// Changes to the Firewall are being applied.
+        Waiting => "waiting",
+        /// Every change to the Firewall was applied.
+        Succeeded => "succeeded",
+        /// Applying changes to the Firewall failed.
+        Failed => "failed",
+    }
+);
+
+/// Fields which exists inside Firewalls.
+pub mod firewall_fields {
+    use super::super::id::DropletId;
+
+    /// This exists in the `inbound_rules` field of a firewall.
+    ///
+    /// Inbound rules allow traffic on the given protocol and ports from the
+    /// given sources to reach the Droplets of the Firewall.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct InboundRule {
+        /// The type of traffic to be allowed. This may be one of "tcp",
+        /// "udp", or "icmp".
+        pub protocol: String,
+        /// The ports on which traffic will be allowed specified as a string
+        /// containing a single port, a range (e.g. "8000-9000"), or "all" to
+        /// open all ports for a protocol. Not used for "icmp".
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub ports: Option<String>,
+        /// The locations from which inbound traffic will be accepted.
+        pub sources: Targets,
+    }
+    impl InboundRule {
+        pub fn new<S>(protocol: S, ports: Option<S>, sources: Targets) -> Self
+        where
+            S: AsRef<str>,
+        {
+            InboundRule {
+                protocol: protocol.as_ref().to_string(),
+                ports: ports.map(|v| v.as_ref().to_string()),
+                sources,
+            }
+        }
+    }
+    impl<S> From<(S, S, Targets)> for InboundRule
+    where
+        S: AsRef<str>,
+    {
+        fn from(val: (S, S, Targets)) -> Self {
+            InboundRule::new(val.0, Some(val.1), val.2)
+        }
+    }
+
+    /// This exists in the `outbound_rules` field of a firewall.
+    ///
+    /// Outbound rules allow traffic on the given protocol and ports from the
+    /// Droplets of the Firewall to reach the given destinations.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct OutboundRule {
+        /// The type of traffic to be allowed. This may be one of "tcp",
+        /// "udp", or "icmp".
+        pub protocol: String,
+        /// The ports on which traffic will be allowed specified as a string
+        /// containing a single port, a range (e.g. "8000-9000"), or "all" to
+        /// open all ports for a protocol. Not used for "icmp".
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub ports: Option<String>,
+        /// The locations to which outbound traffic will be allowed.
+        pub destinations: Targets,
+    }
+    impl OutboundRule {
+        pub fn new<S>(protocol: S, ports: Option<S>, destinations: Targets) -> Self
+        where
+            S: AsRef<str>,
+        {
+            OutboundRule {
+                protocol: protocol.as_ref().to_string(),
+                ports: ports.map(|v| v.as_ref().to_string()),
+                destinations,
+            }
+        }
+    }
+    impl<S> From<(S, S, Targets)> for OutboundRule
+    where
+        S: AsRef<str>,
+    {
+        fn from(val: (S, S, Targets)) -> Self {
+            OutboundRule::new(val.0, Some(val.1), val.2)
+        }
+    }
+
+    /// This exists in the `sources` field of an inbound rule and the
+    /// `destinations` field of an outbound rule.
+    ///
+    /// Traffic is allowed to or from any of the given locations.
+    #[derive(Deserialize, Serialize, Debug, Clone, Default)]
+    pub struct Targets {
+        /// An array of strings containing the IPv4 addresses, IPv6 addresses,
+        /// IPv4 CIDRs, and/or IPv6 CIDRs.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub addresses: Vec<String>,
+        /// An array containing the IDs of Droplets.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub droplet_ids: Vec<DropletId>,
+        /// An array containing the IDs of Load Balancers.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub load_balancer_uids: Vec<String>,
+        /// An array containing the names of Tags corresponding to groups of
+        /// Droplets.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub tags: Vec<String>,
+    }
+    impl Targets {
+        pub fn new() -> Self {
+            Targets::default()
+        }
+        pub fn address<S>(mut self, address: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            self.addresses.push(address.as_ref().to_string());
+            self
+        }
+        pub fn droplet<I>(mut self, id: I) -> Self
+        where
+            I: Into<DropletId>,
+        {
+            self.droplet_ids.push(id.into());
+            self
+        }
+        pub fn load_balancer<S>(mut self, id: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            self.load_balancer_uids.push(id.as_ref().to_string());
+            self
+        }
+        pub fn tag<S>(mut self, tag: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            self.tags.push(tag.as_ref().to_string());
+            self
+        }
+    }
+
+    /// This exists in the `pending_changes` field of a firewall.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct PendingChange {
+        /// The ID of the Droplet whose security policy is being updated.
+        pub droplet_id: DropletId,
+        /// If true, the Droplet is being removed from the Firewall.
+        pub removing: bool,
+        /// A status string indicating the current state of the change.
+        pub status: String,
+    }
+}
+
+impl Firewall {
+    /// Chain `.inbound_rule()` and `.outbound_rule()` onto this to allow
+    /// traffic, without any rules all traffic is blocked.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-firewall)
+    pub fn create<S>(name: S) -> FirewallRequest<Create, Firewall>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(FIREWALLS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-firewall)
+    pub fn get<S>(id: S) -> FirewallRequest<Get, Firewall>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(FIREWALLS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-firewalls)
+    pub fn list() -> FirewallRequest<List, Vec<Firewall>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(FIREWALLS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// **Note:** The request replaces the whole Firewall, so the name and
+    /// every rule, Droplet, and Tag which should remain must be included.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-firewall)
+    pub fn update<S>(id: S, name: S) -> FirewallRequest<Update, Firewall>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(FIREWALLS_SEGMENT)
+            .push(id.as_ref());
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-firewall)
+    pub fn delete<S>(id: S) -> FirewallRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(FIREWALLS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl FirewallRequest<Create, Firewall> {
+    /// An inbound access rule, allowing traffic to the Droplets of the Firewall.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-firewall)
+    pub fn inbound_rule<T>(mut self, val: T) -> FirewallRequest<Create, Firewall>
+    where
+        T: Into<InboundRule>,
+    {
+        push_rule(self.body_mut(), "inbound_rules", json!(val.into()));
+        self
+    }
+    /// An outbound access rule, allowing traffic from the Droplets of the Firewall.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-firewall)
+    pub fn outbound_rule<T>(mut self, val: T) -> FirewallRequest<Create, Firewall>
+    where
+        T: Into<OutboundRule>,
+    {
+        push_rule(self.body_mut(), "outbound_rules", json!(val.into()));
+        self
+    }
+    /// The IDs of the Droplets to be assigned to the Firewall.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-firewall)
+    pub fn droplets<I, D>(mut self, ids: I) -> FirewallRequest<Create, Firewall>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
+        self.body_mut()["droplet_ids"] = json!(collect_droplet_ids(ids));
+        self
+    }
+    /// The names of the Tags corresponding to Droplets to be assigned to the
+    /// Firewall. Accepts any collection of `&str` or `String`, such as a
+    /// slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-firewall)
+    pub fn tags<I, S>(mut self, tags: I) -> FirewallRequest<Create, Firewall>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(tags));
+        self
+    }
+}
+
+impl FirewallRequest<Update, Firewall> {
+    /// An inbound access rule, allowing traffic to the Droplets of the Firewall.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-firewall)
+    pub fn inbound_rule<T>(mut self, val: T) -> FirewallRequest<Update, Firewall>
+    where
+        T: Into<InboundRule>,
+    {
+        push_rule(self.body_mut(), "inbound_rules", json!(val.into()));
+        self
+    }
+    /// An outbound access rule, allowing traffic from the Droplets of the Firewall.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-firewall)
+    pub fn outbound_rule<T>(mut self, val: T) -> FirewallRequest<Update, Firewall>
+    where
+        T: Into<OutboundRule>,
+    {
+        push_rule(self.body_mut(), "outbound_rules", json!(val.into()));
+        self
+    }
+    /// The IDs of the Droplets to be assigned to the Firewall.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-firewall)
+    pub fn droplets<I, D>(mut self, ids: I) -> FirewallRequest<Update, Firewall>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
+        self.body_mut()["droplet_ids"] = json!(collect_droplet_ids(ids));
+        self
+    }
+    /// The names of the Tags corresponding to Droplets to be assigned to the
+    /// Firewall. Accepts any collection of `&str` or `String`, such as a
+    /// slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-firewall)
+    pub fn tags<I, S>(mut self, tags: I) -> FirewallRequest<Update, Firewall>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(tags));
+        self
+    }
+}
+
+impl FirewallRequest<Get, Firewall> {
+    /// Add access rules to the Firewall, leaving its existing rules in place.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#adding-rules-to-a-firewall)
+    pub fn add_rules(
+        mut self,
+        inbound: Vec<InboundRule>,
+        outbound: Vec<OutboundRule>,
+    ) -> FirewallRequest<Create, ()> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RULES_SEGMENT);
+
+        self.set_body(json!({
+            "inbound_rules": inbound,
+            "outbound_rules": outbound,
+        }));
+
+        self.transmute()
+    }
+    /// Remove access rules from the Firewall. Each rule must match an
+    /// existing rule exactly.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#removing-rules-from-a-firewall)
+    pub fn remove_rules(
+        mut self,
+        inbound: Vec<InboundRule>,
+        outbound: Vec<OutboundRule>,
+    ) -> FirewallRequest<Delete, ()> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RULES_SEGMENT);
+
+        self.set_body(json!({
+            "inbound_rules": inbound,
+            "outbound_rules": outbound,
+        }));
+
+        self.transmute()
+    }
+    /// Assign Droplets (by id) to the Firewall.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#adding-droplets-to-a-firewall)
+    pub fn add_droplets<I, D>(mut self, ids: I) -> FirewallRequest<Create, ()>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DROPLETS_SEGMENT);
+
+        self.set_body(json!({
+            "droplet_ids": collect_droplet_ids(ids),
+        }));
+
+        self.transmute()
+    }
+    /// Remove Droplets (by id) from the Firewall.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#removing-droplets-from-a-firewall)
+    pub fn remove_droplets<I, D>(mut self, ids: I) -> FirewallRequest<Delete, ()>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DROPLETS_SEGMENT);
+
+        self.set_body(json!({
+            "droplet_ids": collect_droplet_ids(ids),
+        }));
+
+        self.transmute()
+    }
+    /// Assign the Droplets with any of the given Tags to the Firewall.
+    /// Accepts any collection of `&str` or `String`, such as a slice, `Vec`,
+    /// or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#adding-tags-to-a-firewall)
+    pub fn add_tags<I, S>(mut self, tags: I) -> FirewallRequest<Create, ()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(TAGS_SEGMENT);
+
+        self.set_body(json!({
+            "tags": collect_strings(tags),
+        }));
+
+        self.transmute()
+    }
+    /// Remove Tags from the Firewall. Accepts any collection of `&str` or
+    /// `String`, such as a slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#removing-tags-from-a-firewall)
+    pub fn remove_tags<I, S>(mut self, tags: I) -> FirewallRequest<Delete, ()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(TAGS_SEGMENT);
+
+        self.set_body(json!({
+            "tags": collect_strings(tags),
+        }));
+
+        self.transmute()
+    }
+}
+
+/// Appends `rule` to the array at `key` of `body`, creating it if needed.
+fn push_rule(body: &mut serde_json::Value, key: &str, rule: serde_json::Value) {
+    if !body[key].is_array() {
+        body[key] = json!([]);
+    }
+
+    body[key]
+        .as_array_mut()
+        .expect("rules should always be an array.")
+        .push(rule);
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct FirewallResponse {
+    firewall: Firewall,
+}
+
+impl HasResponse for Firewall {
+    type Response = FirewallResponse;
+}
+
+impl HasValue for FirewallResponse {
+    type Value = Firewall;
+    fn value(self) -> Firewall {
+        self.firewall
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct FirewallListResponse {
+    firewalls: Vec<Firewall>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<Firewall> {
+    type Response = FirewallListResponse;
+}
+
+impl HasPagination for FirewallListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for FirewallListResponse {
+    type Value = Vec<Firewall>;
+    fn value(self) -> Vec<Firewall> {
+        self.firewalls
+    }
+}
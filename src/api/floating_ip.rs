@@ -1,3 +1,4 @@
+use super::id::DropletId;
 use super::{ApiLinks, ApiMeta};
 use super::{Droplet, Region};
 use super::{HasPagination, HasResponse, HasValue};
@@ -36,6 +37,8 @@ pub struct FloatingIp {
     droplet: Option<Droplet>,
 }
 
+identified_by!(FloatingIp, ip);
+
 impl FloatingIp {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-floating-ips)
     pub fn list() -> FloatingIpRequest<List, Vec<FloatingIp>> {
@@ -48,7 +51,10 @@ impl FloatingIp {
     }
 
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-floating-ip-assigned-to-a-droplet)
-    pub fn for_droplet(id: usize) -> FloatingIpRequest<Create, FloatingIp> {
+    pub fn for_droplet<I>(id: I) -> FloatingIpRequest<Create, FloatingIp>
+    where
+        I: Into<DropletId>,
+    {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -56,7 +62,7 @@ impl FloatingIp {
 
         let mut req = Request::new(url);
         req.set_body(json!({
-            "droplet_id": id,
+            "droplet_id": id.into(),
         }));
         req
     }
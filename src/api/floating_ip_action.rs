@@ -1,4 +1,5 @@
 use super::floating_ip::FloatingIp;
+use super::id::DropletId;
 use super::Action;
 use crate::method::{Create, Get, List};
 use crate::request::{FloatingIpActionRequest, FloatingIpRequest};
@@ -7,6 +8,9 @@ use crate::STATIC_URL_ERROR;
 const FLOATING_IP_ACTIONS_SEGMENT: &str = "actions";
 
 impl FloatingIpRequest<Get, FloatingIp> {
+    /// The actions (such as assignments) which have been taken on the
+    /// floating IP.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-actions-for-a-floating-ip)
     pub fn actions(mut self) -> FloatingIpActionRequest<List, Vec<Action>> {
         self.url_mut()
@@ -17,6 +21,10 @@ impl FloatingIpRequest<Get, FloatingIp> {
         self.transmute()
     }
 
+    /// A single action taken on the floating IP. Assigning and unassigning
+    /// happen asynchronously, so this may be polled to confirm that one
+    /// completed, or see [`Action::wait()`](../api/struct.Action.html#method.wait).
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-floating-ip-action)
     pub fn action(mut self, id: usize) -> FloatingIpActionRequest<Get, Action> {
         self.url_mut()
@@ -43,7 +51,10 @@ impl FloatingIpRequest<Get, FloatingIp> {
     }
 
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#assign-a-floating-ip-to-a-droplet)
-    pub fn assign(mut self, id: usize) -> FloatingIpActionRequest<Create, Action> {
+    pub fn assign<I>(mut self, id: I) -> FloatingIpActionRequest<Create, Action>
+    where
+        I: Into<DropletId>,
+    {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -51,7 +62,7 @@ impl FloatingIpRequest<Get, FloatingIp> {
 
         self.set_body(json!({
             "type": "assign",
-            "droplet_id": id,
+            "droplet_id": id.into(),
         }));
 
         self.transmute()
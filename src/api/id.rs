@@ -0,0 +1,181 @@
+use super::{Region, Size};
+use crate::error::ErrorKind;
+use std::fmt;
+use std::str::FromStr;
+
+// Numeric ids convert from (and into) `usize`, as they are stored on the
+// resources themselves, so that existing call sites keep compiling.
+macro_rules! numeric_id {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(pub usize);
+
+        impl From<usize> for $name {
+            fn from(id: usize) -> Self {
+                $name(id)
+            }
+        }
+
+        impl<'a> From<&'a usize> for $name {
+            fn from(id: &'a usize) -> Self {
+                $name(*id)
+            }
+        }
+
+        impl From<$name> for usize {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<usize> for $name {
+            fn eq(&self, other: &usize) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+// UUID ids convert from (and into) strings.
+macro_rules! uuid_id {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                $name(id)
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(id: &'a str) -> Self {
+                $name(id.to_string())
+            }
+        }
+
+        impl<'a> From<&'a String> for $name {
+            fn from(id: &'a String) -> Self {
+                $name(id.clone())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+// Slugs are parsed (and so validated) from strings, as they are often read
+// from configuration or arguments.
+macro_rules! slug {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl FromStr for $name {
+            type Err = ErrorKind;
+
+            /// Fails with `ErrorKind::InvalidSlug` unless `slug` is made up of
+            /// lowercase letters, digits, and dashes.
+            fn from_str(slug: &str) -> Result<Self, Self::Err> {
+                let valid = !slug.is_empty()
+                    && slug
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+                if valid {
+                    Ok($name(slug.to_string()))
+                } else {
+                    Err(ErrorKind::InvalidSlug(slug.to_string()))
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(slug: $name) -> Self {
+                slug.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+numeric_id!(
+    /// The numeric id of a [`Droplet`](struct.Droplet.html).
+    DropletId
+);
+
+numeric_id!(
+    /// The numeric id of an [`Image`](struct.Image.html), such as a snapshot
+    /// or backup.
+    ImageId
+);
+
+uuid_id!(
+    /// The UUID of a [`Volume`](struct.Volume.html).
+    VolumeId
+);
+
+uuid_id!(
+    /// The UUID of a [`LoadBalancer`](struct.LoadBalancer.html).
+    LoadBalancerId
+);
+
+slug!(
+    /// The slug of a [`Region`](struct.Region.html), such as `nyc3`.
+    RegionSlug
+);
+
+slug!(
+    /// The slug of a [`Size`](struct.Size.html), such as `s-1vcpu-1gb`.
+    SizeSlug
+);
+
+impl<'a> From<&'a Region> for RegionSlug {
+    fn from(region: &'a Region) -> Self {
+        RegionSlug(region.slug().clone())
+    }
+}
+
+impl<'a> From<&'a Size> for SizeSlug {
+    fn from(size: &'a Size) -> Self {
+        SizeSlug(size.slug().clone())
+    }
+}
@@ -31,11 +31,11 @@ pub struct Image {
     /// in question.
     name: String,
     /// The kind of image, describing the duration of how long the image is
-    /// stored. This is either "snapshot" or "backup".
+    /// stored.
     ///
     /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
     #[serde(rename = "type")]
-    kind: String, // 'type' is reserved in Rust.
+    kind: ImageType, // 'type' is reserved in Rust.
     /// This attribute describes the base distribution used for this image.
     distribution: String,
     /// A uniquely identifying string that is associated with each of the
@@ -58,6 +58,23 @@ pub struct Image {
     created_at: DateTime<Utc>,
 }
 
+identified_by!(Image, id);
+
+string_enum!(
+    /// The type of an [`Image`](struct.Image.html) or
+    /// [`CustomImage`](struct.CustomImage.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#images)
+    ImageType {
+        /// The image is a snapshot taken of a Droplet.
+        Snapshot => "snapshot",
+        /// The image is an automatic backup of a Droplet.
+        Backup => "backup",
+        /// The image was imported by the user.
+        Custom => "custom",
+    }
+);
+
 impl Image {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-images)
     pub fn list() -> ImageRequest<List, Vec<Image>> {
@@ -69,8 +86,10 @@ impl Image {
         Request::new(url)
     }
 
+    /// Only the public Linux distribution images.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-distribution-images)
-    pub fn distributions() -> ImageRequest<List, Vec<Image>> {
+    pub fn list_distribution() -> ImageRequest<List, Vec<Image>> {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -81,8 +100,17 @@ impl Image {
         Request::new(url)
     }
 
+    /// The same as `list_distribution()`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-distribution-images)
+    pub fn distributions() -> ImageRequest<List, Vec<Image>> {
+        Image::list_distribution()
+    }
+
+    /// Only the public One-Click application images.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-application-images)
-    pub fn applications() -> ImageRequest<List, Vec<Image>> {
+    pub fn list_application() -> ImageRequest<List, Vec<Image>> {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -93,8 +121,17 @@ impl Image {
         Request::new(url)
     }
 
+    /// The same as `list_application()`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-application-images)
+    pub fn applications() -> ImageRequest<List, Vec<Image>> {
+        Image::list_application()
+    }
+
+    /// Only the private images of the account, such as snapshots, backups, and custom images.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-a-user-s-images)
-    pub fn user() -> ImageRequest<List, Vec<Image>> {
+    pub fn list_user() -> ImageRequest<List, Vec<Image>> {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -105,6 +142,13 @@ impl Image {
         Request::new(url)
     }
 
+    /// The same as `list_user()`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-a-user-s-images)
+    pub fn user() -> ImageRequest<List, Vec<Image>> {
+        Image::list_user()
+    }
+
     /// `id` is either an `id` (numeric) or a `slug` (string).
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-image-by-id)
@@ -121,12 +165,14 @@ impl Image {
         Request::new(url)
     }
 
-    /// `id` is either an `id` (numeric) or a `slug` (string).
+    /// Renames an image. `id` is either an `id` (numeric) or a `slug`
+    /// (string).
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-image)
-    pub fn update<S>(id: S) -> ImageRequest<Update, Image>
+    pub fn update<S, N>(id: S, name: N) -> ImageRequest<Update, Image>
     where
         S: Display,
+        N: Display + Serialize,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
@@ -134,10 +180,15 @@ impl Image {
             .push(IMAGES_SEGMENT)
             .push(&format!("{}", id));
 
-        Request::new(url)
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+        }));
+        req
     }
 
-    /// `id` is either an `id` (numeric) or a `slug` (string).
+    /// Deletes a snapshot, backup, or custom image. `id` is either an `id`
+    /// (numeric) or a `slug` (string).
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-an-image)
     pub fn delete<S>(id: S) -> ImageRequest<Delete, ()>
@@ -18,6 +18,9 @@ impl ImageRequest<Get, Image> {
 
         self.transmute()
     }
+    /// Copies the image to the region with the slug `region`, so Droplets can
+    /// be created from it there.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#transfer-an-image)
     pub fn transfer<S>(mut self, region: S) -> ImageActionRequest<Create, Action>
     where
@@ -35,7 +38,9 @@ impl ImageRequest<Get, Image> {
 
         self.transmute()
     }
-
+    /// Converts a backup image into a snapshot, so it is kept after the
+    /// Droplet is destroyed.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#convert-an-image-to-a-snapshot)
     pub fn convert(mut self) -> ImageActionRequest<Create, Action> {
         self.url_mut()
@@ -0,0 +1,330 @@
+use self::invoice_fields::{Address, Charges};
+use super::amount::Amount;
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::client::{HttpClient, StreamResponse};
+use crate::method::{Get, List};
+use crate::request::InvoiceRequest;
+use crate::request::Request;
+use crate::DigitalOcean;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use failure::Error;
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const CUSTOMERS_SEGMENT: &str = "customers";
+const MY_SEGMENT: &str = "my";
+const INVOICES_SEGMENT: &str = "invoices";
+const SUMMARY_SEGMENT: &str = "summary";
+const CSV_SEGMENT: &str = "csv";
+const PDF_SEGMENT: &str = "pdf";
+
+/// An invoice for a billing period of the account.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#invoices)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Invoice {
+    /// The UUID of the invoice, which is used to reference it.
+    invoice_uuid: String,
+    /// The total amount of the invoice.
+    amount: Amount,
+    /// The billing period of the invoice, in the format "YYYY-MM".
+    invoice_period: String,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the invoice was last updated.
+    updated_at: DateTime<Utc>,
+}
+
+/// A charge on an invoice, for the usage of a single resource.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-invoice-by-uuid)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct InvoiceItem {
+    /// The name of the product being charged for, such as "Droplets".
+    product: String,
+    /// The UUID of the resource being charged for, if any.
+    #[serde(default)]
+    resource_uuid: String,
+    /// The id of the resource being charged for, if any.
+    #[serde(default)]
+    resource_id: String,
+    /// A description of the group the charge belongs to, if any.
+    #[serde(default)]
+    group_description: String,
+    /// A description of the charge, such as the size of the Droplet.
+    description: String,
+    /// The amount of the charge.
+    amount: Amount,
+    /// How long the resource was used for, in `duration_unit`s.
+    duration: String,
+    /// The unit of `duration`, such as "Hours".
+    duration_unit: String,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the charged usage started.
+    start_time: DateTime<Utc>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the charged usage ended.
+    end_time: DateTime<Utc>,
+    /// The name of the project the resource belongs to, if any.
+    #[serde(default)]
+    project_name: String,
+}
+
+/// A summary of an invoice, broken down by the kind of charge.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-invoice-summary-by-uuid)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct InvoiceSummary {
+    /// The UUID of the invoice.
+    invoice_uuid: String,
+    /// The billing period of the invoice, in the format "YYYY-MM".
+    billing_period: String,
+    /// The total amount of the invoice.
+    amount: Amount,
+    /// The name of the account holder.
+    user_name: String,
+    /// The billing address of the account holder.
+    #[serde(default)]
+    user_billing_address: Option<Address>,
+    /// The company of the account holder.
+    #[serde(default)]
+    user_company: String,
+    /// The email of the account holder.
+    user_email: String,
+    /// The charges for products used.
+    product_charges: Charges,
+    /// The charges for usage beyond what was included.
+    overages: Charges,
+    /// The taxes applied.
+    taxes: Charges,
+    /// The credits and adjustments applied.
+    credits_and_adjustments: Charges,
+}
+
+/// The raw contents of an invoice document, such as a CSV or a PDF.
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct InvoiceDocument {
+    /// The bytes of the document.
+    bytes: Vec<u8>,
+}
+
+/// Fields which exists inside invoices.
+pub mod invoice_fields {
+    use super::Amount;
+    use chrono::{DateTime, Utc};
+
+    /// This exists in the `user_billing_address` field of an invoice summary.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Address {
+        #[serde(default)]
+        pub address_line1: String,
+        #[serde(default)]
+        pub address_line2: String,
+        #[serde(default)]
+        pub city: String,
+        #[serde(default)]
+        pub region: String,
+        #[serde(default)]
+        pub postal_code: String,
+        #[serde(default)]
+        pub country_iso2_code: String,
+        pub created_at: Option<DateTime<Utc>>,
+        pub updated_at: Option<DateTime<Utc>>,
+    }
+
+    /// This exists in the charge fields, such as `product_charges`, of an
+    /// invoice summary.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Charges {
+        pub name: String,
+        pub amount: Amount,
+        #[serde(default)]
+        pub items: Vec<ChargeItem>,
+    }
+
+    /// This exists in the `items` field of an invoice summary's charges.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct ChargeItem {
+        pub name: String,
+        pub amount: Amount,
+        #[serde(default)]
+        pub count: String,
+    }
+}
+
+impl Invoice {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-invoices)
+    pub fn list() -> InvoiceRequest<List, Vec<Invoice>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CUSTOMERS_SEGMENT)
+            .push(MY_SEGMENT)
+            .push(INVOICES_SEGMENT);
+
+        Request::new(url)
+    }
+    /// The charges on the invoice. The summary and documents of the invoice
+    /// are retrieved by chaining `.summary()`, `.csv()`, or `.pdf()` onto
+    /// this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-invoice-by-uuid)
+    pub fn get<S>(uuid: S) -> InvoiceRequest<List, Vec<InvoiceItem>>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CUSTOMERS_SEGMENT)
+            .push(MY_SEGMENT)
+            .push(INVOICES_SEGMENT)
+            .push(uuid.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl InvoiceRequest<List, Vec<InvoiceItem>> {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-invoice-summary-by-uuid)
+    pub fn summary(mut self) -> InvoiceRequest<Get, InvoiceSummary> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(SUMMARY_SEGMENT);
+
+        self.transmute()
+    }
+    /// The invoice as a CSV document.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-invoice-csv-by-uuid)
+    pub fn csv(mut self) -> InvoiceRequest<Get, InvoiceDocument> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CSV_SEGMENT);
+
+        self.transmute()
+    }
+    /// The invoice as a PDF document.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-invoice-pdf-by-uuid)
+    pub fn pdf(mut self) -> InvoiceRequest<Get, InvoiceDocument> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(PDF_SEGMENT);
+
+        self.transmute()
+    }
+}
+
+impl InvoiceRequest<Get, InvoiceDocument> {
+    /// Executes the request, returning the document to be read as it is
+    /// downloaded instead of buffering all of it in memory.
+    ///
+    /// ```rust,no_run
+    /// # use digitalocean::prelude::*;
+    /// # use std::fs::File;
+    /// # use std::io;
+    /// # let client = DigitalOcean::new("API_KEY").unwrap();
+    /// let mut pdf = Invoice::get("22737513-0ea7-4206-8ceb-98a575af7681")
+    ///     .pdf()
+    ///     .execute_stream(&client)
+    ///     .unwrap();
+    /// io::copy(&mut pdf, &mut File::create("invoice.pdf").unwrap()).unwrap();
+    /// ```
+    pub fn execute_stream<C>(self, instance: &DigitalOcean<C>) -> Result<StreamResponse, Error>
+    where
+        C: HttpClient,
+    {
+        instance.get_stream(self)
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InvoiceListResponse {
+    invoices: Vec<Invoice>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<Invoice> {
+    type Response = InvoiceListResponse;
+}
+
+impl HasPagination for InvoiceListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for InvoiceListResponse {
+    type Value = Vec<Invoice>;
+    fn value(self) -> Vec<Invoice> {
+        self.invoices
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InvoiceItemListResponse {
+    invoice_items: Vec<InvoiceItem>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<InvoiceItem> {
+    type Response = InvoiceItemListResponse;
+}
+
+impl HasPagination for InvoiceItemListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for InvoiceItemListResponse {
+    type Value = Vec<InvoiceItem>;
+    fn value(self) -> Vec<InvoiceItem> {
+        self.invoice_items
+    }
+}
+
+// The summary is not wrapped in an object.
+impl HasResponse for InvoiceSummary {
+    type Response = InvoiceSummary;
+}
+
+impl HasValue for InvoiceSummary {
+    type Value = InvoiceSummary;
+    fn value(self) -> InvoiceSummary {
+        self
+    }
+}
+
+// Invoice documents are returned as is, rather than as JSON.
+impl HasResponse for InvoiceDocument {
+    type Response = InvoiceDocument;
+
+    fn parse(body: &[u8]) -> Result<InvoiceDocument, Error> {
+        Ok(InvoiceDocument {
+            bytes: body.to_vec(),
+        })
+    }
+}
+
+impl HasValue for InvoiceDocument {
+    type Value = InvoiceDocument;
+    fn value(self) -> InvoiceDocument {
+        self
+    }
+}
@@ -0,0 +1,767 @@
+use self::kubernetes_fields::{ClusterStatus, MaintenancePolicy, Node, NodePoolSpec, Taint};
+use super::{collect_strings, HasPagination, HasResponse, HasValue};
+use super::{ApiLinks, ApiMeta};
+use crate::client::REDACTED;
+use crate::error::ErrorKind;
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::KubernetesClusterRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use failure::Error;
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use url::Url;
+
+const KUBERNETES_SEGMENT: &str = "kubernetes";
+const CLUSTERS_SEGMENT: &str = "clusters";
+const KUBECONFIG_SEGMENT: &str = "kubeconfig";
+const CREDENTIALS_SEGMENT: &str = "credentials";
+const UPGRADES_SEGMENT: &str = "upgrades";
+const UPGRADE_SEGMENT: &str = "upgrade";
+
+/// A DigitalOcean Kubernetes (DOKS) cluster.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#kubernetes)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct KubernetesCluster {
+    /// A unique ID that can be used to identify and reference a Kubernetes
+    /// cluster.
+    id: String,
+    /// A human-readable name for a Kubernetes cluster.
+    name: String,
+    /// The slug identifier for the region where the Kubernetes cluster is
+    /// located.
+    region: String,
+    /// The slug identifier for the version of Kubernetes used for the
+    /// cluster.
+    version: String,
+    /// The range of IP addresses in the overlay network of the Kubernetes
+    /// cluster in CIDR notation.
+    cluster_subnet: String,
+    /// The range of assignable IP addresses for services running in the
+    /// Kubernetes cluster in CIDR notation.
+    service_subnet: String,
+    /// The ID of the VPC where the Kubernetes cluster is located.
+    #[serde(default)]
+    vpc_uuid: String,
+    /// The public IPv4 address of the Kubernetes master node.
+    ipv4: String,
+    /// The base URL of the API server on the Kubernetes master node.
+    endpoint: String,
+    /// The tags applied to the Kubernetes cluster.
+    tags: Vec<String>,
+    /// The node pools of the Kubernetes cluster.
+    node_pools: Vec<NodePool>,
+    /// The window during which the cluster may be automatically upgraded.
+    maintenance_policy: Option<MaintenancePolicy>,
+    /// If true, the cluster will be automatically upgraded to new patch
+    /// releases during its maintenance window.
+    auto_upgrade: bool,
+    /// If true, new nodes are created before old ones are drained during an
+    /// upgrade.
+    #[serde(default)]
+    surge_upgrade: bool,
+    /// The current state of the Kubernetes cluster.
+    status: ClusterStatus,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the Kubernetes cluster was created.
+    created_at: DateTime<Utc>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the Kubernetes cluster was last updated.
+    updated_at: DateTime<Utc>,
+}
+
+identified_by!(KubernetesCluster, id);
+
+/// A set of nodes of the same size within a Kubernetes cluster.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#kubernetes)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct NodePool {
+    /// A unique ID that can be used to identify and reference a node pool.
+    id: String,
+    /// A human-readable name for the node pool.
+    name: String,
+    /// The slug identifier for the type of Droplet used as workers in the
+    /// node pool.
+    size: String,
+    /// The number of Droplet instances in the node pool.
+    count: usize,
+    /// The tags applied to the node pool.
+    tags: Vec<String>,
+    /// The Kubernetes labels applied to the nodes of the node pool.
+    labels: Option<HashMap<String, String>>,
+    /// The Kubernetes taints applied to the nodes of the node pool.
+    #[serde(default)]
+    taints: Vec<Taint>,
+    /// If true, the node pool is scaled automatically between `min_nodes`
+    /// and `max_nodes`.
+    #[serde(default)]
+    auto_scale: bool,
+    /// The minimum number of nodes the node pool may be scaled down to.
+    #[serde(default)]
+    min_nodes: usize,
+    /// The maximum number of nodes the node pool may be scaled up to.
+    #[serde(default)]
+    max_nodes: usize,
+    /// The nodes in the node pool.
+    nodes: Vec<Node>,
+}
+
+/// The kubeconfig file of a Kubernetes cluster, for use with `kubectl`.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-the-kubeconfig-for-a-kubernetes-cluster)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct Kubeconfig {
+    /// The contents of the kubeconfig file, as YAML.
+    yaml: String,
+}
+
+/// Credentials for authenticating to the API server of a Kubernetes cluster,
+/// such as from an `exec` credential plugin of a kubeconfig.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-credentials-for-a-kubernetes-cluster)
+#[derive(Deserialize, Serialize, Clone, Getters)]
+#[get = "pub"]
+pub struct KubernetesCredentials {
+    /// The URL of the API server of the cluster.
+    server: String,
+    /// The base64 encoded certificate of the authority which signed the
+    /// certificate of the API server.
+    certificate_authority_data: String,
+    /// The base64 encoded client certificate, when the credentials are a
+    /// certificate rather than a token.
+    client_certificate_data: Option<String>,
+    /// The base64 encoded key of the client certificate.
+    client_key_data: Option<String>,
+    /// The bearer token to authenticate with, when the credentials are a
+    /// token rather than a certificate.
+    token: Option<String>,
+    /// When the credentials stop being accepted.
+    expires_at: DateTime<Utc>,
+}
+
+impl fmt::Debug for KubernetesCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KubernetesCredentials")
+            .field("server", &self.server)
+            .field(
+                "certificate_authority_data",
+                &self.certificate_authority_data,
+            )
+            .field("client_certificate_data", &self.client_certificate_data)
+            .field(
+                "client_key_data",
+                &self.client_key_data.as_ref().map(|_| REDACTED),
+            )
+            .field("token", &self.token.as_ref().map(|_| REDACTED))
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// A version of Kubernetes which a cluster may run.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-available-upgrades-for-an-existing-kubernetes-cluster)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct KubernetesVersion {
+    /// The slug of the version, such as "1.16.6-do.2", which clusters are
+    /// created or upgraded with.
+    slug: String,
+    /// The upstream version of Kubernetes, such as "1.16.6".
+    kubernetes_version: String,
+    /// The features which clusters of this version support.
+    #[serde(default)]
+    supported_features: Vec<String>,
+}
+
+/// Fields which exists inside Kubernetes clusters.
+pub mod kubernetes_fields {
+    use chrono::{DateTime, Utc};
+    use std::collections::HashMap;
+
+    /// This exists in the `status` field of a Kubernetes cluster.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct ClusterStatus {
+        /// The current state of the cluster.
+        pub state: ClusterState,
+        /// An optional message providing additional information about the
+        /// current cluster state.
+        #[serde(default)]
+        pub message: String,
+    }
+
+    /// This exists in the `maintenance_policy` field of a Kubernetes cluster.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct MaintenancePolicy {
+        /// The start time in UTC of the maintenance window, such as "15:00".
+        pub start_time: String,
+        /// The duration of the maintenance window, such as "4h0m0s".
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        pub duration: String,
+        /// The day of the maintenance window, such as "any" or "monday".
+        pub day: String,
+    }
+
+    /// This exists in the `nodes` field of a node pool.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Node {
+        /// A unique ID that can be used to identify and reference the node.
+        pub id: String,
+        /// An automatically generated, human-readable name for the node.
+        pub name: String,
+        /// The current state of the node.
+        pub status: NodeStatus,
+        /// The ID of the Droplet used for the worker node.
+        #[serde(default)]
+        pub droplet_id: String,
+        pub created_at: DateTime<Utc>,
+        pub updated_at: DateTime<Utc>,
+    }
+
+    /// This exists in the `status` field of a node.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct NodeStatus {
+        /// The current state of the node.
+        pub state: NodeState,
+    }
+
+    string_enum!(
+        /// The state of a Kubernetes cluster, in its
+        /// [`ClusterStatus`](struct.ClusterStatus.html).
+        ///
+        /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#kubernetes)
+        ClusterState {
+            /// The cluster is running.
+            Running => "running",
+            /// The cluster is being created.
+            Provisioning => "provisioning",
+            /// The cluster is running, but some of its components are not healthy.
+            Degraded => "degraded",
+            /// The cluster is not working.
+            Error => "error",
+            /// The cluster has been deleted.
+            Deleted => "deleted",
+            /// The cluster is being upgraded.
+            Upgrading => "upgrading",
+            /// The cluster is being deleted.
+            Deleting => "deleting",
+        }
+    );
+
+    string_enum!(
+        /// The state of a node of a node pool, in its
+        /// [`NodeStatus`](struct.NodeStatus.html).
+        ///
+        /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#kubernetes)
+        NodeState {
+            /// The node is being created.
+            Provisioning => "provisioning",
+            /// The node is running.
+            Running => "running",
+            /// Pods are being evicted from the node.
+            Draining => "draining",
+            /// The node is being deleted.
+            Deleting => "deleting",
+        }
+    );
+
+    /// This exists in the `taints` field of a node pool.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Taint {
+        pub key: String,
+        pub value: String,
+        /// This may be "NoSchedule", "PreferNoSchedule", or "NoExecute".
+        pub effect: String,
+    }
+    impl Taint {
+        pub fn new<S>(key: S, value: S, effect: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            Taint {
+                key: key.as_ref().to_string(),
+                value: value.as_ref().to_string(),
+                effect: effect.as_ref().to_string(),
+            }
+        }
+    }
+
+    /// A node pool to be created.
+    ///
+    /// Used when creating a Kubernetes cluster or node pool.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct NodePoolSpec {
+        /// The slug identifier for the type of Droplet to be used as workers
+        /// in the node pool.
+        pub size: String,
+        /// A human-readable name for the node pool.
+        pub name: String,
+        /// The number of Droplet instances in the node pool.
+        pub count: usize,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub tags: Vec<String>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        pub labels: HashMap<String, String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub taints: Vec<Taint>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub auto_scale: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub min_nodes: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub max_nodes: Option<usize>,
+    }
+    impl NodePoolSpec {
+        pub fn new<S>(name: S, size: S, count: usize) -> Self
+        where
+            S: AsRef<str>,
+        {
+            NodePoolSpec {
+                size: size.as_ref().to_string(),
+                name: name.as_ref().to_string(),
+                count,
+                tags: Vec::new(),
+                labels: HashMap::new(),
+                taints: Vec::new(),
+                auto_scale: None,
+                min_nodes: None,
+                max_nodes: None,
+            }
+        }
+        /// Accepts any collection of `&str` or `String`, such as a slice,
+        /// `Vec`, or array.
+        pub fn tags<I, S>(mut self, tags: I) -> Self
+        where
+            I: IntoIterator<Item = S>,
+            S: AsRef<str>,
+        {
+            self.tags = super::super::collect_strings(tags);
+            self
+        }
+        pub fn label<S>(mut self, key: S, value: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            self.labels
+                .insert(key.as_ref().to_string(), value.as_ref().to_string());
+            self
+        }
+        pub fn taint(mut self, taint: Taint) -> Self {
+            self.taints.push(taint);
+            self
+        }
+        /// Scale the node pool automatically between `min_nodes` and `max_nodes`.
+        pub fn auto_scale(mut self, min_nodes: usize, max_nodes: usize) -> Self {
+            self.auto_scale = Some(true);
+            self.min_nodes = Some(min_nodes);
+            self.max_nodes = Some(max_nodes);
+            self
+        }
+    }
+    impl<S> From<(S, S, usize)> for NodePoolSpec
+    where
+        S: AsRef<str>,
+    {
+        fn from(val: (S, S, usize)) -> Self {
+            NodePoolSpec::new(val.0, val.1, val.2)
+        }
+    }
+}
+
+impl KubernetesCluster {
+    /// Be sure to include a node pool by chaining `.node_pool()` onto this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-kubernetes-cluster)
+    pub fn create<S>(
+        name: S,
+        region: S,
+        version: S,
+    ) -> KubernetesClusterRequest<Create, KubernetesCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(KUBERNETES_SEGMENT)
+            .push(CLUSTERS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "region": region,
+            "version": version,
+            "node_pools": [],
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-kubernetes-cluster)
+    pub fn get<S>(id: S) -> KubernetesClusterRequest<Get, KubernetesCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(KUBERNETES_SEGMENT)
+            .push(CLUSTERS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-kubernetes-clusters)
+    pub fn list() -> KubernetesClusterRequest<List, Vec<KubernetesCluster>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(KUBERNETES_SEGMENT)
+            .push(CLUSTERS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-kubernetes-cluster)
+    pub fn update<S>(id: S, name: S) -> KubernetesClusterRequest<Update, KubernetesCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(KUBERNETES_SEGMENT)
+            .push(CLUSTERS_SEGMENT)
+            .push(id.as_ref());
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-kubernetes-cluster)
+    pub fn delete<S>(id: S) -> KubernetesClusterRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(KUBERNETES_SEGMENT)
+            .push(CLUSTERS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl KubernetesClusterRequest<Create, KubernetesCluster> {
+    /// A node pool of the cluster. At least one node pool is required when
+    /// creating a new cluster.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-kubernetes-cluster)
+    pub fn node_pool<T>(mut self, val: T) -> KubernetesClusterRequest<Create, KubernetesCluster>
+    where
+        T: Into<NodePoolSpec>,
+    {
+        if !self.body_mut()["node_pools"].is_array() {
+            self.body_mut()["node_pools"] = json!([]);
+        }
+
+        self.body_mut()["node_pools"]
+            .as_array_mut()
+            .expect("node_pools should always be an array.")
+            .push(json!(val.into()));
+        self
+    }
+    /// The tags to apply to the cluster. Accepts any collection of `&str` or
+    /// `String`, such as a slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-kubernetes-cluster)
+    pub fn tags<I, S>(mut self, tags: I) -> KubernetesClusterRequest<Create, KubernetesCluster>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(tags));
+        self
+    }
+    /// The ID of the VPC where the cluster will be located.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-kubernetes-cluster)
+    pub fn vpc<S>(mut self, vpc_uuid: S) -> KubernetesClusterRequest<Create, KubernetesCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["vpc_uuid"] = json!(vpc_uuid);
+        self
+    }
+    /// Whether the cluster will be automatically upgraded to new patch
+    /// releases during its maintenance window.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-kubernetes-cluster)
+    pub fn auto_upgrade(
+        mut self,
+        val: bool,
+    ) -> KubernetesClusterRequest<Create, KubernetesCluster> {
+        self.body_mut()["auto_upgrade"] = json!(val);
+        self
+    }
+    /// Whether new nodes are created before old ones are drained during an
+    /// upgrade.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-kubernetes-cluster)
+    pub fn surge_upgrade(
+        mut self,
+        val: bool,
+    ) -> KubernetesClusterRequest<Create, KubernetesCluster> {
+        self.body_mut()["surge_upgrade"] = json!(val);
+        self
+    }
+    /// The window during which the cluster may be automatically upgraded.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-kubernetes-cluster)
+    pub fn maintenance_policy<S>(
+        mut self,
+        start_time: S,
+        day: S,
+    ) -> KubernetesClusterRequest<Create, KubernetesCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["maintenance_policy"] = json!({
+            "start_time": start_time,
+            "day": day,
+        });
+        self
+    }
+}
+
+impl KubernetesClusterRequest<Update, KubernetesCluster> {
+    /// The tags to apply to the cluster. Accepts any collection of `&str` or
+    /// `String`, such as a slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-kubernetes-cluster)
+    pub fn tags<I, S>(mut self, tags: I) -> KubernetesClusterRequest<Update, KubernetesCluster>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(tags));
+        self
+    }
+    /// Whether the cluster will be automatically upgraded to new patch
+    /// releases during its maintenance window.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-kubernetes-cluster)
+    pub fn auto_upgrade(
+        mut self,
+        val: bool,
+    ) -> KubernetesClusterRequest<Update, KubernetesCluster> {
+        self.body_mut()["auto_upgrade"] = json!(val);
+        self
+    }
+    /// Whether new nodes are created before old ones are drained during an
+    /// upgrade.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-kubernetes-cluster)
+    pub fn surge_upgrade(
+        mut self,
+        val: bool,
+    ) -> KubernetesClusterRequest<Update, KubernetesCluster> {
+        self.body_mut()["surge_upgrade"] = json!(val);
+        self
+    }
+    /// The window during which the cluster may be automatically upgraded.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-kubernetes-cluster)
+    pub fn maintenance_policy<S>(
+        mut self,
+        start_time: S,
+        day: S,
+    ) -> KubernetesClusterRequest<Update, KubernetesCluster>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["maintenance_policy"] = json!({
+            "start_time": start_time,
+            "day": day,
+        });
+        self
+    }
+}
+
+impl KubernetesClusterRequest<Get, KubernetesCluster> {
+    /// The kubeconfig file of the cluster, for use with `kubectl`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-the-kubeconfig-for-a-kubernetes-cluster)
+    pub fn kubeconfig(mut self) -> KubernetesClusterRequest<Get, Kubeconfig> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(KUBECONFIG_SEGMENT);
+
+        self.transmute()
+    }
+
+    /// Short-lived credentials for the API server of the cluster, such as
+    /// for an `exec` credential plugin which keeps a kubeconfig from
+    /// needing to hold them.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-credentials-for-a-kubernetes-cluster)
+    pub fn credentials(mut self) -> KubernetesClusterRequest<Get, KubernetesCredentials> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(CREDENTIALS_SEGMENT);
+
+        self.transmute()
+    }
+
+    /// The versions the cluster may be upgraded to. It is empty when the
+    /// cluster runs the latest version.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-available-upgrades-for-an-existing-kubernetes-cluster)
+    pub fn available_upgrades(mut self) -> KubernetesClusterRequest<Get, Vec<KubernetesVersion>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(UPGRADES_SEGMENT);
+
+        self.transmute()
+    }
+
+    /// Upgrade the cluster to the version with the given slug, which should
+    /// be one of its [`available_upgrades()`](#method.available_upgrades).
+    /// The upgrade happens in the background.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#upgrade-a-kubernetes-cluster)
+    pub fn upgrade<S>(mut self, version: S) -> KubernetesClusterRequest<Create, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(UPGRADE_SEGMENT);
+
+        self.set_body(json!({ "version": version }));
+
+        self.transmute()
+    }
+}
+
+impl KubernetesClusterRequest<Get, KubernetesCredentials> {
+    /// How long the credentials should be accepted for. By default they
+    /// expire after seven days.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-credentials-for-a-kubernetes-cluster)
+    pub fn expiry_seconds(mut self, seconds: u64) -> Self {
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("expiry_seconds", &seconds.to_string());
+
+        self
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct KubernetesClusterResponse {
+    kubernetes_cluster: KubernetesCluster,
+}
+
+impl HasResponse for KubernetesCluster {
+    type Response = KubernetesClusterResponse;
+}
+
+impl HasValue for KubernetesClusterResponse {
+    type Value = KubernetesCluster;
+    fn value(self) -> KubernetesCluster {
+        self.kubernetes_cluster
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct KubernetesClusterListResponse {
+    kubernetes_clusters: Vec<KubernetesCluster>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<KubernetesCluster> {
+    type Response = KubernetesClusterListResponse;
+}
+
+impl HasPagination for KubernetesClusterListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for KubernetesClusterListResponse {
+    type Value = Vec<KubernetesCluster>;
+    fn value(self) -> Vec<KubernetesCluster> {
+        self.kubernetes_clusters
+    }
+}
+
+// The kubeconfig is returned as YAML rather than JSON.
+impl HasResponse for Kubeconfig {
+    type Response = Kubeconfig;
+
+    fn parse(body: &[u8]) -> Result<Kubeconfig, Error> {
+        Ok(Kubeconfig {
+            yaml: String::from_utf8(body.to_vec())?,
+        })
+    }
+}
+
+impl HasValue for Kubeconfig {
+    type Value = Kubeconfig;
+    fn value(self) -> Kubeconfig {
+        self
+    }
+}
+
+// The credentials are not wrapped in an object.
+impl HasResponse for KubernetesCredentials {
+    type Response = KubernetesCredentials;
+
+    // The body is the credentials, so it must not end up in the error.
+    fn parse(body: &[u8]) -> Result<Self::Response, Error> {
+        serde_json::from_slice(body).map_err(|e| ErrorKind::deserialize_secret(e).into())
+    }
+}
+
+impl HasValue for KubernetesCredentials {
+    type Value = KubernetesCredentials;
+    fn value(self) -> KubernetesCredentials {
+        self
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct KubernetesUpgradesResponse {
+    // This is `null` rather than empty when there are no upgrades.
+    available_upgrade_versions: Option<Vec<KubernetesVersion>>,
+}
+
+impl HasResponse for Vec<KubernetesVersion> {
+    type Response = KubernetesUpgradesResponse;
+}
+
+impl HasValue for KubernetesUpgradesResponse {
+    type Value = Vec<KubernetesVersion>;
+    fn value(self) -> Vec<KubernetesVersion> {
+        self.available_upgrade_versions.unwrap_or_default()
+    }
+}
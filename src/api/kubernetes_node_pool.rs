@@ -0,0 +1,233 @@
+use super::kubernetes::{KubernetesCluster, NodePool};
+use super::kubernetes_fields::{NodePoolSpec, Taint};
+use super::{collect_strings, HasPagination, HasResponse, HasValue};
+use super::{ApiLinks, ApiMeta};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::{KubernetesClusterRequest, NodePoolRequest};
+use crate::STATIC_URL_ERROR;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+use url::Url;
+
+const NODE_POOLS_SEGMENT: &str = "node_pools";
+const NODES_SEGMENT: &str = "nodes";
+const RECYCLE_SEGMENT: &str = "recycle";
+
+impl KubernetesClusterRequest<Get, KubernetesCluster> {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-node-pools-in-a-kubernetes-clusters)
+    pub fn node_pools(mut self) -> NodePoolRequest<List, Vec<NodePool>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(NODE_POOLS_SEGMENT);
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-a-node-pool-for-a-kubernetes-cluster)
+    pub fn node_pool<S>(mut self, id: S) -> NodePoolRequest<Get, NodePool>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(NODE_POOLS_SEGMENT)
+            .push(id.as_ref());
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#add-a-node-pool-to-a-kubernetes-cluster)
+    pub fn create_node_pool<T>(mut self, node_pool: T) -> NodePoolRequest<Create, NodePool>
+    where
+        T: Into<NodePoolSpec>,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(NODE_POOLS_SEGMENT);
+
+        self.set_body(json!(node_pool.into()));
+
+        self.transmute()
+    }
+    /// **Note:** Any attribute that is not provided will be reset to its default value.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-node-pool-in-a-kubernetes-cluster)
+    pub fn update_node_pool<S>(
+        mut self,
+        id: S,
+        name: S,
+        count: usize,
+    ) -> NodePoolRequest<Update, NodePool>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(NODE_POOLS_SEGMENT)
+            .push(id.as_ref());
+
+        self.set_body(json!({
+            "name": name,
+            "count": count,
+        }));
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-node-pool-in-a-kubernetes-cluster)
+    pub fn delete_node_pool<S>(mut self, id: S) -> NodePoolRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(NODE_POOLS_SEGMENT)
+            .push(id.as_ref());
+
+        self.transmute()
+    }
+}
+
+impl NodePoolRequest<Update, NodePool> {
+    /// The tags to apply to the node pool. Accepts any collection of `&str` or
+    /// `String`, such as a slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-node-pool-in-a-kubernetes-cluster)
+    pub fn tags<I, S>(mut self, tags: I) -> NodePoolRequest<Update, NodePool>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(tags));
+        self
+    }
+    /// The Kubernetes labels to apply to the nodes of the node pool.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-node-pool-in-a-kubernetes-cluster)
+    pub fn labels(mut self, labels: HashMap<String, String>) -> NodePoolRequest<Update, NodePool> {
+        self.body_mut()["labels"] = json!(labels);
+        self
+    }
+    /// The Kubernetes taints to apply to the nodes of the node pool.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-node-pool-in-a-kubernetes-cluster)
+    pub fn taints(mut self, taints: Vec<Taint>) -> NodePoolRequest<Update, NodePool> {
+        self.body_mut()["taints"] = json!(taints);
+        self
+    }
+    /// Scale the node pool automatically between `min_nodes` and `max_nodes`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-node-pool-in-a-kubernetes-cluster)
+    pub fn auto_scale(
+        mut self,
+        min_nodes: usize,
+        max_nodes: usize,
+    ) -> NodePoolRequest<Update, NodePool> {
+        self.body_mut()["auto_scale"] = json!(true);
+        self.body_mut()["min_nodes"] = json!(min_nodes);
+        self.body_mut()["max_nodes"] = json!(max_nodes);
+        self
+    }
+}
+
+impl NodePoolRequest<Get, NodePool> {
+    /// Replace the given nodes (by id) of the node pool with new ones.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#recycle-nodes-in-a-kubernetes-cluster)
+    pub fn recycle<S>(mut self, nodes: &[S]) -> NodePoolRequest<Create, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RECYCLE_SEGMENT);
+
+        self.set_body(json!({
+            "nodes": nodes,
+        }));
+
+        self.transmute()
+    }
+    /// Delete a node (by id) of the node pool. The node is drained first and
+    /// the node pool is scaled down to match.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-node-in-a-kubernetes-cluster)
+    pub fn delete_node<S>(mut self, id: S) -> NodePoolRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(NODES_SEGMENT)
+            .push(id.as_ref());
+
+        self.transmute()
+    }
+    /// Replace a node (by id) of the node pool with a new one.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-node-in-a-kubernetes-cluster)
+    pub fn replace_node<S>(mut self, id: S) -> NodePoolRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(NODES_SEGMENT)
+            .push(id.as_ref());
+
+        self.url_mut().query_pairs_mut().append_pair("replace", "1");
+
+        self.transmute()
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NodePoolResponse {
+    node_pool: NodePool,
+}
+
+impl HasResponse for NodePool {
+    type Response = NodePoolResponse;
+}
+
+impl HasValue for NodePoolResponse {
+    type Value = NodePool;
+    fn value(self) -> NodePool {
+        self.node_pool
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NodePoolListResponse {
+    node_pools: Vec<NodePool>,
+    // Node pools are not paginated, but may be in the future.
+    #[serde(default)]
+    links: ApiLinks,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+impl HasResponse for Vec<NodePool> {
+    type Response = NodePoolListResponse;
+}
+
+impl HasPagination for NodePoolListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for NodePoolListResponse {
+    type Value = Vec<NodePool>;
+    fn value(self) -> Vec<NodePool> {
+        self.node_pools
+    }
+}
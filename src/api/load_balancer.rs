@@ -1,12 +1,22 @@
 use self::load_balancer_fields::{ForwardingRule, HealthCheck, StickySessions};
+use super::id::{DropletId, LoadBalancerId};
 use super::Region;
+use super::{collect_droplet_ids, HasPagination, HasResponse, HasValue};
 use super::{ApiLinks, ApiMeta};
-use super::{HasPagination, HasResponse, HasValue};
+#[cfg(feature = "async")]
+use crate::client::AsyncHttpClient;
+use crate::client::HttpClient;
+use crate::error::ErrorKind;
 use crate::method::{Create, Delete, Get, List, Update};
+#[cfg(feature = "async")]
+use crate::request::ExecutableAsync;
 use crate::request::LoadBalancerRequest;
-use crate::request::Request;
+use crate::request::{Executable, Request};
+use crate::wait::{self, WaitPolicy};
+use crate::DigitalOcean;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use chrono::{DateTime, Utc};
+use failure::Error;
 use getset::{Getters, Setters};
 use serde::Serialize;
 use std::fmt::Display;
@@ -35,9 +45,8 @@ pub struct LoadBalancer {
     /// will be selected by a client. It must be either "round_robin" or
     /// "least_connections".
     algorithm: String,
-    /// A status string indicating the current state of the Load Balancer.
-    /// This can be "new", "active", or "errored".
-    status: String,
+    /// The current state of the Load Balancer.
+    status: LoadBalancerStatus,
     /// A time value given in ISO8601 combined date and time format that
     /// represents when the Load Balancer was created.
     created_at: DateTime<Utc>,
@@ -50,16 +59,33 @@ pub struct LoadBalancer {
     /// The region where the Load Balancer instance is located.
     region: Region,
     /// The name of a Droplet tag corresponding to Droplets assigned to the
-    /// Load Balancer.
+    /// Load Balancer. Empty when Droplets are assigned by ID.
+    #[serde(default)]
     tag: String,
     /// An array containing the IDs of the Droplets assigned to the Load
     /// Balancer.
-    droplet_ids: Vec<usize>,
+    droplet_ids: Vec<DropletId>,
     /// A boolean value indicating whether HTTP requests to the Load Balancer
     /// on port 80 will be redirected to HTTPS on port 443.
     redirect_http_to_https: bool,
 }
 
+identified_by!(LoadBalancer, id);
+
+string_enum!(
+    /// The state of a [`LoadBalancer`](struct.LoadBalancer.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#load-balancers)
+    LoadBalancerStatus {
+        /// The Load Balancer is being provisioned.
+        New => "new",
+        /// The Load Balancer is serving traffic.
+        Active => "active",
+        /// The Load Balancer could not be provisioned.
+        Errored => "errored",
+    }
+);
+
 /// Fields which exists inside Droplets.
 pub mod load_balancer_fields {
     /// This exists in the `forwarding_rules` field of a droplet.
@@ -165,7 +191,8 @@ pub mod load_balancer_fields {
         /// the health check will attempt a connection.
         pub port: usize,
         /// The path on the backend Droplets to which the Load Balancer
-        /// instance will send a request.
+        /// instance will send a request. Empty for "tcp" health checks.
+        #[serde(default)]
         pub path: String,
         /// The number of seconds between between two consecutive health
         /// checks.
@@ -201,7 +228,7 @@ pub mod load_balancer_fields {
         /// The number of seconds until the cookie set by the Load Balancer
         /// expires. This attribute is only returned when using "cookies" for
         /// the sticky sessions type.
-        pub cookie_ttl_seconds: Option<String>,
+        pub cookie_ttl_seconds: Option<usize>,
     }
 }
 
@@ -229,15 +256,15 @@ impl LoadBalancer {
         req
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-load-balancer)
-    pub fn get<S>(id: S) -> LoadBalancerRequest<Get, LoadBalancer>
+    pub fn get<I>(id: I) -> LoadBalancerRequest<Get, LoadBalancer>
     where
-        S: AsRef<str> + Serialize + Display,
+        I: Into<LoadBalancerId>,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(LOAD_BALANCERS_SEGMENT)
-            .push(id.as_ref());
+            .push(id.into().as_ref());
 
         Request::new(url)
     }
@@ -255,34 +282,128 @@ impl LoadBalancer {
     /// **Note:** It may contain one of the droplets_ids or tag attributes as they are mutually exclusive.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-load-balancer)
-    pub fn update<S>(id: S) -> LoadBalancerRequest<Update, LoadBalancer>
+    pub fn update<I>(id: I) -> LoadBalancerRequest<Update, LoadBalancer>
     where
-        S: AsRef<str> + Serialize + Display,
+        I: Into<LoadBalancerId>,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(LOAD_BALANCERS_SEGMENT)
-            .push(id.as_ref());
+            .push(id.into().as_ref());
 
         Request::new(url)
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-load-balancer)
-    pub fn delete<S>(id: S) -> LoadBalancerRequest<Delete, ()>
+    pub fn delete<I>(id: I) -> LoadBalancerRequest<Delete, ()>
     where
-        S: AsRef<str> + Serialize + Display,
+        I: Into<LoadBalancerId>,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(LOAD_BALANCERS_SEGMENT)
-            .push(id.as_ref());
+            .push(id.into().as_ref());
 
         Request::new(url)
     }
 }
 
+impl LoadBalancer {
+    /// Polls the load balancer until its status is "active", returning the
+    /// load balancer once it is serving traffic. Fails with
+    /// `ErrorKind::LoadBalancerFailed` if the status becomes "errored", or
+    /// `ErrorKind::Timeout` if it is still not active when the policy's
+    /// timeout runs out.
+    ///
+    /// The API reports no health for each of the assigned Droplets, so this
+    /// only waits on the load balancer itself.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-load-balancer)
+    pub fn wait_active<C>(
+        &self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<LoadBalancer, Error>
+    where
+        C: HttpClient,
+    {
+        wait::poll(
+            self.clone(),
+            policy,
+            |load_balancer| LoadBalancer::get(load_balancer.id.as_str()).execute(instance),
+            LoadBalancer::finished,
+        )
+    }
+
+    /// Polls the load balancer without blocking the calling thread. This
+    /// mirrors [`wait_active`](#method.wait_active).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-load-balancer)
+    #[cfg(feature = "async")]
+    pub async fn wait_active_async<C>(
+        &self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<LoadBalancer, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        wait::poll_async(
+            self.clone(),
+            policy,
+            |load_balancer| LoadBalancer::get(load_balancer.id.as_str()).execute_async(instance),
+            LoadBalancer::finished,
+        )
+        .await
+    }
+
+    /// Whether the load balancer is active, failing if it errored.
+    fn finished(&self) -> Result<bool, Error> {
+        match self.status {
+            LoadBalancerStatus::Active => Ok(true),
+            LoadBalancerStatus::Errored => {
+                Err(ErrorKind::LoadBalancerFailed(self.id.clone()).into())
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
 impl LoadBalancerRequest<Create, LoadBalancer> {
+    /// Creates the load balancer, then waits for it to become active as
+    /// [`LoadBalancer::wait_active`](../api/struct.LoadBalancer.html#method.wait_active)
+    /// does.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-load-balancer)
+    pub fn create_and_wait<C>(
+        self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<LoadBalancer, Error>
+    where
+        C: HttpClient,
+    {
+        self.execute(instance)?.wait_active(instance, policy)
+    }
+    /// Creates the load balancer without blocking the calling thread. This
+    /// mirrors [`create_and_wait`](#method.create_and_wait).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-load-balancer)
+    #[cfg(feature = "async")]
+    pub async fn create_and_wait_async<C>(
+        self,
+        instance: &DigitalOcean<C>,
+        policy: WaitPolicy,
+    ) -> Result<LoadBalancer, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        self.execute_async(instance)
+            .await?
+            .wait_active_async(instance, policy)
+            .await
+    }
     /// The load balancing algorithm used to determine which backend Droplet
     /// will be selected by a client. It must be either "round_robin" or
     /// "least_connections". The default value is "round_robin".
@@ -397,12 +518,18 @@ impl LoadBalancerRequest<Create, LoadBalancer> {
         self
     }
     /// The IDs of the Droplets to be assigned to the Load Balancer.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
     ///
     /// **Note:** Not intended to be used alongside the `tag` function.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-load-balancer)
-    pub fn droplets(mut self, ids: Vec<usize>) -> LoadBalancerRequest<Create, LoadBalancer> {
-        self.body_mut()["droplet_ids"] = json!(ids);
+    pub fn droplets<I, D>(mut self, ids: I) -> LoadBalancerRequest<Create, LoadBalancer>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
+        self.body_mut()["droplet_ids"] = json!(collect_droplet_ids(ids));
         self
     }
     /// The name of a Droplet tag corresponding to Droplets to be assigned to
@@ -555,12 +682,18 @@ impl LoadBalancerRequest<Update, LoadBalancer> {
         self
     }
     /// The IDs of the Droplets to be assigned to the Load Balancer.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
     ///
     /// **Note:** Not intended to be used alongside the `tag` function.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-load-balancer)
-    pub fn droplets(mut self, ids: Vec<usize>) -> LoadBalancerRequest<Update, LoadBalancer> {
-        self.body_mut()["droplet_ids"] = json!(ids);
+    pub fn droplets<I, D>(mut self, ids: I) -> LoadBalancerRequest<Update, LoadBalancer>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
+        self.body_mut()["droplet_ids"] = json!(collect_droplet_ids(ids));
         self
     }
     /// The name of a Droplet tag corresponding to Droplets to be assigned to
@@ -580,31 +713,43 @@ impl LoadBalancerRequest<Update, LoadBalancer> {
 
 impl LoadBalancerRequest<Get, LoadBalancer> {
     /// Add droplets (by id) to the load balancer.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#add-droplets-to-a-load-balancer)
-    pub fn add_droplets(mut self, ids: Vec<usize>) -> LoadBalancerRequest<Create, ()> {
+    pub fn add_droplets<I, D>(mut self, ids: I) -> LoadBalancerRequest<Create, ()>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(DROPLETS_SEGMENT);
 
         self.set_body(json!({
-            "droplet_ids": ids,
+            "droplet_ids": collect_droplet_ids(ids),
         }));
 
         self.transmute()
     }
     /// Remove droplets (by id) from the load balancer.
+    /// Accepts any collection of `DropletId`s (or `usize`s), such as the ids
+    /// of listed Droplets.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#remove-droplets-from-a-load-balancer)
-    pub fn remove_droplets(mut self, ids: Vec<usize>) -> LoadBalancerRequest<Delete, ()> {
+    pub fn remove_droplets<I, D>(mut self, ids: I) -> LoadBalancerRequest<Delete, ()>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<DropletId>,
+    {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(DROPLETS_SEGMENT);
 
         self.set_body(json!({
-            "droplet_ids": ids,
+            "droplet_ids": collect_droplet_ids(ids),
         }));
 
         self.transmute()
@@ -1,51 +1,234 @@
 //! API specific documentation.
+//!
+//! Every resource type both deserializes from and serializes back to the
+//! shape the API uses, so responses can be cached (ex: to disk) and parsed
+//! again later.
+//!
+//! Timestamps are parsed into `chrono::DateTime<Utc>`, whichever offset they
+//! are given in, so that they may be compared across resources.
+//!
+//! Resources with a unique identifier (such as a [`Droplet`](struct.Droplet.html)
+//! and its `id`, or a [`Region`](struct.Region.html) and its `slug`) implement
+//! `PartialEq`, `Eq` and `Hash` on that identifier alone, so that they may be
+//! kept in a `HashSet` or used as the keys of a `HashMap`. Two fetches of the
+//! same resource are equal even if its other fields changed in between.
+
+// Enums of the strings the API returns. Strings this crate does not know
+// about (yet) become `Unknown`, so that new values do not fail to parse, and
+// are serialized back unchanged. The enums are `non_exhaustive` so that
+// variants may be added for such values without breaking matches on them.
+macro_rules! string_enum {
+    (
+        $(#[$attr:meta])*
+        $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+        #[serde(from = "String", into = "String")]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$variant_attr])* $variant,)*
+            /// A value which this crate does not know about.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The string the API represents the value with.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(value: &'a str) -> Self {
+                $name::from(value.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(value) => value,
+                    value => value.as_str().to_string(),
+                }
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl<'a> PartialEq<&'a str> for $name {
+            fn eq(&self, other: &&'a str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+    };
+}
+
+// Resources are equal, and hash the same, when their unique identifiers are
+// equal, whatever their other fields. A resource fetched twice is still the
+// same resource even if its state (ex: its status) changed in between, and so
+// it may be used as the key of a `HashMap` or in a `HashSet`.
+macro_rules! identified_by {
+    ($name:ident, $field:ident) => {
+        impl PartialEq for $name {
+            #[doc = concat!("Only the `", stringify!($field), "`s are compared.")]
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl ::std::hash::Hash for $name {
+            #[doc = concat!("Only the `", stringify!($field), "` is hashed.")]
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.$field.hash(state);
+            }
+        }
+    };
+}
 
 mod account;
 mod action;
+mod alert_policy;
+mod amount;
+mod balance;
+mod cdn_endpoint;
 mod certificate;
 mod custom_image;
+mod database;
+mod database_resources;
 mod domain;
 mod domain_record;
 mod droplet;
 mod droplet_action;
+mod droplet_metrics;
+mod firewall;
 mod floating_ip;
 mod floating_ip_action;
+mod id;
 mod image;
 mod image_action;
+mod invoice;
+mod kubernetes;
+mod kubernetes_node_pool;
 mod load_balancer;
+mod one_click;
+mod project;
 mod region;
+mod registry;
+mod reserved_ip;
+mod reserved_ip_action;
 mod size;
 mod snapshot;
+mod spaces_key;
 mod ssh_key;
 mod tag;
+mod uptime_check;
 mod volume;
 mod volume_action;
+mod vpc;
 
+use crate::error::ErrorKind;
+use failure::Error;
 use serde::de::DeserializeOwned;
 use url::Url;
 use url_serde;
 
-pub use self::account::Account;
-pub use self::action::Action;
-pub use self::certificate::Certificate;
-pub use self::custom_image::CustomImage;
+pub use self::account::{Account, AccountStatus};
+pub use self::action::{Action, ActionStatus, ActionType};
+pub use self::alert_policy::{alert_policy_fields, AlertPolicy};
+pub use self::amount::Amount;
+pub use self::balance::{Balance, BillingEvent};
+pub use self::cdn_endpoint::CdnEndpoint;
+pub use self::certificate::{Certificate, CertificateState, CertificateType};
+pub use self::custom_image::{CustomImage, CustomImageStatus};
+pub use self::database::{
+    database_fields, ConnectionPool, Database, DatabaseCluster, DatabaseStatus, DatabaseUser,
+};
 pub use self::domain::Domain;
-pub use self::domain_record::DomainRecord;
-pub use self::droplet::{droplet_fields, Droplet};
+pub use self::domain_record::{DnsRecordType, DomainRecord};
+pub use self::droplet::{droplet_fields, Droplet, DropletFeature, DropletStatus, NeighborIds};
+pub use self::droplet_action::TaggedDropletAction;
+pub use self::droplet_metrics::MetricSeries;
+pub use self::firewall::{firewall_fields, Firewall, FirewallStatus};
 pub use self::floating_ip::FloatingIp;
-pub use self::image::Image;
-pub use self::load_balancer::{load_balancer_fields, LoadBalancer};
+pub use self::id::{DropletId, ImageId, LoadBalancerId, RegionSlug, SizeSlug, VolumeId};
+pub use self::image::{Image, ImageType};
+pub use self::invoice::{invoice_fields, Invoice, InvoiceDocument, InvoiceItem, InvoiceSummary};
+pub use self::kubernetes::{
+    kubernetes_fields, Kubeconfig, KubernetesCluster, KubernetesCredentials, KubernetesVersion,
+    NodePool,
+};
+pub use self::load_balancer::{load_balancer_fields, LoadBalancer, LoadBalancerStatus};
+pub use self::one_click::{OneClick, OneClickInstall, OneClickType};
+pub use self::project::{Project, ProjectResource, ProjectResourceStatus};
 pub use self::region::Region;
+pub use self::registry::{
+    registry_fields, DockerCredentials, GarbageCollection, GarbageCollectionStatus, Registry,
+    Repository, RepositoryTag,
+};
+pub use self::reserved_ip::ReservedIp;
 pub use self::size::Size;
 pub use self::snapshot::Snapshot;
+pub use self::spaces_key::{spaces_key_fields, SpacesKey};
 pub use self::ssh_key::SshKey;
-pub use self::tag::Tag;
+pub use self::tag::{tag_fields, Tag};
+pub use self::uptime_check::{
+    uptime_check_fields, UptimeAlert, UptimeAlertType, UptimeCheck, UptimeCheckState,
+    UptimeCheckType,
+};
 pub use self::volume::Volume;
+pub use self::vpc::{Vpc, VpcMember};
 
 // Defined in https://developers.digitalocean.com/documentation/v2/#links
 pub const MAX_PER_PAGE: usize = 200;
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// Collects the strings of builders which accept any collection of `&str` or
+/// `String`, such as a slice, `Vec`, or array.
+fn collect_strings<I, S>(val: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    val.into_iter().map(|s| s.as_ref().to_string()).collect()
+}
+
+/// Collects the ids of builders which accept any collection of `DropletId`s
+/// (or `usize`s).
+fn collect_droplet_ids<I, D>(ids: I) -> Vec<DropletId>
+where
+    I: IntoIterator<Item = D>,
+    D: Into<DropletId>,
+{
+    ids.into_iter().map(Into::into).collect()
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 struct ApiLinks {
     pages: Option<ApiPages>,
 }
@@ -53,10 +236,7 @@ struct ApiLinks {
 impl ApiLinks {
     fn next(&self) -> Option<Url> {
         match self.pages {
-            Some(ref pages) => match pages.next {
-                Some(ref v) => Some(v.clone()),
-                None => None,
-            },
+            Some(ref pages) => pages.next.clone(),
             None => None,
         }
     }
@@ -95,8 +275,21 @@ impl HasValue for () {
 
 pub trait HasResponse: DeserializeOwned + Clone {
     type Response: DeserializeOwned + Clone + HasValue<Value = Self>;
+
+    /// Parses the body of a response. Bodies are JSON unless overridden, with
+    /// an empty body (such as that of a `204`) parsed as `null`.
+    fn parse(body: &[u8]) -> Result<Self::Response, Error> {
+        let body: &[u8] = if body.is_empty() { b"null" } else { body };
+        serde_json::from_slice(body).map_err(|e| ErrorKind::deserialize(body, e).into())
+    }
 }
 
 impl HasResponse for () {
     type Response = ();
+
+    // Requests which return nothing (usually with a `204 No Content`) never
+    // look at the body, so that an unexpected one can not fail the request.
+    fn parse(_body: &[u8]) -> Result<Self::Response, Error> {
+        Ok(())
+    }
 }
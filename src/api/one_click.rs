@@ -0,0 +1,145 @@
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, List};
+use crate::request::OneClickRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const ONE_CLICKS_SEGMENT: &str = "1-clicks";
+const KUBERNETES_SEGMENT: &str = "kubernetes";
+
+/// 1-Click applications are pre-built Droplet images or Kubernetes apps with
+/// software, features, and configuration details already set up for you.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#1-click-applications)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct OneClick {
+    /// The slug identifier for the 1-Click application.
+    slug: String,
+    /// The type of the 1-Click application.
+    ///
+    /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+    #[serde(rename = "type")]
+    kind: OneClickType,
+}
+
+string_enum!(
+    /// The type of a [`OneClick`](struct.OneClick.html) application.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#1-click-applications)
+    OneClickType {
+        /// The application is a Droplet image.
+        Droplet => "droplet",
+        /// The application is installed onto a Kubernetes cluster.
+        Kubernetes => "kubernetes",
+    }
+);
+
+/// The result of installing 1-Click applications onto a Kubernetes cluster.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#install-kubernetes-1-click-applications)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct OneClickInstall {
+    /// A message about the result of the request.
+    message: String,
+}
+
+impl OneClick {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-1-click-applications)
+    pub fn list() -> OneClickRequest<List, Vec<OneClick>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(ONE_CLICKS_SEGMENT);
+
+        Request::new(url)
+    }
+
+    /// Install the given 1-Click applications (by slug) onto a Kubernetes
+    /// cluster.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#install-kubernetes-1-click-applications)
+    pub fn install_kubernetes<S>(
+        cluster_uuid: S,
+        slugs: &[S],
+    ) -> OneClickRequest<Create, OneClickInstall>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(ONE_CLICKS_SEGMENT)
+            .push(KUBERNETES_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "addon_slugs": slugs,
+            "cluster_uuid": cluster_uuid,
+        }));
+        req
+    }
+}
+
+impl OneClickRequest<List, Vec<OneClick>> {
+    /// Only list 1-Click applications of the given type. This may be
+    /// "droplet" or "kubernetes".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-1-click-applications)
+    pub fn filter_type<S>(mut self, kind: S) -> Self
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("type", kind.as_ref());
+
+        self
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OneClickListResponse {
+    #[serde(rename = "1_clicks")]
+    one_clicks: Vec<OneClick>,
+    #[serde(default)]
+    links: ApiLinks,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+impl HasResponse for Vec<OneClick> {
+    type Response = OneClickListResponse;
+}
+
+impl HasPagination for OneClickListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for OneClickListResponse {
+    type Value = Vec<OneClick>;
+    fn value(self) -> Vec<OneClick> {
+        self.one_clicks
+    }
+}
+
+// The result of an install is not wrapped in an object.
+impl HasResponse for OneClickInstall {
+    type Response = OneClickInstall;
+}
+
+impl HasValue for OneClickInstall {
+    type Value = OneClickInstall;
+    fn value(self) -> OneClickInstall {
+        self
+    }
+}
@@ -0,0 +1,294 @@
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::ProjectRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const PROJECTS_SEGMENT: &str = "projects";
+const DEFAULT_SEGMENT: &str = "default";
+const RESOURCES_SEGMENT: &str = "resources";
+
+/// Projects allow you to organize your resources into groups that fit the way
+/// you work. You can group resources (like Droplets, Spaces, Load Balancers,
+/// domains, and Floating IPs) in ways that align with the applications you
+/// host on DigitalOcean.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#projects)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Project {
+    /// The unique universal identifier of this project.
+    id: String,
+    /// The unique universal identifier of the project owner.
+    owner_uuid: String,
+    /// The integer id of the project owner.
+    owner_id: usize,
+    /// The human-readable name for the project.
+    name: String,
+    /// The description of the project.
+    description: String,
+    /// The purpose of the project, such as "Web Application" or
+    /// "Service or API".
+    purpose: String,
+    /// The environment of the project's resources. This may be
+    /// "Development", "Staging", or "Production".
+    environment: String,
+    /// If true, all resources will be added to this project if no project is
+    /// specified.
+    is_default: bool,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the project was created.
+    created_at: DateTime<Utc>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the project was updated.
+    updated_at: DateTime<Utc>,
+}
+
+identified_by!(Project, id);
+
+/// A resource assigned to a project.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#project-resources)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct ProjectResource {
+    /// The uniform resource name (URN) of the resource, such as
+    /// "do:droplet:13457723".
+    urn: String,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the resource was assigned to the project.
+    assigned_at: Option<DateTime<Utc>>,
+    /// The status of assigning the resource to the project.
+    status: ProjectResourceStatus,
+}
+
+string_enum!(
+    /// The status of assigning a [`ProjectResource`](struct.ProjectResource.html)
+    /// to a project.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#assign-resources-to-a-project)
+    ProjectResourceStatus {
+        /// The resource is in the project.
+        Ok => "ok",
+        /// The resource does not exist.
+        NotFound => "not_found",
+        /// The resource was assigned to the project.
+        Assigned => "assigned",
+        /// The resource was already in the project.
+        AlreadyAssigned => "already_assigned",
+        /// The resource could not be assigned because a service is unavailable.
+        ServiceDown => "service_down",
+    }
+);
+
+impl Project {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-project)
+    pub fn create<S>(
+        name: S,
+        purpose: S,
+        description: S,
+        environment: S,
+    ) -> ProjectRequest<Create, Project>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(PROJECTS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "purpose": purpose,
+            "description": description,
+            "environment": environment,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-project)
+    pub fn get<S>(id: S) -> ProjectRequest<Get, Project>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(PROJECTS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// The project which resources are added to when no project is
+    /// specified.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-the-default-project)
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> ProjectRequest<Get, Project> {
+        Project::get(DEFAULT_SEGMENT)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-projects)
+    pub fn list() -> ProjectRequest<List, Vec<Project>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(PROJECTS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// **Note:** Every attribute must be provided, as they all replace the
+    /// existing values.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-project)
+    pub fn update<S>(
+        id: S,
+        name: S,
+        purpose: S,
+        description: S,
+        environment: S,
+        is_default: bool,
+    ) -> ProjectRequest<Update, Project>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(PROJECTS_SEGMENT)
+            .push(id.as_ref());
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "purpose": purpose,
+            "description": description,
+            "environment": environment,
+            "is_default": is_default,
+        }));
+        req
+    }
+    /// **Note:** Only projects without any resources can be deleted.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-an-existing-project)
+    pub fn delete<S>(id: S) -> ProjectRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(PROJECTS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl ProjectRequest<Get, Project> {
+    /// The resources assigned to the project.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-project-resources)
+    pub fn resources(mut self) -> ProjectRequest<List, Vec<ProjectResource>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESOURCES_SEGMENT);
+
+        self.transmute()
+    }
+    /// Accepts uniform resource names (URNs) in the format
+    /// `do:resource_type:resource_id`, such as "do:droplet:13457723".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#assign-resources-to-a-project)
+    pub fn assign_resources<S>(mut self, urns: &[S]) -> ProjectRequest<Create, Vec<ProjectResource>>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESOURCES_SEGMENT);
+
+        self.set_body(json!({
+            "resources": urns,
+        }));
+
+        self.transmute()
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectResponse {
+    project: Project,
+}
+
+impl HasResponse for Project {
+    type Response = ProjectResponse;
+}
+
+impl HasValue for ProjectResponse {
+    type Value = Project;
+    fn value(self) -> Project {
+        self.project
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectListResponse {
+    projects: Vec<Project>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<Project> {
+    type Response = ProjectListResponse;
+}
+
+impl HasPagination for ProjectListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for ProjectListResponse {
+    type Value = Vec<Project>;
+    fn value(self) -> Vec<Project> {
+        self.projects
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectResourceListResponse {
+    resources: Vec<ProjectResource>,
+    // Assigning resources responds without pagination.
+    #[serde(default)]
+    links: ApiLinks,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+impl HasResponse for Vec<ProjectResource> {
+    type Response = ProjectResourceListResponse;
+}
+
+impl HasPagination for ProjectResourceListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for ProjectResourceListResponse {
+    type Value = Vec<ProjectResource>;
+    fn value(self) -> Vec<ProjectResource> {
+        self.resources
+    }
+}
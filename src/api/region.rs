@@ -5,6 +5,7 @@ use crate::request::RegionRequest;
 use crate::request::Request;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use getset::{Getters, Setters};
+use std::fmt;
 use url::Url;
 
 const REGIONS_SEGMENT: &str = "regions";
@@ -20,11 +21,11 @@ const REGIONS_SEGMENT: &str = "regions";
 #[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
 #[get = "pub"]
 pub struct Region {
-    /// A human-readable string that is used as a unique identifier for each
-    /// region.
-    name: String,
     /// The display name of the region. This will be a full name that is used
     /// in the control panel and other interfaces.
+    name: String,
+    /// A human-readable string that is used as a unique identifier for each
+    /// region.
     slug: String,
     /// This attribute is set to an array which contains the identifying slugs
     ///  for the sizes available in this region.
@@ -37,6 +38,8 @@ pub struct Region {
     features: Vec<String>,
 }
 
+identified_by!(Region, slug);
+
 impl Region {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-regions)
     pub fn list() -> RegionRequest<List, Vec<Region>> {
@@ -47,6 +50,22 @@ impl Region {
 
         Request::new(url)
     }
+
+    /// Whether droplets of the size with the given slug can be created in
+    /// this region.
+    pub fn supports_size<S>(&self, slug: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.available && self.sizes.iter().any(|size| size == slug.as_ref())
+    }
+}
+
+// Renders the slug, which is how the API refers to the region.
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.slug.fmt(f)
+    }
 }
 
 /// Response type returned from Digital Ocean.
@@ -0,0 +1,389 @@
+use self::registry_fields::DockerAuth;
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List};
+use crate::request::RegistryRequest;
+use crate::request::Request;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+use url::Url;
+
+const REGISTRY_SEGMENT: &str = "registry";
+const DOCKER_CREDENTIALS_SEGMENT: &str = "docker-credentials";
+const REPOSITORIES_SEGMENT: &str = "repositoriesV2";
+const REPOSITORY_SEGMENT: &str = "repositories";
+const TAGS_SEGMENT: &str = "tags";
+const GARBAGE_COLLECTION_SEGMENT: &str = "garbage-collection";
+
+/// The DigitalOcean Container Registry (DOCR) is a private Docker image
+/// registry. Each account may have a single registry.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#container-registry)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Registry {
+    /// A globally unique name for the container registry. Must be lowercase
+    /// and be composed only of numbers, letters and `-`, up to a limit of
+    /// 63 characters.
+    name: String,
+    /// The slug identifier for the region where the registry data is
+    /// stored.
+    #[serde(default)]
+    region: String,
+    /// The amount of storage used in the registry in bytes.
+    #[serde(default)]
+    storage_usage_bytes: u64,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the storage usage was last updated.
+    storage_usage_bytes_updated_at: Option<DateTime<Utc>>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the registry was created.
+    created_at: DateTime<Utc>,
+}
+
+/// A Docker configuration file containing the credentials of the container
+/// registry, for use with `docker login` or a Kubernetes image pull secret.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-docker-credentials-for-container-registry)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct DockerCredentials {
+    /// The credentials, keyed by the hostname of the registry.
+    auths: HashMap<String, DockerAuth>,
+}
+
+/// A repository of the container registry.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-container-registry-repositories-v2)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Repository {
+    /// The name of the container registry.
+    registry_name: String,
+    /// The name of the repository.
+    name: String,
+    /// The number of tags in the repository.
+    tag_count: usize,
+    /// The number of manifests in the repository.
+    #[serde(default)]
+    manifest_count: usize,
+}
+
+/// A tag of a repository of the container registry.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-container-registry-repository-tags)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct RepositoryTag {
+    /// The name of the container registry.
+    registry_name: String,
+    /// The name of the repository.
+    repository: String,
+    /// The name of the tag.
+    tag: String,
+    /// The digest of the manifest associated with the tag.
+    manifest_digest: String,
+    /// The compressed size of the tag in bytes.
+    compressed_size_bytes: u64,
+    /// The uncompressed size of the tag in bytes.
+    size_bytes: u64,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the tag was last updated.
+    updated_at: DateTime<Utc>,
+}
+
+/// A garbage collection of the container registry, which removes the
+/// unreferenced blobs of deleted tags and manifests.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#start-garbage-collection)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct GarbageCollection {
+    /// A string specifying the UUID of the garbage collection.
+    uuid: String,
+    /// The name of the container registry.
+    registry_name: String,
+    /// The current status of the garbage collection.
+    status: GarbageCollectionStatus,
+    /// The number of blobs deleted as a result of the garbage collection.
+    #[serde(default)]
+    blobs_deleted: usize,
+    /// The number of bytes freed as a result of the garbage collection.
+    #[serde(default)]
+    freed_bytes: u64,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the garbage collection was created.
+    created_at: DateTime<Utc>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the garbage collection was last updated.
+    updated_at: DateTime<Utc>,
+}
+
+string_enum!(
+    /// The status of a [`GarbageCollection`](struct.GarbageCollection.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-active-garbage-collection)
+    GarbageCollectionStatus {
+        /// The garbage collection was requested, but has not started.
+        Requested => "requested",
+        /// The garbage collection is waiting for writes to the registry to stop.
+        WaitingForWriteJwtsToExpire => "waiting for write JWTs to expire",
+        /// The manifests of the registry are being scanned.
+        ScanningManifests => "scanning manifests",
+        /// The blobs found to be unreferenced are being deleted.
+        DeletingUnreferencedBlobs => "deleting unreferenced blobs",
+        /// The garbage collection is being cancelled.
+        Cancelling => "cancelling",
+        /// The garbage collection failed.
+        Failed => "failed",
+        /// The garbage collection finished.
+        Succeeded => "succeeded",
+        /// The garbage collection was cancelled.
+        Cancelled => "cancelled",
+    }
+);
+
+/// Fields which exists inside the container registry.
+pub mod registry_fields {
+    /// This exists in the `auths` field of Docker credentials.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct DockerAuth {
+        /// The base64 encoded `username:password` of the registry.
+        pub auth: String,
+    }
+}
+
+impl Registry {
+    /// The `subscription_tier` may be "starter", "basic", or "professional".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-container-registry)
+    pub fn create<S>(name: S, subscription_tier: S) -> RegistryRequest<Create, Registry>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(REGISTRY_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "subscription_tier_slug": subscription_tier,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-container-registry-information)
+    pub fn get() -> RegistryRequest<Get, Registry> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(REGISTRY_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-container-registry)
+    pub fn delete() -> RegistryRequest<Delete, ()> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(REGISTRY_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-container-registry-repositories-v2)
+    pub fn repositories<S>(registry: S) -> RegistryRequest<List, Vec<Repository>>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(REGISTRY_SEGMENT)
+            .push(registry.as_ref())
+            .push(REPOSITORIES_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-container-registry-repository-tags)
+    pub fn tags<S>(registry: S, repository: S) -> RegistryRequest<List, Vec<RepositoryTag>>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(REGISTRY_SEGMENT)
+            .push(registry.as_ref())
+            .push(REPOSITORY_SEGMENT)
+            .push(repository.as_ref())
+            .push(TAGS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#start-garbage-collection)
+    pub fn garbage_collection<S>(registry: S) -> RegistryRequest<Create, GarbageCollection>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(REGISTRY_SEGMENT)
+            .push(registry.as_ref())
+            .push(GARBAGE_COLLECTION_SEGMENT);
+
+        Request::new(url)
+    }
+}
+
+impl RegistryRequest<Create, Registry> {
+    /// The slug identifier for the region where the registry data will be
+    /// stored. When excluded, a region is selected automatically.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-container-registry)
+    pub fn region<S>(mut self, val: S) -> RegistryRequest<Create, Registry>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["region"] = json!(val);
+        self
+    }
+}
+
+impl RegistryRequest<Get, Registry> {
+    /// The Docker credentials of the registry. When `read_write` is false
+    /// the credentials only allow pulling images.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-docker-credentials-for-container-registry)
+    pub fn docker_credentials(
+        mut self,
+        read_write: bool,
+    ) -> RegistryRequest<Get, DockerCredentials> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(DOCKER_CREDENTIALS_SEGMENT);
+
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("read_write", &read_write.to_string());
+
+        self.transmute()
+    }
+}
+
+impl RegistryRequest<Get, DockerCredentials> {
+    /// The number of seconds after which the credentials expire. When
+    /// excluded, the credentials never expire.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#get-docker-credentials-for-container-registry)
+    pub fn expiry_seconds(mut self, seconds: usize) -> RegistryRequest<Get, DockerCredentials> {
+        self.url_mut()
+            .query_pairs_mut()
+            .append_pair("expiry_seconds", &seconds.to_string());
+
+        self
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RegistryResponse {
+    registry: Registry,
+}
+
+impl HasResponse for Registry {
+    type Response = RegistryResponse;
+}
+
+impl HasValue for RegistryResponse {
+    type Value = Registry;
+    fn value(self) -> Registry {
+        self.registry
+    }
+}
+
+// The Docker credentials are returned as a Docker configuration file, rather
+// than wrapped in an object.
+impl HasResponse for DockerCredentials {
+    type Response = DockerCredentials;
+}
+
+impl HasValue for DockerCredentials {
+    type Value = DockerCredentials;
+    fn value(self) -> DockerCredentials {
+        self
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RepositoryListResponse {
+    repositories: Vec<Repository>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<Repository> {
+    type Response = RepositoryListResponse;
+}
+
+impl HasPagination for RepositoryListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for RepositoryListResponse {
+    type Value = Vec<Repository>;
+    fn value(self) -> Vec<Repository> {
+        self.repositories
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RepositoryTagListResponse {
+    tags: Vec<RepositoryTag>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<RepositoryTag> {
+    type Response = RepositoryTagListResponse;
+}
+
+impl HasPagination for RepositoryTagListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for RepositoryTagListResponse {
+    type Value = Vec<RepositoryTag>;
+    fn value(self) -> Vec<RepositoryTag> {
+        self.tags
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GarbageCollectionResponse {
+    garbage_collection: GarbageCollection,
+}
+
+impl HasResponse for GarbageCollection {
+    type Response = GarbageCollectionResponse;
+}
+
+impl HasValue for GarbageCollectionResponse {
+    type Value = GarbageCollection;
+    fn value(self) -> GarbageCollection {
+        self.garbage_collection
+    }
+}
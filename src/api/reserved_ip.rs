@@ -0,0 +1,161 @@
+use super::id::DropletId;
+use super::{ApiLinks, ApiMeta};
+use super::{Droplet, Region};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List};
+use crate::request::Request;
+use crate::request::ReservedIpRequest;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use std::net::IpAddr;
+use url::Url;
+
+const RESERVED_IP_SEGMENT: &str = "reserved_ips";
+
+/// Reserved IP objects represent a publicly-accessible static IP addresses
+/// that can be mapped to one of your Droplets. They can be used to create
+/// highly available setups or other configurations requiring movable
+/// addresses.
+///
+/// Reserved IPs are bound to a specific region. They were formerly known as
+/// Floating IPs, see [`FloatingIp`](struct.FloatingIp.html).
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#reserved-ips)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct ReservedIp {
+    /// The public IP address of the Reserved IP. It also serves as its
+    /// identifier.
+    ip: IpAddr,
+    /// The region that the Reserved IP is reserved to. When you query a
+    /// Reserved IP, the entire region object will be returned.
+    region: Region,
+    /// The Droplet that the Reserved IP has been assigned to. When you query
+    /// a Reserved IP, if it is assigned to a Droplet, the entire Droplet
+    /// object will be returned. If it is not assigned, the value will be null.
+    droplet: Option<Droplet>,
+    /// A boolean value indicating whether or not the Reserved IP has pending
+    /// actions preventing new ones from being submitted.
+    #[serde(default)]
+    locked: bool,
+}
+
+identified_by!(ReservedIp, ip);
+
+impl ReservedIp {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-reserved-ips)
+    pub fn list() -> ReservedIpRequest<List, Vec<ReservedIp>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_SEGMENT);
+
+        Request::new(url)
+    }
+
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-reserved-ip-assigned-to-a-droplet)
+    pub fn for_droplet<I>(id: I) -> ReservedIpRequest<Create, ReservedIp>
+    where
+        I: Into<DropletId>,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "droplet_id": id.into(),
+        }));
+        req
+    }
+
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-reserved-ip-reserved-to-a-region)
+    pub fn for_region<S>(id: S) -> ReservedIpRequest<Create, ReservedIp>
+    where
+        S: AsRef<str> + Display + Serialize,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "region": id,
+        }));
+        req
+    }
+
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-reserved-ip)
+    pub fn get<I>(id: I) -> ReservedIpRequest<Get, ReservedIp>
+    where
+        I: Into<IpAddr>,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_SEGMENT)
+            .push(&id.into().to_string());
+
+        Request::new(url)
+    }
+
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-reserved-ip)
+    pub fn delete<I>(id: I) -> ReservedIpRequest<Delete, ()>
+    where
+        I: Into<IpAddr>,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_SEGMENT)
+            .push(&id.into().to_string());
+
+        Request::new(url)
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReservedIpResponse {
+    reserved_ip: ReservedIp,
+}
+
+impl HasResponse for ReservedIp {
+    type Response = ReservedIpResponse;
+}
+
+impl HasValue for ReservedIpResponse {
+    type Value = ReservedIp;
+    fn value(self) -> ReservedIp {
+        self.reserved_ip
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReservedIpListResponse {
+    reserved_ips: Vec<ReservedIp>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<ReservedIp> {
+    type Response = ReservedIpListResponse;
+}
+
+impl HasPagination for ReservedIpListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for ReservedIpListResponse {
+    type Value = Vec<ReservedIp>;
+    fn value(self) -> Vec<ReservedIp> {
+        self.reserved_ips
+    }
+}
@@ -0,0 +1,70 @@
+use super::id::DropletId;
+use super::reserved_ip::ReservedIp;
+use super::Action;
+use crate::method::{Create, Get, List};
+use crate::request::{ReservedIpActionRequest, ReservedIpRequest};
+use crate::STATIC_URL_ERROR;
+
+const RESERVED_IP_ACTIONS_SEGMENT: &str = "actions";
+
+impl ReservedIpRequest<Get, ReservedIp> {
+    /// The actions (such as assignments) which have been taken on the
+    /// reserved IP.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-actions-for-a-reserved-ip)
+    pub fn actions(mut self) -> ReservedIpActionRequest<List, Vec<Action>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_ACTIONS_SEGMENT);
+
+        self.transmute()
+    }
+
+    /// A single action taken on the reserved IP. Assigning and unassigning
+    /// happen asynchronously, so this may be polled to confirm that one
+    /// completed, or see [`Action::wait()`](../api/struct.Action.html#method.wait).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-reserved-ip-action)
+    pub fn action(mut self, id: usize) -> ReservedIpActionRequest<Get, Action> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_ACTIONS_SEGMENT)
+            .push(&id.to_string());
+
+        self.transmute()
+    }
+
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#unassign-a-reserved-ip)
+    pub fn unassign(mut self) -> ReservedIpActionRequest<Create, Action> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_ACTIONS_SEGMENT);
+
+        self.set_body(json!({
+            "type": "unassign",
+        }));
+
+        self.transmute()
+    }
+
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#assign-a-reserved-ip-to-a-droplet)
+    pub fn assign<I>(mut self, id: I) -> ReservedIpActionRequest<Create, Action>
+    where
+        I: Into<DropletId>,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(RESERVED_IP_ACTIONS_SEGMENT);
+
+        self.set_body(json!({
+            "type": "assign",
+            "droplet_id": id.into(),
+        }));
+
+        self.transmute()
+    }
+}
@@ -1,3 +1,4 @@
+use super::amount::Amount;
 use super::{ApiLinks, ApiMeta};
 use super::{HasPagination, HasResponse, HasValue};
 use crate::method::List;
@@ -5,6 +6,7 @@ use crate::request::Request;
 use crate::request::SizeRequest;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use getset::{Getters, Setters};
+use std::fmt;
 use url::Url;
 
 const SIZES_SEGMENT: &str = "sizes";
@@ -34,9 +36,15 @@ pub struct Size {
     /// This attribute describes the monthly cost of this Droplet size if the
     /// Droplet is kept for an entire month. The value is measured in US
     /// dollars.
+    ///
+    /// *Note:* Use `price_monthly_amount()` for calculations, which does not
+    /// accumulate floating point errors.
     price_monthly: f64,
     /// This describes the price of the Droplet size as measured hourly. The
     /// value is measured in US dollars.
+    ///
+    /// *Note:* Use `price_hourly_amount()` for calculations, which does not
+    /// accumulate floating point errors.
     price_hourly: f64,
     /// The amount of RAM allocated to Droplets created of this size. The value
     /// is represented in megabytes.
@@ -51,8 +59,10 @@ pub struct Size {
     regions: Vec<String>,
 }
 
+identified_by!(Size, slug);
+
 impl Size {
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-images)
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-sizes)
     pub fn list() -> SizeRequest<List, Vec<Size>> {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
@@ -61,9 +71,39 @@ impl Size {
 
         Request::new(url)
     }
+
+    /// The monthly price as an [`Amount`](struct.Amount.html), rounded to the
+    /// nearest micro-dollar.
+    pub fn price_monthly_amount(&self) -> Amount {
+        dollars(self.price_monthly)
+    }
+
+    /// The hourly price as an [`Amount`](struct.Amount.html), rounded to the
+    /// nearest micro-dollar.
+    pub fn price_hourly_amount(&self) -> Amount {
+        dollars(self.price_hourly)
+    }
+
+    /// The monthly price in cents, rounded to the nearest cent.
+    pub fn price_monthly_cents(&self) -> u64 {
+        self.price_monthly_amount().cents().max(0) as u64
+    }
+}
+
+/// Converts a price in dollars from the decimal the API sent it as, which may
+/// have more places (such as `0.00743999984115362`) than an `Amount` keeps.
+fn dollars(price: f64) -> Amount {
+    Amount::parse_rounded(&price.to_string()).unwrap_or_default()
 }
 
-// There is no signular size return.
+// There is no singular size return.
+
+// Renders the slug, which is how the API refers to the size.
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.slug.fmt(f)
+    }
+}
 
 /// Response type returned from Digital Ocean.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -6,6 +6,7 @@ use crate::request::SnapshotRequest;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use chrono::{DateTime, Utc};
 use getset::{Getters, Setters};
+use std::fmt::Display;
 use url::Url;
 
 const SNAPSHOT_SEGMENT: &str = "snapshots";
@@ -39,8 +40,13 @@ pub struct Snapshot {
     min_disk_size: usize,
     /// The billable size of the snapshot in gigabytes.
     size_gigabytes: f64,
+    /// The tags applied to the snapshot.
+    #[serde(default)]
+    tags: Vec<String>,
 }
 
+identified_by!(Snapshot, id);
+
 impl Snapshot {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-snapshots)
     pub fn list() -> SnapshotRequest<List, Vec<Snapshot>> {
@@ -53,7 +59,7 @@ impl Snapshot {
     }
 
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-droplet-snapshots)
-    pub fn droplets() -> SnapshotRequest<List, Vec<Snapshot>> {
+    pub fn list_droplet_snapshots() -> SnapshotRequest<List, Vec<Snapshot>> {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -66,7 +72,7 @@ impl Snapshot {
     }
 
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-volume-snapshots)
-    pub fn volumes() -> SnapshotRequest<List, Vec<Snapshot>> {
+    pub fn list_volume_snapshots() -> SnapshotRequest<List, Vec<Snapshot>> {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -77,8 +83,24 @@ impl Snapshot {
         Request::new(url)
     }
 
+    /// Equivalent to [`Snapshot::list_droplet_snapshots()`](#method.list_droplet_snapshots).
+    pub fn droplets() -> SnapshotRequest<List, Vec<Snapshot>> {
+        Snapshot::list_droplet_snapshots()
+    }
+
+    /// Equivalent to [`Snapshot::list_volume_snapshots()`](#method.list_volume_snapshots).
+    pub fn volumes() -> SnapshotRequest<List, Vec<Snapshot>> {
+        Snapshot::list_volume_snapshots()
+    }
+
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-snapshot-by-id)
-    pub fn get(id: usize) -> SnapshotRequest<Get, Snapshot> {
+    ///
+    /// Droplet snapshots are identified by a numeric ID, while volume
+    /// snapshots are identified by a UUID, so either may be given.
+    pub fn get<S>(id: S) -> SnapshotRequest<Get, Snapshot>
+    where
+        S: Display,
+    {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -89,7 +111,13 @@ impl Snapshot {
     }
 
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-snapshot)
-    pub fn delete(id: usize) -> SnapshotRequest<Delete, ()> {
+    ///
+    /// Droplet snapshots are identified by a numeric ID, while volume
+    /// snapshots are identified by a UUID, so either may be given.
+    pub fn delete<S>(id: S) -> SnapshotRequest<Delete, ()>
+    where
+        S: Display,
+    {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
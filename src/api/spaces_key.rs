@@ -0,0 +1,239 @@
+use self::spaces_key_fields::Grant;
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::Request;
+use crate::request::SpacesKeyRequest;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const SPACES_SEGMENT: &str = "spaces";
+const KEYS_SEGMENT: &str = "keys";
+
+/// Spaces access keys are the credentials used with the S3-compatible API of
+/// Spaces object storage. They may be restricted to specific buckets by
+/// their grants.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#spaces-keys)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct SpacesKey {
+    /// A human-readable name for the key.
+    name: String,
+    /// The access key ID of the key. It also serves as its identifier.
+    access_key: String,
+    /// The secret access key of the key.
+    ///
+    /// *Note:* This is only returned when the key is created.
+    #[serde(default)]
+    secret_key: Option<String>,
+    /// The grants restricting which buckets the key has access to, and
+    /// with which permissions.
+    #[serde(default)]
+    grants: Vec<Grant>,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the key was created.
+    created_at: DateTime<Utc>,
+}
+
+/// Fields which exists inside Spaces keys.
+pub mod spaces_key_fields {
+    /// This exists in the `grants` field of a Spaces key.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Grant {
+        /// The name of the bucket. An empty string applies to every bucket.
+        pub bucket: String,
+        /// The permission of the key on the bucket. This may be "read",
+        /// "readwrite", or "fullaccess".
+        pub permission: String,
+    }
+    impl Grant {
+        pub fn new<S>(bucket: S, permission: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            Grant {
+                bucket: bucket.as_ref().to_string(),
+                permission: permission.as_ref().to_string(),
+            }
+        }
+    }
+    impl<S> From<(S, S)> for Grant
+    where
+        S: AsRef<str>,
+    {
+        fn from(val: (S, S)) -> Self {
+            Grant::new(val.0, val.1)
+        }
+    }
+}
+
+impl SpacesKey {
+    /// The secret key of the created key is only returned in this response.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-spaces-key)
+    pub fn create<S>(name: S) -> SpacesKeyRequest<Create, SpacesKey>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(SPACES_SEGMENT)
+            .push(KEYS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "grants": [],
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-spaces-key)
+    pub fn get<S>(access_key: S) -> SpacesKeyRequest<Get, SpacesKey>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(SPACES_SEGMENT)
+            .push(KEYS_SEGMENT)
+            .push(access_key.as_ref());
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-spaces-keys)
+    pub fn list() -> SpacesKeyRequest<List, Vec<SpacesKey>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(SPACES_SEGMENT)
+            .push(KEYS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// **Note:** The grants of the key are replaced, so be sure to include
+    /// every grant by chaining `.grant()` onto this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-spaces-key)
+    pub fn update<S>(access_key: S, name: S) -> SpacesKeyRequest<Update, SpacesKey>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(SPACES_SEGMENT)
+            .push(KEYS_SEGMENT)
+            .push(access_key.as_ref());
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "grants": [],
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-spaces-key)
+    pub fn delete<S>(access_key: S) -> SpacesKeyRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(SPACES_SEGMENT)
+            .push(KEYS_SEGMENT)
+            .push(access_key.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl SpacesKeyRequest<Create, SpacesKey> {
+    /// Restricts the key to a bucket with a permission. Accepts a
+    /// [`Grant`](spaces_key_fields/struct.Grant.html) or a tuple matching
+    /// `(bucket, permission)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-spaces-key)
+    pub fn grant<T>(mut self, val: T) -> SpacesKeyRequest<Create, SpacesKey>
+    where
+        T: Into<Grant>,
+    {
+        push_grant(self.body_mut(), val.into());
+        self
+    }
+}
+
+impl SpacesKeyRequest<Update, SpacesKey> {
+    /// Restricts the key to a bucket with a permission. Accepts a
+    /// [`Grant`](spaces_key_fields/struct.Grant.html) or a tuple matching
+    /// `(bucket, permission)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-spaces-key)
+    pub fn grant<T>(mut self, val: T) -> SpacesKeyRequest<Update, SpacesKey>
+    where
+        T: Into<Grant>,
+    {
+        push_grant(self.body_mut(), val.into());
+        self
+    }
+}
+
+fn push_grant(body: &mut serde_json::Value, grant: Grant) {
+    if !body["grants"].is_array() {
+        body["grants"] = json!([]);
+    }
+
+    body["grants"]
+        .as_array_mut()
+        .expect("grants should always be an array.")
+        .push(json!(grant));
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SpacesKeyResponse {
+    key: SpacesKey,
+}
+
+impl HasResponse for SpacesKey {
+    type Response = SpacesKeyResponse;
+}
+
+impl HasValue for SpacesKeyResponse {
+    type Value = SpacesKey;
+    fn value(self) -> SpacesKey {
+        self.key
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SpacesKeyListResponse {
+    keys: Vec<SpacesKey>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<SpacesKey> {
+    type Response = SpacesKeyListResponse;
+}
+
+impl HasPagination for SpacesKeyListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for SpacesKeyListResponse {
+    type Value = Vec<SpacesKey>;
+    fn value(self) -> Vec<SpacesKey> {
+        self.keys
+    }
+}
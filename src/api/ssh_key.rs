@@ -24,8 +24,8 @@ pub struct SshKey {
     /// to reference a specific SSH key when you wish to embed a key into a
     /// Droplet.
     ///
-    /// *Note:* This is a `String` to allow for `id` and `fingerprint` to be
-    /// used in `Get`, `Update`, and `Delete` calls like the API describes.
+    /// *Note:* Either this or the `fingerprint` can be used in `Get`,
+    /// `Update`, and `Delete` calls like the API describes.
     id: usize,
     /// This attribute contains the fingerprint value that is generated from
     /// the public key. This is a unique identifier that will differentiate
@@ -40,6 +40,8 @@ pub struct SshKey {
     name: String,
 }
 
+identified_by!(SshKey, id);
+
 impl SshKey {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-key)
     pub fn create<N>(name: N, public_key: N) -> SshKeyRequest<Create, SshKey>
@@ -71,6 +73,8 @@ impl SshKey {
         Request::new(url)
     }
 
+    /// `id` is either an `id` (numeric) or a `fingerprint` (string).
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-key)
     pub fn get<S>(id: S) -> SshKeyRequest<Get, SshKey>
     where
@@ -86,10 +90,14 @@ impl SshKey {
         Request::new(url)
     }
 
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-key)
-    pub fn update<S>(id: S) -> SshKeyRequest<Update, SshKey>
+    /// Renames a key. `id` is either an `id` (numeric) or a `fingerprint`
+    /// (string).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-key)
+    pub fn update<S, N>(id: S, name: N) -> SshKeyRequest<Update, SshKey>
     where
         S: Serialize + Display,
+        N: AsRef<str> + Serialize + Display,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
@@ -98,10 +106,16 @@ impl SshKey {
             .push(KEYS_SEGMENT)
             .push(&format!("{}", id));
 
-        Request::new(url)
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+        }));
+        req
     }
 
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-domain)
+    /// `id` is either an `id` (numeric) or a `fingerprint` (string).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#destroy-a-key)
     pub fn delete<S>(id: S) -> SshKeyRequest<Delete, ()>
     where
         S: Serialize + Display,
@@ -118,9 +132,9 @@ impl SshKey {
 }
 
 impl SshKeyRequest<Update, SshKey> {
-    /// The name to give the new SSH key in your account.
+    /// The name to give the SSH key in your account.
     ///
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#domain-records)
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-key)
     pub fn name<S>(mut self, val: S) -> Self
     where
         S: AsRef<str> + Display + Serialize,
@@ -1,3 +1,4 @@
+use self::tag_fields::{Resource, Resources};
 use super::{ApiLinks, ApiMeta};
 use super::{HasPagination, HasResponse, HasValue};
 use crate::method::{Create, Delete, Get, List};
@@ -6,16 +7,15 @@ use crate::request::TagRequest;
 use crate::{ROOT_URL, STATIC_URL_ERROR};
 use getset::{Getters, Setters};
 use serde::Serialize;
-use serde_json::Value;
 use std::fmt::Display;
 use url::Url;
 
 const TAG_SEGMENT: &str = "tags";
 const RESOURCES_SEGMENT: &str = "resources";
 
-/// A Tag is a label that can be applied to a resource (currently only
-/// Droplets) in order to better organize or facilitate the lookups and actions
-///  on it.
+/// A Tag is a label that can be applied to a resource (such as a Droplet,
+/// image, volume, volume snapshot, or database) in order to better organize or
+/// facilitate the lookups and actions on it.
 ///
 /// Tags have two attributes: a user defined name attribute and an embedded
 /// resources attribute with information about resources that have been tagged.
@@ -29,7 +29,73 @@ pub struct Tag {
     name: String,
     /// An embedded object containing key value pairs of resource type and
     /// resource statistics.
-    resources: Value,
+    resources: Resources,
+}
+
+identified_by!(Tag, name);
+
+/// Fields which exists inside tags.
+pub mod tag_fields {
+    /// A resource to be tagged or untagged.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Resource {
+        /// The identifier of the resource, such as the ID of a Droplet.
+        pub resource_id: String,
+        /// The type of the resource. This may be "droplet", "image",
+        /// "volume", "volume_snapshot", or "database".
+        pub resource_type: String,
+    }
+    impl Resource {
+        pub fn new<S>(resource_id: S, resource_type: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            Resource {
+                resource_id: resource_id.as_ref().to_string(),
+                resource_type: resource_type.as_ref().to_string(),
+            }
+        }
+    }
+    impl<S> From<(S, S)> for Resource
+    where
+        S: AsRef<str>,
+    {
+        fn from(val: (S, S)) -> Self {
+            Resource::new(val.0, val.1)
+        }
+    }
+
+    /// This exists in the `resources` field of a tag.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct Resources {
+        /// The number of resources tagged with the tag.
+        #[serde(default)]
+        pub count: usize,
+        /// The URI of the resource most recently tagged with the tag.
+        pub last_tagged_uri: Option<String>,
+        #[serde(default)]
+        pub droplets: ResourceCount,
+        #[serde(default)]
+        pub images: ResourceCount,
+        #[serde(default)]
+        pub volumes: ResourceCount,
+        #[serde(default)]
+        pub volume_snapshots: ResourceCount,
+        #[serde(default)]
+        pub databases: ResourceCount,
+    }
+
+    /// This exists for every resource type in the `resources` field of a
+    /// tag.
+    #[derive(Deserialize, Serialize, Debug, Clone, Default)]
+    pub struct ResourceCount {
+        /// The number of resources of this type tagged with the tag.
+        #[serde(default)]
+        pub count: usize,
+        /// The URI of the resource of this type most recently tagged with
+        /// the tag.
+        pub last_tagged_uri: Option<String>,
+    }
 }
 
 impl Tag {
@@ -62,8 +128,8 @@ impl Tag {
 
         Request::new(url)
     }
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-a-tag)
-    pub fn list() -> TagRequest<List, Tag> {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-tags)
+    pub fn list() -> TagRequest<List, Vec<Tag>> {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -87,62 +153,52 @@ impl Tag {
 }
 
 impl TagRequest<Get, Tag> {
-    /// Accepts tuples matching `(id, type)`. Currently the only `type` is `"droplet"`.
-    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#tag-a-resource)
-    pub fn add_resources<S>(mut self, resources: Vec<(S, S)>) -> TagRequest<Create, ()>
-    where
-        S: AsRef<str> + Serialize + Display,
-    {
+    pub fn tag_resources(mut self, resources: &[Resource]) -> TagRequest<Create, ()> {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(RESOURCES_SEGMENT);
 
-        let resources = resources
-            .into_iter()
-            .map(|(id, kind)| {
-                json!({
-                    "resource_id": id,
-                    "resource_type": kind,
-                })
-            })
-            .collect::<Vec<_>>();
-
         self.set_body(json!({
             "resources": resources,
         }));
 
         self.transmute()
     }
-    /// Accepts tuples matching `(id, type)`. Currently the only `type` is `"droplet"`.
-    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#untag-a-resource)
-    pub fn remove_resources<S>(mut self, resources: Vec<(S, S)>) -> TagRequest<Delete, ()>
-    where
-        S: AsRef<str> + Serialize + Display,
-    {
+    pub fn untag_resources(mut self, resources: &[Resource]) -> TagRequest<Delete, ()> {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(RESOURCES_SEGMENT);
 
-        let resources = resources
-            .into_iter()
-            .map(|(id, kind)| {
-                json!({
-                    "resource_id": id,
-                    "resource_type": kind,
-                })
-            })
-            .collect::<Vec<_>>();
-
         self.set_body(json!({
             "resources": resources,
         }));
 
         self.transmute()
     }
+    /// Accepts tuples matching `(id, type)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#tag-a-resource)
+    pub fn add_resources<S>(self, resources: Vec<(S, S)>) -> TagRequest<Create, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let resources = resources.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.tag_resources(&resources)
+    }
+    /// Accepts tuples matching `(id, type)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#untag-a-resource)
+    pub fn remove_resources<S>(self, resources: Vec<(S, S)>) -> TagRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let resources = resources.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.untag_resources(&resources)
+    }
 }
 
 /// Response type returned from Digital Ocean.
@@ -0,0 +1,650 @@
+use self::uptime_check_fields::{PreviousOutage, RegionState};
+use super::alert_policy_fields::{Alerts, SlackDetails};
+use super::{collect_strings, HasPagination, HasResponse, HasValue};
+use super::{ApiLinks, ApiMeta};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::Request;
+use crate::request::{UptimeAlertRequest, UptimeCheckRequest};
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+use url::Url;
+
+const UPTIME_SEGMENT: &str = "uptime";
+const CHECKS_SEGMENT: &str = "checks";
+const STATE_SEGMENT: &str = "state";
+const ALERTS_SEGMENT: &str = "alerts";
+
+/// Uptime checks monitor the availability of a website or host from
+/// several regions, and can notify you when it goes down or responds slowly.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#uptime)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct UptimeCheck {
+    /// A unique ID that can be used to identify and reference the check.
+    id: String,
+    /// A human-friendly display name for the check.
+    name: String,
+    /// The type of health check to perform.
+    ///
+    /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+    #[serde(rename = "type")]
+    kind: UptimeCheckType,
+    /// The endpoint to perform the check on, such as a URL or an IP address.
+    target: String,
+    /// The regions the check is performed from, such as "us_east" or
+    /// "eu_west".
+    #[serde(default)]
+    regions: Vec<String>,
+    /// If false, the check is not performed.
+    enabled: bool,
+}
+
+identified_by!(UptimeCheck, id);
+
+string_enum!(
+    /// The type of an [`UptimeCheck`](struct.UptimeCheck.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#uptime)
+    UptimeCheckType {
+        /// The target is pinged.
+        Ping => "ping",
+        /// An HTTP request is sent to the target.
+        Http => "http",
+        /// An HTTPS request is sent to the target.
+        Https => "https",
+    }
+);
+
+/// The current state of an uptime check, in each region it is performed
+/// from.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-check-state)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct UptimeCheckState {
+    /// The state of the check in each region, keyed by region.
+    #[serde(default)]
+    regions: HashMap<String, RegionState>,
+    /// The most recent outage, if there was one.
+    #[serde(default)]
+    previous_outage: Option<PreviousOutage>,
+}
+
+/// An alert which notifies you when an uptime check goes down, responds
+/// slowly, or has an expiring SSL certificate.
+///
+/// Requests with this output this type are accessed via [`UptimeCheck::get(..).alerts()`](../request/type.UptimeCheckRequest.html#method.alerts).
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#uptime)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct UptimeAlert {
+    /// A unique ID that can be used to identify and reference the alert.
+    id: String,
+    /// A human-friendly display name for the alert.
+    name: String,
+    /// The type of alert.
+    ///
+    /// *Note:* Since `type` is a keyword in Rust `kind` is used instead.
+    #[serde(rename = "type")]
+    kind: UptimeAlertType,
+    /// The threshold at which the alert triggers, in milliseconds for
+    /// "latency" alerts and in days for "ssl_expiry" alerts.
+    #[serde(default)]
+    threshold: Option<usize>,
+    /// How the check is compared to `threshold`. This may be "greater_than"
+    /// or "less_than".
+    #[serde(default)]
+    comparison: Option<String>,
+    /// Where notifications are sent when the alert triggers.
+    notifications: Alerts,
+    /// The period of time the threshold must be crossed for, such as "2m",
+    /// "3m", "5m", "10m", "15m", "30m", or "1h".
+    period: String,
+}
+
+string_enum!(
+    /// The type of an [`UptimeAlert`](struct.UptimeAlert.html).
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#uptime)
+    UptimeAlertType {
+        /// The check took longer than the threshold.
+        Latency => "latency",
+        /// The check failed in a region.
+        Down => "down",
+        /// The check failed in every region.
+        DownGlobal => "down_global",
+        /// The certificate of the target expires within the threshold.
+        SslExpiry => "ssl_expiry",
+    }
+);
+
+/// Fields which exists inside uptime check states.
+pub mod uptime_check_fields {
+    use chrono::{DateTime, Utc};
+
+    /// This exists in the `regions` field of an uptime check state.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct RegionState {
+        /// The status of the check in the region.
+        pub status: RegionStatus,
+        /// When the status last changed.
+        #[serde(default)]
+        pub status_changed_at: Option<DateTime<Utc>>,
+        /// The percentage of time over the last thirty days the check was up
+        /// in the region.
+        #[serde(default)]
+        pub thirty_day_uptime_percentage: f64,
+    }
+
+    string_enum!(
+        /// The status of an uptime check in a region, in its
+        /// [`RegionState`](struct.RegionState.html).
+        ///
+        /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#uptime)
+        RegionStatus {
+            /// The target is up.
+            Up => "UP",
+            /// The target is down.
+            Down => "DOWN",
+            /// The target has not been checked yet.
+            Checking => "CHECKING",
+        }
+    );
+
+    /// This exists in the `previous_outage` field of an uptime check state.
+    #[derive(Deserialize, Serialize, Debug, Clone)]
+    pub struct PreviousOutage {
+        /// The region the outage was detected in.
+        pub region: String,
+        /// When the outage started.
+        pub started_at: DateTime<Utc>,
+        /// When the outage ended.
+        pub ended_at: DateTime<Utc>,
+        /// How long the outage lasted, in seconds.
+        pub duration_seconds: usize,
+    }
+}
+
+impl UptimeCheck {
+    /// Creates an enabled check.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-check)
+    pub fn create<S>(
+        name: S,
+        kind: S,
+        target: S,
+        regions: &[S],
+    ) -> UptimeCheckRequest<Create, UptimeCheck>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(UPTIME_SEGMENT)
+            .push(CHECKS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "type": kind,
+            "target": target,
+            "regions": regions,
+            "enabled": true,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-checks)
+    pub fn list() -> UptimeCheckRequest<List, Vec<UptimeCheck>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(UPTIME_SEGMENT)
+            .push(CHECKS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// The state and alerts of the check are retrieved by chaining
+    /// `.state()` or `.alerts()` onto this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-check)
+    pub fn get<S>(id: S) -> UptimeCheckRequest<Get, UptimeCheck>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(UPTIME_SEGMENT)
+            .push(CHECKS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// Only the fields set on the returned request are changed.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-check)
+    pub fn update<S>(id: S) -> UptimeCheckRequest<Update, UptimeCheck>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(UPTIME_SEGMENT)
+            .push(CHECKS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// Deletes the check along with its alerts.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-check)
+    pub fn delete<S>(id: S) -> UptimeCheckRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(UPTIME_SEGMENT)
+            .push(CHECKS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl UptimeCheckRequest<Create, UptimeCheck> {
+    /// If false, the check is created without being performed.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-check)
+    pub fn enabled(mut self, val: bool) -> UptimeCheckRequest<Create, UptimeCheck> {
+        self.body_mut()["enabled"] = json!(val);
+        self
+    }
+}
+
+impl UptimeCheckRequest<Update, UptimeCheck> {
+    /// A human-friendly display name for the check.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-check)
+    pub fn name<S>(mut self, val: S) -> UptimeCheckRequest<Update, UptimeCheck>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["name"] = json!(val);
+        self
+    }
+    /// The type of health check to perform. This may be "ping", "http", or
+    /// "https".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-check)
+    pub fn kind<S>(mut self, val: S) -> UptimeCheckRequest<Update, UptimeCheck>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["type"] = json!(val);
+        self
+    }
+    /// The endpoint to perform the check on, such as a URL or an IP address.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-check)
+    pub fn target<S>(mut self, val: S) -> UptimeCheckRequest<Update, UptimeCheck>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["target"] = json!(val);
+        self
+    }
+    /// The regions the check is performed from, such as "us_east" or
+    /// "eu_west". Accepts any collection of `&str` or `String`, such as a
+    /// slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-check)
+    pub fn regions<I, S>(mut self, val: I) -> UptimeCheckRequest<Update, UptimeCheck>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["regions"] = json!(collect_strings(val));
+        self
+    }
+    /// If false, the check is no longer performed.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-check)
+    pub fn enabled(mut self, val: bool) -> UptimeCheckRequest<Update, UptimeCheck> {
+        self.body_mut()["enabled"] = json!(val);
+        self
+    }
+}
+
+impl UptimeCheckRequest<Get, UptimeCheck> {
+    /// The current status of the check in each region.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-check-state)
+    pub fn state(mut self) -> UptimeCheckRequest<Get, UptimeCheckState> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(STATE_SEGMENT);
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-alerts)
+    pub fn alerts(mut self) -> UptimeAlertRequest<List, Vec<UptimeAlert>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(ALERTS_SEGMENT);
+
+        self.transmute()
+    }
+}
+
+impl UptimeAlertRequest<List, Vec<UptimeAlert>> {
+    /// Creates an alert without any notification targets, which are added by
+    /// chaining `.email()` and `.slack()` onto this.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-alert)
+    pub fn create<S>(
+        mut self,
+        name: S,
+        kind: S,
+        period: S,
+    ) -> UptimeAlertRequest<Create, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.set_body(json!({
+            "name": name,
+            "type": kind,
+            "period": period,
+            "notifications": {
+                "email": [],
+                "slack": [],
+            },
+        }));
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-alert)
+    pub fn get<S>(mut self, id: S) -> UptimeAlertRequest<Get, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(id.as_ref());
+
+        self.transmute()
+    }
+    /// Only the fields set on the returned request are changed.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn update<S>(mut self, id: S) -> UptimeAlertRequest<Update, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(id.as_ref());
+
+        self.transmute()
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-an-alert)
+    pub fn delete<S>(mut self, id: S) -> UptimeAlertRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(id.as_ref());
+
+        self.transmute()
+    }
+}
+
+impl UptimeAlertRequest<Create, UptimeAlert> {
+    /// The threshold at which the alert triggers, in milliseconds for
+    /// "latency" alerts and in days for "ssl_expiry" alerts.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-alert)
+    pub fn threshold(mut self, val: usize) -> UptimeAlertRequest<Create, UptimeAlert> {
+        self.body_mut()["threshold"] = json!(val);
+        self
+    }
+    /// How the check is compared to `threshold`. This may be "greater_than"
+    /// or "less_than".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-alert)
+    pub fn comparison<S>(mut self, val: S) -> UptimeAlertRequest<Create, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["comparison"] = json!(val);
+        self
+    }
+    /// The email addresses notified when the alert triggers. Accepts any
+    /// collection of `&str` or `String`, such as a slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-alert)
+    pub fn email<I, S>(mut self, val: I) -> UptimeAlertRequest<Create, UptimeAlert>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["notifications"]["email"] = json!(collect_strings(val));
+        self
+    }
+    /// Adds a Slack channel notified when the alert triggers. Accepts a
+    /// [`SlackDetails`](alert_policy_fields/struct.SlackDetails.html) or a
+    /// tuple matching `(channel, url)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-alert)
+    pub fn slack<T>(mut self, val: T) -> UptimeAlertRequest<Create, UptimeAlert>
+    where
+        T: Into<SlackDetails>,
+    {
+        push_slack(self.body_mut(), val.into());
+        self
+    }
+}
+
+impl UptimeAlertRequest<Update, UptimeAlert> {
+    /// A human-friendly display name for the alert.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn name<S>(mut self, val: S) -> UptimeAlertRequest<Update, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["name"] = json!(val);
+        self
+    }
+    /// The type of alert. This may be "latency", "down", "down_global", or
+    /// "ssl_expiry".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn kind<S>(mut self, val: S) -> UptimeAlertRequest<Update, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["type"] = json!(val);
+        self
+    }
+    /// The period of time the threshold must be crossed for, such as "2m",
+    /// "3m", "5m", "10m", "15m", "30m", or "1h".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn period<S>(mut self, val: S) -> UptimeAlertRequest<Update, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["period"] = json!(val);
+        self
+    }
+    /// The threshold at which the alert triggers, in milliseconds for
+    /// "latency" alerts and in days for "ssl_expiry" alerts.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn threshold(mut self, val: usize) -> UptimeAlertRequest<Update, UptimeAlert> {
+        self.body_mut()["threshold"] = json!(val);
+        self
+    }
+    /// How the check is compared to `threshold`. This may be "greater_than"
+    /// or "less_than".
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn comparison<S>(mut self, val: S) -> UptimeAlertRequest<Update, UptimeAlert>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["comparison"] = json!(val);
+        self
+    }
+    /// The email addresses notified when the alert triggers. Accepts any
+    /// collection of `&str` or `String`, such as a slice, `Vec`, or array.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn email<I, S>(mut self, val: I) -> UptimeAlertRequest<Update, UptimeAlert>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["notifications"]["email"] = json!(collect_strings(val));
+        self
+    }
+    /// Adds a Slack channel notified when the alert triggers. Accepts a
+    /// [`SlackDetails`](alert_policy_fields/struct.SlackDetails.html) or a
+    /// tuple matching `(channel, url)`.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-an-alert)
+    pub fn slack<T>(mut self, val: T) -> UptimeAlertRequest<Update, UptimeAlert>
+    where
+        T: Into<SlackDetails>,
+    {
+        push_slack(self.body_mut(), val.into());
+        self
+    }
+}
+
+fn push_slack(body: &mut serde_json::Value, slack: SlackDetails) {
+    if !body["notifications"]["slack"].is_array() {
+        body["notifications"]["slack"] = json!([]);
+    }
+
+    body["notifications"]["slack"]
+        .as_array_mut()
+        .expect("slack should always be an array.")
+        .push(json!(slack));
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UptimeCheckResponse {
+    check: UptimeCheck,
+}
+
+impl HasResponse for UptimeCheck {
+    type Response = UptimeCheckResponse;
+}
+
+impl HasValue for UptimeCheckResponse {
+    type Value = UptimeCheck;
+    fn value(self) -> UptimeCheck {
+        self.check
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UptimeCheckListResponse {
+    checks: Vec<UptimeCheck>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<UptimeCheck> {
+    type Response = UptimeCheckListResponse;
+}
+
+impl HasPagination for UptimeCheckListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for UptimeCheckListResponse {
+    type Value = Vec<UptimeCheck>;
+    fn value(self) -> Vec<UptimeCheck> {
+        self.checks
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UptimeCheckStateResponse {
+    state: UptimeCheckState,
+}
+
+impl HasResponse for UptimeCheckState {
+    type Response = UptimeCheckStateResponse;
+}
+
+impl HasValue for UptimeCheckStateResponse {
+    type Value = UptimeCheckState;
+    fn value(self) -> UptimeCheckState {
+        self.state
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UptimeAlertResponse {
+    alert: UptimeAlert,
+}
+
+impl HasResponse for UptimeAlert {
+    type Response = UptimeAlertResponse;
+}
+
+impl HasValue for UptimeAlertResponse {
+    type Value = UptimeAlert;
+    fn value(self) -> UptimeAlert {
+        self.alert
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UptimeAlertListResponse {
+    alerts: Vec<UptimeAlert>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<UptimeAlert> {
+    type Response = UptimeAlertListResponse;
+}
+
+impl HasPagination for UptimeAlertListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for UptimeAlertListResponse {
+    type Value = Vec<UptimeAlert>;
+    fn value(self) -> Vec<UptimeAlert> {
+        self.alerts
+    }
+}
@@ -1,7 +1,8 @@
+use super::id::{DropletId, VolumeId};
 use super::region::Region;
 use super::snapshot::Snapshot;
+use super::{collect_strings, HasPagination, HasResponse, HasValue};
 use super::{ApiLinks, ApiMeta};
-use super::{HasPagination, HasResponse, HasValue};
 use crate::method::{Create, Delete, Get, List};
 use crate::request::Request;
 use crate::request::{SnapshotRequest, VolumeRequest};
@@ -35,7 +36,7 @@ pub struct Volume {
     /// An array containing the IDs of the Droplets the volume is attached to.
     /// Note that at this time, a volume can only be attached to a single
     /// Droplet.
-    droplet_ids: Vec<usize>,
+    droplet_ids: Vec<DropletId>,
     /// A human-readable name for the Block Storage volume. Must be lowercase
     /// and be composed only of numbers, letters and "-", up to a limit of 64
     /// characters.
@@ -49,6 +50,8 @@ pub struct Volume {
     created_at: DateTime<Utc>,
 }
 
+identified_by!(Volume, id);
+
 impl Volume {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-block-storage-volumes)
     pub fn list() -> VolumeRequest<List, Vec<Volume>> {
@@ -76,21 +79,51 @@ impl Volume {
         }));
         req
     }
-    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-block-storage-volume)
-    pub fn get<S>(id: S) -> VolumeRequest<Get, Volume>
+    /// Create a volume from a volume snapshot. The size of the volume is
+    /// taken from the snapshot, and `region` must be the region the snapshot
+    /// is in.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-block-storage-volume)
+    pub fn create_from_snapshot<S>(
+        name: S,
+        snapshot_id: S,
+        region: S,
+    ) -> VolumeRequest<Create, Volume>
     where
         S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(VOLUME_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "snapshot_id": snapshot_id,
+            "region": region,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-block-storage-volume)
+    pub fn get<I>(id: I) -> VolumeRequest<Get, Volume>
+    where
+        I: Into<VolumeId>,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(VOLUME_SEGMENT)
-            .push(id.as_ref());
+            .push(id.into().as_ref());
 
         Request::new(url)
     }
+    /// The volume with the given name in `region`. Volume names are only
+    /// unique within a region, so the API returns this as a list, which has
+    /// a single volume when it exists and is empty otherwise.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-block-storage-volume-by-name)
-    pub fn get_by_name<S>(name: S, region: S) -> VolumeRequest<Get, Volume>
+    pub fn get_by_name<S>(name: S, region: S) -> VolumeRequest<List, Vec<Volume>>
     where
         S: AsRef<str> + Serialize + Display,
     {
@@ -106,19 +139,19 @@ impl Volume {
         Request::new(url)
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-block-storage-volume)
-    pub fn delete<S>(id: S) -> VolumeRequest<Delete, ()>
+    pub fn delete<I>(id: I) -> VolumeRequest<Delete, ()>
     where
-        S: AsRef<str> + Serialize + Display,
+        I: Into<VolumeId>,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
             .expect(STATIC_URL_ERROR)
             .push(VOLUME_SEGMENT)
-            .push(id.as_ref());
+            .push(id.into().as_ref());
 
         Request::new(url)
     }
-    /// [Digital Ocean Documentation.](hhttps://developers.digitalocean.com/documentation/v2/#delete-a-block-storage-volume-by-name)
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-block-storage-volume-by-name)
     pub fn delete_by_name<S>(name: S, region: S) -> VolumeRequest<Delete, ()>
     where
         S: AsRef<str> + Serialize + Display,
@@ -151,6 +184,8 @@ impl VolumeRequest<List, Vec<Volume>> {
 }
 
 impl VolumeRequest<Get, Volume> {
+    /// The snapshots which have been taken of the volume.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-snapshots-for-a-volume)
     pub fn snapshots(mut self) -> SnapshotRequest<List, Vec<Snapshot>> {
         self.url_mut()
@@ -160,6 +195,10 @@ impl VolumeRequest<Get, Volume> {
 
         self.transmute()
     }
+    /// Take a snapshot of the volume with the given name. Unlike resizing or
+    /// attaching, this is not a volume action; the snapshot is returned
+    /// directly.
+    ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-snapshot-from-a-volume)
     pub fn snapshot<S>(mut self, name: S) -> SnapshotRequest<Create, Snapshot>
     where
@@ -176,6 +215,20 @@ impl VolumeRequest<Get, Volume> {
     }
 }
 
+impl SnapshotRequest<Create, Snapshot> {
+    /// Tags to apply to the snapshot.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-snapshot-from-a-volume)
+    pub fn tags<I, S>(mut self, val: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.body_mut()["tags"] = json!(collect_strings(val));
+        self
+    }
+}
+
 impl VolumeRequest<Create, Volume> {
     /// An optional free-form text field to describe a Block Storage volume.
     ///
@@ -192,7 +245,8 @@ impl VolumeRequest<Create, Volume> {
     /// you query a Block Storage volume, the entire region object will be
     /// returned.
     ///
-    /// **Note:** Should not be specified with a `snapshot_id`.
+    /// **Note:** When creating from a `snapshot_id` this must be the region
+    /// the snapshot is in.
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#block-storage)
     pub fn region<S>(mut self, val: S) -> Self
@@ -204,9 +258,8 @@ impl VolumeRequest<Create, Volume> {
     }
 
     /// The unique identifier for the volume snapshot from which to create the
-    /// volume.
-    ///
-    /// **Note:** Should not be specified with a `region_id`.
+    /// volume. See
+    /// [`Volume::create_from_snapshot()`](../api/struct.Volume.html#method.create_from_snapshot).
     ///
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#block-storage)
     pub fn snapshot_id<S>(mut self, val: S) -> Self
@@ -1,4 +1,5 @@
 use super::action::Action;
+use super::id::DropletId;
 use super::volume::Volume;
 use crate::method::{Create, Get, List};
 use crate::request::Request;
@@ -12,9 +13,10 @@ const VOLUME_ACTIONS_SEGMENT: &str = "actions";
 
 impl Volume {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#attach-a-block-storage-volume-to-a-droplet-by-name)
-    pub fn attach<S>(volume_name: S, droplet: usize) -> VolumeActionRequest<Create, Action>
+    pub fn attach<S, D>(volume_name: S, droplet: D) -> VolumeActionRequest<Create, Action>
     where
         S: AsRef<str> + Serialize + Display,
+        D: Into<DropletId>,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
@@ -25,14 +27,15 @@ impl Volume {
         req.set_body(json!({
             "type": "attach",
             "volume_name": volume_name,
-            "droplet_id": droplet,
+            "droplet_id": droplet.into(),
         }));
         req
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#remove-a-block-storage-volume-from-a-droplet-by-name)
-    pub fn detach<S>(volume_name: S, droplet: usize) -> VolumeActionRequest<Create, Action>
+    pub fn detach<S, D>(volume_name: S, droplet: D) -> VolumeActionRequest<Create, Action>
     where
         S: AsRef<str> + Serialize + Display,
+        D: Into<DropletId>,
     {
         let mut url = ROOT_URL.clone();
         url.path_segments_mut()
@@ -43,7 +46,7 @@ impl Volume {
         req.set_body(json!({
             "type": "detach",
             "volume_name": volume_name,
-            "droplet_id": droplet,
+            "droplet_id": droplet.into(),
         }));
         req
     }
@@ -51,7 +54,10 @@ impl Volume {
 
 impl VolumeRequest<Get, Volume> {
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#attach-a-block-storage-volume-to-a-droplet)
-    pub fn attach(mut self, droplet: usize) -> VolumeActionRequest<Create, Action> {
+    pub fn attach<D>(mut self, droplet: D) -> VolumeActionRequest<Create, Action>
+    where
+        D: Into<DropletId>,
+    {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -59,13 +65,16 @@ impl VolumeRequest<Get, Volume> {
 
         self.set_body(json!({
             "type": "attach",
-            "droplet_id": droplet,
+            "droplet_id": droplet.into(),
         }));
 
         self.transmute()
     }
     /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#remove-a-block-storage-volume-from-a-droplet)
-    pub fn detach(mut self, droplet: usize) -> VolumeActionRequest<Create, Action> {
+    pub fn detach<D>(mut self, droplet: D) -> VolumeActionRequest<Create, Action>
+    where
+        D: Into<DropletId>,
+    {
         self.url_mut()
             .path_segments_mut()
             .expect(STATIC_URL_ERROR)
@@ -73,7 +82,7 @@ impl VolumeRequest<Get, Volume> {
 
         self.set_body(json!({
             "type": "detach",
-            "droplet_id": droplet,
+            "droplet_id": droplet.into(),
         }));
 
         self.transmute()
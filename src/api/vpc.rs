@@ -0,0 +1,261 @@
+use super::{ApiLinks, ApiMeta};
+use super::{HasPagination, HasResponse, HasValue};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::Request;
+use crate::request::VpcRequest;
+use crate::{ROOT_URL, STATIC_URL_ERROR};
+use chrono::{DateTime, Utc};
+use getset::{Getters, Setters};
+use serde::Serialize;
+use std::fmt::Display;
+use url::Url;
+
+const VPCS_SEGMENT: &str = "vpcs";
+const MEMBERS_SEGMENT: &str = "members";
+
+/// VPCs (virtual private clouds) are private networks that contain resources,
+/// such as Droplets, Load Balancers, and database clusters, which can
+/// communicate with each other over their private IP addresses.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#vpcs)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct Vpc {
+    /// A unique ID that can be used to identify and reference the VPC.
+    id: String,
+    /// The uniform resource name (URN) for the VPC.
+    urn: String,
+    /// A human-readable name for the VPC.
+    name: String,
+    /// A free-form text field for describing the VPC's purpose.
+    #[serde(default)]
+    description: String,
+    /// The slug identifier for the region where the VPC is located.
+    region: String,
+    /// The range of IP addresses in the VPC in CIDR notation.
+    ip_range: String,
+    /// If true, this is the default VPC of its region.
+    default: bool,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the VPC was created.
+    created_at: DateTime<Utc>,
+}
+
+identified_by!(Vpc, id);
+
+/// A resource located in a VPC.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-the-member-resources-of-a-vpc)
+#[derive(Deserialize, Serialize, Debug, Clone, Getters, Setters)]
+#[get = "pub"]
+pub struct VpcMember {
+    /// The uniform resource name (URN) of the resource, such as
+    /// "do:droplet:13457723".
+    urn: String,
+    /// The name of the resource.
+    name: String,
+    /// A time value given in ISO8601 combined date and time format that
+    /// represents when the resource was created.
+    created_at: DateTime<Utc>,
+}
+
+impl Vpc {
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-vpc)
+    pub fn create<S>(name: S, region: S) -> VpcRequest<Create, Vpc>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(VPCS_SEGMENT);
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+            "region": region,
+        }));
+        req
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#retrieve-an-existing-vpc)
+    pub fn get<S>(id: S) -> VpcRequest<Get, Vpc>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(VPCS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-all-vpcs)
+    pub fn list() -> VpcRequest<List, Vec<Vpc>> {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(VPCS_SEGMENT);
+
+        Request::new(url)
+    }
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-vpc)
+    pub fn update<S>(id: S, name: S) -> VpcRequest<Update, Vpc>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(VPCS_SEGMENT)
+            .push(id.as_ref());
+
+        let mut req = Request::new(url);
+        req.set_body(json!({
+            "name": name,
+        }));
+        req
+    }
+    /// **Note:** A VPC can only be deleted once it contains no resources, and
+    /// the default VPC of a region can not be deleted.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#delete-a-vpc)
+    pub fn delete<S>(id: S) -> VpcRequest<Delete, ()>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        let mut url = ROOT_URL.clone();
+        url.path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(VPCS_SEGMENT)
+            .push(id.as_ref());
+
+        Request::new(url)
+    }
+}
+
+impl VpcRequest<Create, Vpc> {
+    /// A free-form text field for describing the VPC's purpose.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-vpc)
+    pub fn description<S>(mut self, val: S) -> VpcRequest<Create, Vpc>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["description"] = json!(val);
+        self
+    }
+    /// The range of IP addresses in the VPC in CIDR notation. When excluded,
+    /// a range is generated automatically.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#create-a-new-vpc)
+    pub fn ip_range<S>(mut self, val: S) -> VpcRequest<Create, Vpc>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["ip_range"] = json!(val);
+        self
+    }
+}
+
+impl VpcRequest<Update, Vpc> {
+    /// A free-form text field for describing the VPC's purpose.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-vpc)
+    pub fn description<S>(mut self, val: S) -> VpcRequest<Update, Vpc>
+    where
+        S: AsRef<str> + Serialize + Display,
+    {
+        self.body_mut()["description"] = json!(val);
+        self
+    }
+    /// Makes this the default VPC of its region. The default can not be
+    /// unset, only moved to another VPC.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#update-a-vpc)
+    pub fn default(mut self, val: bool) -> VpcRequest<Update, Vpc> {
+        self.body_mut()["default"] = json!(val);
+        self
+    }
+}
+
+impl VpcRequest<Get, Vpc> {
+    /// The resources located in the VPC.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#list-the-member-resources-of-a-vpc)
+    pub fn members(mut self) -> VpcRequest<List, Vec<VpcMember>> {
+        self.url_mut()
+            .path_segments_mut()
+            .expect(STATIC_URL_ERROR)
+            .push(MEMBERS_SEGMENT);
+
+        self.transmute()
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VpcResponse {
+    vpc: Vpc,
+}
+
+impl HasResponse for Vpc {
+    type Response = VpcResponse;
+}
+
+impl HasValue for VpcResponse {
+    type Value = Vpc;
+    fn value(self) -> Vpc {
+        self.vpc
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VpcListResponse {
+    vpcs: Vec<Vpc>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<Vpc> {
+    type Response = VpcListResponse;
+}
+
+impl HasPagination for VpcListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for VpcListResponse {
+    type Value = Vec<Vpc>;
+    fn value(self) -> Vec<Vpc> {
+        self.vpcs
+    }
+}
+
+/// Response type returned from Digital Ocean.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VpcMemberListResponse {
+    members: Vec<VpcMember>,
+    links: ApiLinks,
+    meta: ApiMeta,
+}
+
+impl HasResponse for Vec<VpcMember> {
+    type Response = VpcMemberListResponse;
+}
+
+impl HasPagination for VpcMemberListResponse {
+    fn next_page(&self) -> Option<Url> {
+        self.links.next()
+    }
+}
+
+impl HasValue for VpcMemberListResponse {
+    type Value = Vec<VpcMember>;
+    fn value(self) -> Vec<VpcMember> {
+        self.members
+    }
+}
@@ -0,0 +1,178 @@
+use super::{check_delete, check_get, check_post, check_put};
+use super::{deserialize, deserialize_page, AsyncHttpClient, PageCursor, RawResponse};
+use super::{replayable, IDEMPOTENCY_KEY};
+use crate::api::{HasPagination, HasResponse};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::Request;
+use crate::response::Response;
+use crate::DigitalOcean;
+use failure::Error;
+use log::{info, warn};
+use reqwest::header::HeaderValue;
+use reqwest::Method;
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use url::Url;
+
+impl<C> DigitalOcean<C>
+where
+    C: AsyncHttpClient + Sync,
+{
+    pub(crate) async fn get_async<V>(&self, request: Request<Get, V>) -> Result<Response<V>, Error>
+    where
+        V: HasResponse,
+    {
+        let url = self.rebase(request.url());
+
+        let response = self
+            .fetch_async(Method::GET, &url, None, None, request.timeout)
+            .await?;
+        check_get(response.status, &response.body)?;
+
+        deserialize(&response)
+    }
+
+    pub(crate) async fn list_async<V>(
+        &self,
+        request: Request<List, Vec<V>>,
+    ) -> Result<Response<Vec<V>>, Error>
+    where
+        Vec<V>: HasResponse,
+        <Vec<V> as HasResponse>::Response: HasPagination,
+    {
+        // This may be a paginated response. We need to buffer.
+        let mut buffer = Vec::new();
+        let mut pages = PageCursor::new(&request);
+
+        while let Some(url) = pages.next_url() {
+            if pages.fetched() > 0 {
+                info!("Fetching next page...");
+            }
+            let response = self
+                .fetch_async(Method::GET, &self.rebase(&url), None, None, request.timeout)
+                .await?;
+            check_get(response.status, &response.body)?;
+
+            let (page, next_page) = deserialize_page(&response)?;
+            buffer.extend(pages.advance(&request, page, next_page));
+        }
+
+        Ok(Response::new(buffer, *pages.rate_limit()))
+    }
+
+    // Delete requests do not return content.
+    pub(crate) async fn delete_async<V>(
+        &self,
+        request: Request<Delete, V>,
+    ) -> Result<Response<()>, Error> {
+        let url = self.rebase(request.url());
+
+        // Some deletions (such as removing Droplets from a Load Balancer)
+        // describe what to delete in the body.
+        let body = Some(request.body()).filter(|body| !body.is_null());
+        let response = self
+            .fetch_async(Method::DELETE, &url, body, None, request.timeout)
+            .await?;
+        check_delete(response.status, &response.body)?;
+
+        Ok(Response::new((), response.rate_limit()))
+    }
+
+    pub(crate) async fn post_async<V>(
+        &self,
+        request: Request<Create, V>,
+    ) -> Result<Response<V>, Error>
+    where
+        V: HasResponse,
+    {
+        let url = self.rebase(request.url());
+
+        // Some creations (such as starting a garbage collection) take no body.
+        let body = Some(request.body()).filter(|body| !body.is_null());
+        let response = self
+            .fetch_async(
+                Method::POST,
+                &url,
+                body,
+                request.idempotency_key.as_deref(),
+                request.timeout,
+            )
+            .await?;
+        check_post(response.status, &response.body)?;
+
+        deserialize(&response)
+    }
+
+    pub(crate) async fn put_async<V>(
+        &self,
+        request: Request<Update, V>,
+    ) -> Result<Response<V>, Error>
+    where
+        V: HasResponse,
+    {
+        let url = self.rebase(request.url());
+
+        let response = self
+            .fetch_async(
+                Method::PUT,
+                &url,
+                Some(request.body()),
+                None,
+                request.timeout,
+            )
+            .await?;
+        check_put(response.status, &response.body)?;
+
+        deserialize(&response)
+    }
+
+    async fn fetch_async(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<&Value>,
+        idempotency_key: Option<&str>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        let mut headers = self.headers(body.is_some())?;
+        if let Some(key) = idempotency_key {
+            headers.insert(IDEMPOTENCY_KEY, HeaderValue::from_str(key)?);
+        }
+        let replayable = replayable(&method, &headers);
+        let body = body.map(|body| body.to_string().into_bytes());
+        let mut attempt = 0;
+
+        loop {
+            self.log_request(&method, url, body.as_deref());
+            let sent = Instant::now();
+            let response = match self
+                .client
+                .execute_async(method.clone(), url, headers.clone(), body.clone(), timeout)
+                .await
+            {
+                Ok(response) => self.redact_response(response),
+                Err(error) => {
+                    let delay = self.transport_retry(&mut attempt, error, replayable)?;
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+            };
+
+            self.log_response(&method, url, &response, sent.elapsed());
+            match self
+                .retry
+                .and_then(|policy| policy.delay(attempt, &response, replayable))
+            {
+                Some(delay) => {
+                    attempt += 1;
+                    warn!(
+                        "Response status {:?}, retry {} in {:?}.",
+                        response.status, attempt, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                None => return Ok(response),
+            }
+        }
+    }
+}
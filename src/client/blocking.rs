@@ -0,0 +1,227 @@
+use super::{check_delete, check_get, check_post, check_put};
+use super::{deserialize, deserialize_page, deserialize_paged, HttpClient, RawResponse};
+use super::{replayable, status_error, StreamResponse, IDEMPOTENCY_KEY};
+use crate::api::{HasPagination, HasResponse};
+use crate::method::{Create, Delete, Get, List, Update};
+use crate::request::Request;
+use crate::response::{PagedResponse, Response};
+use crate::DigitalOcean;
+use failure::Error;
+use log::warn;
+use reqwest::header::HeaderValue;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+use std::thread;
+use std::time::{Duration, Instant};
+use url::Url;
+
+impl<C> DigitalOcean<C>
+where
+    C: HttpClient,
+{
+    pub(crate) fn get<V>(&self, request: Request<Get, V>) -> Result<Response<V>, Error>
+    where
+        V: HasResponse,
+    {
+        let url = self.rebase(request.url());
+
+        let response = self.fetch(Method::GET, &url, None, None, request.timeout)?;
+        check_get(response.status, &response.body)?;
+
+        deserialize(&response)
+    }
+
+    pub(crate) fn list<V>(&self, request: Request<List, Vec<V>>) -> Result<Response<Vec<V>>, Error>
+    where
+        Vec<V>: HasResponse,
+        <Vec<V> as HasResponse>::Response: HasPagination,
+    {
+        // This may be a paginated response. We need to buffer.
+        let mut buffer = Vec::new();
+        let mut pages = request.paginate(self);
+
+        for page in &mut pages {
+            buffer.extend(page?);
+        }
+
+        Ok(Response::new(buffer, *pages.rate_limit()))
+    }
+
+    pub(crate) fn list_page<V>(
+        &self,
+        url: &Url,
+        timeout: Option<Duration>,
+    ) -> Result<(Response<Vec<V>>, Option<Url>), Error>
+    where
+        Vec<V>: HasResponse,
+        <Vec<V> as HasResponse>::Response: HasPagination,
+    {
+        let response = self.fetch(Method::GET, &self.rebase(url), None, None, timeout)?;
+        check_get(response.status, &response.body)?;
+
+        deserialize_page(&response)
+    }
+
+    pub(crate) fn list_paged<V>(
+        &self,
+        url: &Url,
+        timeout: Option<Duration>,
+    ) -> Result<PagedResponse<V>, Error>
+    where
+        Vec<V>: HasResponse,
+        <Vec<V> as HasResponse>::Response: HasPagination,
+    {
+        let response = self.fetch(Method::GET, &self.rebase(url), None, None, timeout)?;
+        check_get(response.status, &response.body)?;
+
+        deserialize_paged(&response)
+    }
+
+    // Delete requests do not return content.
+    pub(crate) fn delete<V>(&self, request: Request<Delete, V>) -> Result<Response<()>, Error> {
+        let url = self.rebase(request.url());
+
+        // Some deletions (such as removing Droplets from a Load Balancer)
+        // describe what to delete in the body.
+        let body = Some(request.body()).filter(|body| !body.is_null());
+        let response = self.fetch(Method::DELETE, &url, body, None, request.timeout)?;
+        check_delete(response.status, &response.body)?;
+
+        Ok(Response::new((), response.rate_limit()))
+    }
+
+    pub(crate) fn post<V>(&self, request: Request<Create, V>) -> Result<Response<V>, Error>
+    where
+        V: HasResponse,
+    {
+        let url = self.rebase(request.url());
+
+        // Some creations (such as starting a garbage collection) take no body.
+        let body = Some(request.body()).filter(|body| !body.is_null());
+        let response = self.fetch(
+            Method::POST,
+            &url,
+            body,
+            request.idempotency_key.as_deref(),
+            request.timeout,
+        )?;
+        check_post(response.status, &response.body)?;
+
+        deserialize(&response)
+    }
+
+    pub(crate) fn put<V>(&self, request: Request<Update, V>) -> Result<Response<V>, Error>
+    where
+        V: HasResponse,
+    {
+        let url = self.rebase(request.url());
+
+        let response = self.fetch(
+            Method::PUT,
+            &url,
+            Some(request.body()),
+            None,
+            request.timeout,
+        )?;
+        check_put(response.status, &response.body)?;
+
+        deserialize(&response)
+    }
+
+    /// Gets the response without buffering its body, unless it is an error.
+    pub(crate) fn get_stream<V>(&self, request: Request<Get, V>) -> Result<StreamResponse, Error> {
+        let url = self.rebase(request.url());
+
+        let headers = self.headers(false)?;
+        let mut attempt = 0;
+
+        loop {
+            self.log_request(&Method::GET, &url, None);
+            let sent = Instant::now();
+            let response = match self.client.execute_stream(
+                Method::GET,
+                &url,
+                headers.clone(),
+                None,
+                request.timeout,
+            ) {
+                Ok(response) => response,
+                Err(error) => {
+                    thread::sleep(self.transport_retry(&mut attempt, error, true)?);
+                    continue;
+                }
+            };
+
+            self.log_status(&Method::GET, &url, *response.status(), sent.elapsed());
+            if *response.status() == StatusCode::OK {
+                return Ok(response);
+            }
+            let response = self.redact_response(response.buffer()?);
+            match self
+                .retry
+                .and_then(|policy| policy.delay(attempt, &response, true))
+            {
+                Some(delay) => {
+                    attempt += 1;
+                    warn!(
+                        "Response status {:?}, retry {} in {:?}.",
+                        response.status, attempt, delay
+                    );
+                    thread::sleep(delay);
+                }
+                None => return Err(status_error(response.status, &response.body)),
+            }
+        }
+    }
+
+    fn fetch(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<&Value>,
+        idempotency_key: Option<&str>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        let mut headers = self.headers(body.is_some())?;
+        if let Some(key) = idempotency_key {
+            headers.insert(IDEMPOTENCY_KEY, HeaderValue::from_str(key)?);
+        }
+        let replayable = replayable(&method, &headers);
+        let body = body.map(|body| body.to_string().into_bytes());
+        let mut attempt = 0;
+
+        loop {
+            self.log_request(&method, url, body.as_deref());
+            let sent = Instant::now();
+            let response = match self.client.execute_with_timeout(
+                method.clone(),
+                url,
+                headers.clone(),
+                body.clone(),
+                timeout,
+            ) {
+                Ok(response) => self.redact_response(response),
+                Err(error) => {
+                    thread::sleep(self.transport_retry(&mut attempt, error, replayable)?);
+                    continue;
+                }
+            };
+
+            self.log_response(&method, url, &response, sent.elapsed());
+            match self
+                .retry
+                .and_then(|policy| policy.delay(attempt, &response, replayable))
+            {
+                Some(delay) => {
+                    attempt += 1;
+                    warn!(
+                        "Response status {:?}, retry {} in {:?}.",
+                        response.status, attempt, delay
+                    );
+                    thread::sleep(delay);
+                }
+                None => return Ok(response),
+            }
+        }
+    }
+}
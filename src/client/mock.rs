@@ -0,0 +1,206 @@
+#[cfg(feature = "async")]
+use super::AsyncHttpClient;
+use super::{HttpClient, RawResponse};
+use failure::{err_msg, Error};
+use getset::Getters;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+const MOCK_POISONED_ERROR: &str = "A thread panicked while holding the mock client.";
+
+type Responses = HashMap<(Method, String), VecDeque<MockResponse>>;
+
+/// An [`HttpClient`](trait.HttpClient.html) which never touches the network,
+/// for testing code which executes requests.
+///
+/// Responses are registered against a method and the path of the URL (such
+/// as `/v2/droplets/1`, the query is ignored). When several responses are
+/// registered against the same method and path they are returned in order,
+/// the last of them being repeated. Requests without a registered response
+/// fail with an error.
+///
+/// Clones share their responses and recorded requests, so a clone may be
+/// given to the `DigitalOcean` client while the original is kept to inspect
+/// the requests which were made.
+///
+/// ```rust
+/// # extern crate digitalocean;
+/// # #[macro_use] extern crate serde_json;
+/// # use digitalocean::client::{Method, MockClient, StatusCode};
+/// # use digitalocean::error::ErrorKind;
+/// # use digitalocean::prelude::*;
+/// let mock = MockClient::new()
+///     .respond(Method::DELETE, "/v2/droplets/1", StatusCode::NO_CONTENT, json!(null))
+///     .respond(Method::GET, "/v2/droplets/2", StatusCode::NOT_FOUND, json!({
+///         "id": "not_found",
+///         "message": "The resource you were accessing could not be found."
+///     }));
+/// let client = DigitalOcean::with_client("API_KEY", mock.clone()).unwrap();
+///
+/// Droplet::delete(1).execute(&client).unwrap();
+/// let error = Droplet::get(2).execute(&client).unwrap_err();
+/// match error.downcast_ref::<ErrorKind>() {
+///     Some(ErrorKind::NotFound) => (),
+///     _ => panic!("Unexpected error: {}", error),
+/// }
+///
+/// let requests = mock.requests();
+/// assert_eq!(requests.len(), 2);
+/// assert_eq!(*requests[0].method(), Method::DELETE);
+/// assert_eq!(requests[0].url().path(), "/v2/droplets/1");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    responses: Arc<Mutex<Responses>>,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+#[derive(Debug, Clone)]
+enum MockResponse {
+    Response(RawResponse),
+    Error(String),
+}
+
+/// A request which was sent to a [`MockClient`](struct.MockClient.html).
+#[derive(Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct MockRequest {
+    method: Method,
+    url: Url,
+    headers: HeaderMap,
+    /// The JSON body of the request, if it had one.
+    body: Option<Value>,
+    /// The timeout the request was sent with, if it overrode the one of the
+    /// client.
+    timeout: Option<Duration>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        MockClient::default()
+    }
+
+    /// Respond to `method` requests on `path` with `status` and the JSON `body`.
+    /// A `null` body is sent as an empty body.
+    pub fn respond<P>(self, method: Method, path: P, status: StatusCode, body: Value) -> Self
+    where
+        P: Into<String>,
+    {
+        let body = match body {
+            Value::Null => Vec::new(),
+            body => body.to_string().into_bytes(),
+        };
+        self.respond_with(
+            method,
+            path,
+            RawResponse::new(status, HeaderMap::new(), body),
+        )
+    }
+
+    /// Respond to `method` requests on `path` with `response`, for when the
+    /// headers of the response matter.
+    pub fn respond_with<P>(self, method: Method, path: P, response: RawResponse) -> Self
+    where
+        P: Into<String>,
+    {
+        self.register(method, path.into(), MockResponse::Response(response))
+    }
+
+    /// Fail `method` requests on `path` as if no response was received, with
+    /// an error carrying `message`.
+    pub fn fail<P, M>(self, method: Method, path: P, message: M) -> Self
+    where
+        P: Into<String>,
+        M: Into<String>,
+    {
+        self.register(method, path.into(), MockResponse::Error(message.into()))
+    }
+
+    /// Every request which has been sent, in the order they were sent.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().expect(MOCK_POISONED_ERROR).clone()
+    }
+
+    fn register(self, method: Method, path: String, response: MockResponse) -> Self {
+        self.responses
+            .lock()
+            .expect(MOCK_POISONED_ERROR)
+            .entry((method, path))
+            .or_default()
+            .push_back(response);
+        self
+    }
+}
+
+impl HttpClient for MockClient {
+    fn execute(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+    ) -> Result<RawResponse, Error> {
+        self.execute_with_timeout(method, url, headers, body, None)
+    }
+
+    fn execute_with_timeout(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        let body = match body {
+            Some(body) => Some(serde_json::from_slice(&body)?),
+            None => None,
+        };
+        self.requests
+            .lock()
+            .expect(MOCK_POISONED_ERROR)
+            .push(MockRequest {
+                method: method.clone(),
+                url: url.clone(),
+                headers,
+                body,
+                timeout,
+            });
+
+        let mut responses = self.responses.lock().expect(MOCK_POISONED_ERROR);
+        let response = match responses.get_mut(&(method.clone(), url.path().to_string())) {
+            Some(queue) if queue.len() > 1 => queue.pop_front(),
+            Some(queue) => queue.front().cloned(),
+            None => None,
+        };
+
+        match response {
+            Some(MockResponse::Response(response)) => Ok(response),
+            Some(MockResponse::Error(message)) => Err(err_msg(message)),
+            None => Err(err_msg(format!(
+                "No mock response for {} {}",
+                method,
+                url.path()
+            ))),
+        }
+    }
+}
+
+// Responses are canned, so there is nothing to wait on.
+#[cfg(feature = "async")]
+impl AsyncHttpClient for MockClient {
+    async fn execute_async(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        self.execute_with_timeout(method, url, headers, body, timeout)
+    }
+}
@@ -1,3 +1,705 @@
-/// Later we can make a different client and implement it as a feature.
+//! The HTTP clients requests are executed with.
+//!
+//! Requests are executed on a [`ReqwestClient`](struct.ReqwestClient.html) by
+//! default. Any other client (a different HTTP library, a caching layer, a test
+//! double, etc.) may be used instead by implementing
+//! [`HttpClient`](trait.HttpClient.html) and passing it to
+//! [`DigitalOcean::with_client()`](../struct.DigitalOcean.html#method.with_client).
+//!
+//! With the `async` feature, requests may also be executed without blocking
+//! on any client which implements [`AsyncHttpClient`](trait.AsyncHttpClient.html),
+//! as both of the clients of this crate do.
+
+#[cfg(feature = "async")]
+mod asynchronous;
+mod blocking;
+mod mock;
 mod reqwest;
-pub use self::reqwest::Client;
+pub use self::mock::{MockClient, MockRequest};
+pub use self::reqwest::ReqwestClient;
+
+pub use ::reqwest::header::HeaderMap;
+pub use ::reqwest::{Method, Proxy, StatusCode};
+
+use crate::api::{HasPagination, HasResponse, HasValue, MAX_PER_PAGE};
+use crate::error::ErrorKind;
+use crate::method::List;
+use crate::request::Request;
+use crate::response::{Links, Meta, PagedResponse, RateLimit, Response};
+use crate::{DigitalOcean, ROOT_URL};
+use ::reqwest::header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
+use chrono::{TimeZone, Utc};
+use failure::Error;
+use getset::Getters;
+use log::{debug, trace, warn};
+use std::fmt;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::io::{self, Cursor, Read};
+use std::time::Duration;
+use url::Url;
+
+/// Replaces the API key (and other secrets) in logged text, errors, and
+/// `Debug` output.
+pub(crate) const REDACTED: &str = "***";
+
+/// The header the idempotency key of a create request is sent in.
+pub(crate) const IDEMPOTENCY_KEY: &str = "idempotency-key";
+
+/// Sends HTTP requests on behalf of a [`DigitalOcean`](../struct.DigitalOcean.html) client.
+///
+/// ```rust,no_run
+/// # extern crate digitalocean;
+/// # extern crate failure;
+/// # extern crate url;
+/// # use digitalocean::client::{HeaderMap, HttpClient, Method, RawResponse, StatusCode};
+/// # use digitalocean::prelude::*;
+/// # use failure::Error;
+/// # use url::Url;
+/// struct NotFound;
+///
+/// impl HttpClient for NotFound {
+///     fn execute(
+///         &self,
+///         _method: Method,
+///         _url: &Url,
+///         _headers: HeaderMap,
+///         _body: Option<Vec<u8>>,
+///     ) -> Result<RawResponse, Error> {
+///         Ok(RawResponse::new(StatusCode::NOT_FOUND, HeaderMap::new(), Vec::new()))
+///     }
+/// }
+///
+/// let client = DigitalOcean::with_client("API_KEY", NotFound).unwrap();
+/// assert!(Droplet::get(1).execute(&client).is_err());
+/// ```
+pub trait HttpClient {
+    /// Send a single request, returning the response whatever its status.
+    ///
+    /// `headers` carries the authorization and user agent (and, when there is
+    /// a `body`, the content type) of the request. Errors should only be returned when no
+    /// response was received.
+    fn execute(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+    ) -> Result<RawResponse, Error>;
+
+    /// Send a single request like [`execute`](#tymethod.execute), abandoning
+    /// it once `timeout` has passed, if one is given, instead of after the
+    /// timeout of the client.
+    ///
+    /// By default `timeout` is ignored and the request is sent with `execute`.
+    fn execute_with_timeout(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        let _ = timeout;
+        self.execute(method, url, headers, body)
+    }
+
+    /// Send a single request like
+    /// [`execute_with_timeout`](#method.execute_with_timeout), returning the
+    /// response before its body has been read, so that large bodies (such as
+    /// invoice documents) need not be held in memory.
+    ///
+    /// By default the response of `execute_with_timeout` is returned, with its
+    /// body already buffered.
+    fn execute_stream(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<StreamResponse, Error> {
+        let response = self.execute_with_timeout(method, url, headers, body, timeout)?;
+        Ok(StreamResponse::new(
+            response.status,
+            response.headers,
+            Cursor::new(response.body),
+        ))
+    }
+}
+
+impl<C> HttpClient for &C
+where
+    C: HttpClient + ?Sized,
+{
+    fn execute(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+    ) -> Result<RawResponse, Error> {
+        (**self).execute(method, url, headers, body)
+    }
+
+    fn execute_with_timeout(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        (**self).execute_with_timeout(method, url, headers, body, timeout)
+    }
+
+    fn execute_stream(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<StreamResponse, Error> {
+        (**self).execute_stream(method, url, headers, body, timeout)
+    }
+}
+
+/// Sends HTTP requests on behalf of a [`DigitalOcean`](../struct.DigitalOcean.html)
+/// client without blocking the calling thread, for
+/// [`ExecutableAsync`](../request/trait.ExecutableAsync.html).
+///
+/// This is only available with the `async` feature.
+#[cfg(feature = "async")]
+pub trait AsyncHttpClient {
+    /// Send a single request like
+    /// [`HttpClient::execute_with_timeout`](trait.HttpClient.html#method.execute_with_timeout),
+    /// returning the response whatever its status.
+    fn execute_async(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> impl Future<Output = Result<RawResponse, Error>> + Send;
+}
+
+#[cfg(feature = "async")]
+impl<C> AsyncHttpClient for &C
+where
+    C: AsyncHttpClient + Sync + ?Sized,
+{
+    fn execute_async(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> impl Future<Output = Result<RawResponse, Error>> + Send {
+        (**self).execute_async(method, url, headers, body, timeout)
+    }
+}
+
+/// Surfaces timeouts from the HTTP client as `ErrorKind::Timeout`.
+///
+/// Credentials in the URL of other errors are redacted.
+pub(crate) fn transport_error(mut error: ::reqwest::Error) -> Error {
+    if error.is_timeout() {
+        return ErrorKind::Timeout.into();
+    }
+    if let Some(url) = error.url_mut() {
+        if url.password().is_some() {
+            url.set_password(Some(REDACTED)).ok();
+        }
+    }
+    error.into()
+}
+
+/// The parts of an HTTP response which are interpreted by this crate.
+#[derive(Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct RawResponse {
+    pub(crate) status: StatusCode,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: Vec<u8>,
+}
+
+impl RawResponse {
+    pub fn new(status: StatusCode, headers: HeaderMap, body: Vec<u8>) -> Self {
+        RawResponse {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    /// The rate limit reported in the headers, if they were all present and valid.
+    pub(crate) fn rate_limit(&self) -> Option<RateLimit> {
+        let header = |name: &str| self.headers.get(name)?.to_str().ok()?.parse::<u64>().ok();
+
+        let limit = header("ratelimit-limit")?;
+        let remaining = header("ratelimit-remaining")?;
+        let reset = Utc
+            .timestamp_opt(header("ratelimit-reset")? as i64, 0)
+            .single()?;
+
+        Some(RateLimit::new(limit as usize, remaining as usize, reset))
+    }
+}
+
+/// An HTTP response whose body is read as it arrives, rather than buffered.
+///
+/// The body is read through the `Read` implementation.
+#[derive(Getters)]
+pub struct StreamResponse {
+    #[get = "pub"]
+    status: StatusCode,
+    #[get = "pub"]
+    headers: HeaderMap,
+    body: Box<dyn Read + Send>,
+}
+
+impl StreamResponse {
+    pub fn new<R>(status: StatusCode, headers: HeaderMap, body: R) -> Self
+    where
+        R: Read + Send + 'static,
+    {
+        StreamResponse {
+            status,
+            headers,
+            body: Box::new(body),
+        }
+    }
+
+    /// Reads the rest of the body into a `RawResponse`, such as when the
+    /// response is an error.
+    pub(crate) fn buffer(mut self) -> Result<RawResponse, Error> {
+        let mut body = Vec::new();
+        self.body.read_to_end(&mut body)?;
+        Ok(RawResponse::new(self.status, self.headers, body))
+    }
+}
+
+impl Read for StreamResponse {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.body.read(buf)
+    }
+}
+
+impl fmt::Debug for StreamResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
+
+/// The headers sent with every request authorized by `token`, shared by the
+/// client and [`DryRun`](../request/struct.DryRun.html).
+pub(crate) fn request_headers(
+    token: &str,
+    user_agent: &str,
+    json: bool,
+) -> Result<HeaderMap, Error> {
+    let mut headers = HeaderMap::new();
+    let mut authorization = HeaderValue::from_str(&format!("Bearer {}", token))?;
+    authorization.set_sensitive(true);
+    headers.insert(AUTHORIZATION, authorization);
+    headers.insert(USER_AGENT, HeaderValue::from_str(user_agent)?);
+    if json {
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    }
+    Ok(headers)
+}
+
+impl<C> DigitalOcean<C> {
+    /// The headers sent with every request.
+    pub(crate) fn headers(&self, json: bool) -> Result<HeaderMap, Error> {
+        request_headers(&self.token, &self.user_agent, json)
+    }
+
+    /// Replaces the API key wherever it appears in `text`, so that the text
+    /// may be logged.
+    pub(crate) fn redact(&self, text: &str) -> String {
+        if self.token.is_empty() {
+            return text.to_string();
+        }
+        text.replace(&self.token, REDACTED)
+    }
+
+    /// The URL without any credentials (or the API key) in it, so that it may
+    /// be logged.
+    pub(crate) fn redact_url(&self, url: &Url) -> String {
+        let mut url = url.clone();
+        if url.password().is_some() {
+            url.set_password(Some(REDACTED)).ok();
+        }
+        self.redact(url.as_str())
+    }
+
+    /// Replaces the API key wherever it appears in the body of `response`,
+    /// which may end up in an error (such as when the API echoes it back).
+    pub(crate) fn redact_response(&self, mut response: RawResponse) -> RawResponse {
+        let token = self.token.as_bytes();
+        if token.is_empty() || !response.body.windows(token.len()).any(|w| w == token) {
+            return response;
+        }
+        let body = String::from_utf8_lossy(&response.body).replace(&self.token, REDACTED);
+        response.body = body.into_bytes();
+        response
+    }
+
+    /// The wait before retrying a request which failed with `error` before a
+    /// response was received, counting the retry in `attempt`. The error is
+    /// returned instead if it should not be retried.
+    pub(crate) fn transport_retry(
+        &self,
+        attempt: &mut usize,
+        error: Error,
+        replayable: bool,
+    ) -> Result<Duration, Error> {
+        match self
+            .retry
+            .and_then(|policy| policy.transport_delay(*attempt, &error, replayable))
+        {
+            Some(delay) => {
+                *attempt += 1;
+                warn!(
+                    "Request failed ({}), retry {} in {:?}.",
+                    self.redact(&error.to_string()),
+                    attempt,
+                    delay
+                );
+                Ok(delay)
+            }
+            None => Err(error),
+        }
+    }
+
+    /// Logs a request which is about to be sent.
+    pub(crate) fn log_request(&self, method: &Method, url: &Url, body: Option<&[u8]>) {
+        debug!("{} {}", method, self.redact_url(url));
+        if let Some(body) = body {
+            trace!(
+                "Request body: {}",
+                self.redact(&String::from_utf8_lossy(body))
+            );
+        }
+    }
+
+    /// Logs the response to a request, `elapsed` after it was sent.
+    pub(crate) fn log_response(
+        &self,
+        method: &Method,
+        url: &Url,
+        response: &RawResponse,
+        elapsed: Duration,
+    ) {
+        self.log_status(method, url, response.status, elapsed);
+        trace!(
+            "Response body: {}",
+            self.redact(&String::from_utf8_lossy(&response.body))
+        );
+    }
+
+    /// Logs the status of the response to a request, `elapsed` after it was
+    /// sent.
+    pub(crate) fn log_status(
+        &self,
+        method: &Method,
+        url: &Url,
+        status: StatusCode,
+        elapsed: Duration,
+    ) {
+        debug!(
+            "{} {} -> {} in {:?}",
+            method,
+            self.redact_url(url),
+            status,
+            elapsed
+        );
+    }
+
+    /// Moves a URL built against `ROOT_URL` (or returned by the API) onto the
+    /// base URL of this client.
+    pub(crate) fn rebase(&self, url: &Url) -> Url {
+        if self.base_url == *ROOT_URL {
+            return url.clone();
+        }
+        match url.as_str().strip_prefix(ROOT_URL.as_str()) {
+            Some(rest) => {
+                let base = self.base_url.as_str().trim_end_matches('/');
+                Url::parse(&format!("{}{}", base, rest)).unwrap_or_else(|_| url.clone())
+            }
+            None => url.clone(),
+        }
+    }
+}
+
+// The functions below are shared between the blocking and the async clients
+// so that both interpret the API identically.
+
+/// Whether a request may be sent again even if the server acted on it. Only
+/// create requests are not idempotent, unless they carry an idempotency key.
+pub(crate) fn replayable(method: &Method, headers: &HeaderMap) -> bool {
+    *method != Method::POST || headers.contains_key(IDEMPOTENCY_KEY)
+}
+
+/// The number of values each page of a list request asks for: the
+/// `per_page` query parameter already present on the request (or the most
+/// the API allows), but no more than the limit of the request.
+fn page_size<V>(request: &Request<List, V>) -> usize {
+    let per_page = request
+        .url()
+        .query_pairs()
+        .find(|(key, _)| key == "per_page")
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(MAX_PER_PAGE);
+
+    match request.method().0 {
+        Some(limit) => per_page.min(limit).max(1),
+        None => per_page,
+    }
+}
+
+/// Sets the `per_page` query parameter of `url`, replacing any it had.
+pub(crate) fn set_per_page(url: &mut Url, per_page: usize) {
+    let value = per_page.to_string();
+    if url
+        .query_pairs()
+        .any(|(key, current)| key == "per_page" && current == value.as_str())
+    {
+        return;
+    }
+
+    let query: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "per_page")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(query)
+        .append_pair("per_page", &value);
+}
+
+/// The URL of the first page of a list request.
+pub(crate) fn list_url<V>(request: &Request<List, V>) -> Url {
+    let mut url = request.url().clone();
+    set_per_page(&mut url, page_size(request));
+    url
+}
+
+/// The URL of the next page of a list request, if another page should be
+/// fetched after `fetched` values have been buffered.
+///
+/// The link to the next page already asks for as many values as the first
+/// page did. Pages are numbered, so asking for fewer would shift which values
+/// the page holds; the values beyond the limit are dropped instead.
+pub(crate) fn next_list_url<V>(
+    request: &Request<List, V>,
+    next_page: Option<Url>,
+    fetched: usize,
+) -> Option<Url> {
+    match request.method().0 {
+        Some(limit) if fetched >= limit => None,
+        _ => next_page,
+    }
+}
+
+/// Where a list request is among its pages, so that the blocking and the
+/// async clients follow them identically.
+pub(crate) struct PageCursor {
+    next: Option<Url>,
+    fetched: usize,
+    rate_limit: Option<RateLimit>,
+}
+
+impl PageCursor {
+    pub(crate) fn new<V>(request: &Request<List, V>) -> Self {
+        PageCursor {
+            next: Some(list_url(request)),
+            fetched: 0,
+            rate_limit: None,
+        }
+    }
+
+    /// The URL of the next page to fetch, if there is one.
+    pub(crate) fn next_url(&mut self) -> Option<Url> {
+        self.next.take()
+    }
+
+    /// Takes the values of the page which was just fetched, noting the page
+    /// to fetch after it.
+    pub(crate) fn advance<V>(
+        &mut self,
+        request: &Request<List, Vec<V>>,
+        page: Response<Vec<V>>,
+        next_page: Option<Url>,
+    ) -> Vec<V> {
+        self.rate_limit = *page.rate_limit();
+        let mut values = page.into_value();
+        // The last page may hold more values than remain of the limit.
+        if let Some(limit) = request.method().0 {
+            values.truncate(limit.saturating_sub(self.fetched));
+        }
+        self.fetched += values.len();
+        self.next = next_list_url(request, next_page, self.fetched);
+        values
+    }
+
+    /// The number of values fetched so far.
+    pub(crate) fn fetched(&self) -> usize {
+        self.fetched
+    }
+
+    /// The rate limit reported alongside the most recently fetched page.
+    pub(crate) fn rate_limit(&self) -> &Option<RateLimit> {
+        &self.rate_limit
+    }
+}
+
+/// Deserializes one page of a list response, returning its values and the
+/// next page (if any).
+pub(crate) fn deserialize_page<V>(
+    response: &RawResponse,
+) -> Result<(Response<Vec<V>>, Option<Url>), Error>
+where
+    Vec<V>: HasResponse,
+    <Vec<V> as HasResponse>::Response: HasPagination,
+{
+    let deserialized: <Vec<V> as HasResponse>::Response = serde_json::from_slice(&response.body)
+        .map_err(|e| ErrorKind::deserialize(&response.body, e))?;
+    let next_page = deserialized.next_page();
+    Ok((
+        Response::new(deserialized.value(), response.rate_limit()),
+        next_page,
+    ))
+}
+
+/// The `links` and `meta` shared by every list response.
+#[derive(Deserialize, Default)]
+struct PageInfo {
+    #[serde(default)]
+    links: PageLinks,
+    #[serde(default)]
+    meta: Option<Meta>,
+}
+
+#[derive(Deserialize, Default)]
+struct PageLinks {
+    #[serde(default)]
+    pages: Option<Links>,
+}
+
+/// Deserializes one page of a list response, keeping its `links` and `meta`.
+pub(crate) fn deserialize_paged<V>(response: &RawResponse) -> Result<PagedResponse<V>, Error>
+where
+    Vec<V>: HasResponse,
+    <Vec<V> as HasResponse>::Response: HasPagination,
+{
+    let (page, _) = deserialize_page(response)?;
+    let info: PageInfo = serde_json::from_slice(&response.body)
+        .map_err(|e| ErrorKind::deserialize(&response.body, e))?;
+
+    Ok(PagedResponse::new(
+        page.into_value(),
+        info.links.pages.unwrap_or_default(),
+        info.meta.unwrap_or_default(),
+        response.rate_limit(),
+    ))
+}
+
+/// Deserializes a single value response.
+pub(crate) fn deserialize<V>(response: &RawResponse) -> Result<Response<V>, Error>
+where
+    V: HasResponse,
+{
+    let deserialized = V::parse(&response.body)?;
+    Ok(Response::new(deserialized.value(), response.rate_limit()))
+}
+
+pub(crate) fn check_get(status: StatusCode, body: &[u8]) -> Result<(), Error> {
+    match status {
+        // Successes
+        StatusCode::OK => Ok(()),
+        // Errors
+        e => Err(status_error(e, body)),
+    }
+}
+
+pub(crate) fn check_delete(status: StatusCode, body: &[u8]) -> Result<(), Error> {
+    match status {
+        // Successes
+        StatusCode::NO_CONTENT => Ok(()), // Delete success
+        StatusCode::OK => Ok(()),         // Delete success (with a body)
+        StatusCode::ACCEPTED => Ok(()),   // Delete success (async)
+        // Errors
+        e => Err(status_error(e, body)),
+    }
+}
+
+pub(crate) fn check_post(status: StatusCode, body: &[u8]) -> Result<(), Error> {
+    match status {
+        // Successes
+        StatusCode::OK => Ok(()),       // Post Success (existing resource)
+        StatusCode::CREATED => Ok(()),  // Post Success
+        StatusCode::ACCEPTED => Ok(()), // Post Success (async)
+        StatusCode::NO_CONTENT => Ok(()), // Post Success (no content)
+        // Errors
+        e => Err(status_error(e, body)),
+    }
+}
+
+pub(crate) fn check_put(status: StatusCode, body: &[u8]) -> Result<(), Error> {
+    match status {
+        // Successes
+        StatusCode::OK => Ok(()),         // Update success
+        StatusCode::NO_CONTENT => Ok(()), // Update success, no body
+        // Errors
+        e => Err(status_error(e, body)),
+    }
+}
+
+/// The body of an error response from the API.
+#[derive(Deserialize)]
+struct ApiError {
+    id: String,
+    message: String,
+}
+
+/// Interprets a response whose status was not the success expected.
+///
+/// A `404` becomes `ErrorKind::NotFound` regardless of the method, so that
+/// (for example) deleting something which is already gone can be told apart
+/// from other failures. Other error statuses become `ErrorKind::Api`, with
+/// the `id` and `message` of the error envelope in the body. If the body is
+/// not an error envelope the `id` is left empty and the body is used as the
+/// `message`.
+fn status_error(status: StatusCode, body: &[u8]) -> Error {
+    if status == StatusCode::NOT_FOUND {
+        return ErrorKind::NotFound.into();
+    }
+    if status == StatusCode::UNAUTHORIZED {
+        return ErrorKind::Unauthorized.into();
+    }
+    if !(status.is_client_error() || status.is_server_error()) {
+        return ErrorKind::UnexpectedStatus(status).into();
+    }
+
+    let (id, message) = match serde_json::from_slice::<ApiError>(body) {
+        Ok(error) => (error.id, error.message),
+        Err(_) => (String::new(), String::from_utf8_lossy(body).into_owned()),
+    };
+
+    ErrorKind::Api {
+        status: status.as_u16(),
+        id,
+        message,
+    }
+    .into()
+}
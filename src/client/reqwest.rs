@@ -1,174 +1,193 @@
-pub use reqwest::Client;
-
-use crate::api::{HasPagination, HasResponse, HasValue, MAX_PER_PAGE};
-use crate::error::ErrorKind;
-use crate::method::{Create, Delete, Get, List, Update};
-use crate::request::Request;
-use crate::DigitalOcean;
+#[cfg(feature = "async")]
+use super::AsyncHttpClient;
+use super::{transport_error, HttpClient, RawResponse, StreamResponse};
 use failure::Error;
-use log::info;
-use reqwest::StatusCode;
-use reqwest::{RequestBuilder, Response};
-
-impl DigitalOcean {
-    pub(crate) fn get<V>(&self, request: Request<Get, V>) -> Result<V, Error>
-    where
-        V: HasResponse,
-    {
-        info!("GET {:?}", request.url());
-        let req = self.client.get(request.url().clone());
-
-        let mut response = self.fetch(req)?;
-
-        match response.status() {
-            // Successes
-            StatusCode::OK => (),
-            // Not Found
-            StatusCode::NOT_FOUND => Err(ErrorKind::NotFound)?,
-            // Errors
-            e => Err(ErrorKind::UnexpectedStatus(e))?,
-        };
-
-        let deserialized: V::Response = response.json()?;
-        Ok(deserialized.value())
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Proxy};
+use std::sync::OnceLock;
+use std::time::Duration;
+use url::Url;
+
+use reqwest::blocking::Client;
+#[cfg(feature = "async")]
+use reqwest::Client as AsyncClient;
+
+const CLIENT_BUILD_ERROR: &str = "Unable to initialize the HTTP client.";
+
+/// The default [`HttpClient`](trait.HttpClient.html), backed by `reqwest`.
+///
+/// With the `async` feature requests may also be executed on it
+/// [asynchronously](../request/trait.ExecutableAsync.html).
+#[derive(Debug, Clone)]
+pub struct ReqwestClient {
+    // The blocking client may not be constructed (or dropped) inside of an
+    // async runtime, so it is only created once it is first used.
+    blocking: OnceLock<Client>,
+    #[cfg(feature = "async")]
+    asynchronous: AsyncClient,
+    timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+}
+
+impl ReqwestClient {
+    pub fn new() -> Self {
+        ReqwestClient::configured(None, None)
     }
 
-    pub(crate) fn list<V>(&self, request: Request<List, Vec<V>>) -> Result<Vec<V>, Error>
-    where
-        Vec<V>: HasResponse,
-        <Vec<V> as HasResponse>::Response: HasPagination,
-    {
-        info!("LIST {:?}", request.url());
-        // This may be a paginated response. We need to buffer.
-        let mut buffer = Vec::new();
-        let mut current_url = request.url().clone();
-
-        match request.method().0 {
-            Some(limit) if limit < MAX_PER_PAGE => {
-                current_url
-                    .query_pairs_mut()
-                    .append_pair("per_page", &limit.to_string());
-            }
-            _ => {
-                current_url
-                    .query_pairs_mut()
-                    .append_pair("per_page", &MAX_PER_PAGE.to_string());
-            }
-        };
-
-        loop {
-            let req = self.client.get(current_url.clone());
-            let mut response = self.fetch(req)?;
-
-            match response.status() {
-                StatusCode::OK => (),
-                // Not Found
-                StatusCode::NOT_FOUND => Err(ErrorKind::NotFound)?,
-                // Errors
-                e => Err(ErrorKind::UnexpectedStatus(e))?,
-            };
-
-            let deserialized: <Vec<V> as HasResponse>::Response = response.json()?;
-
-            let next_page = deserialized.next_page();
-            buffer.extend(deserialized.value());
-
-            current_url = match next_page {
-                Some(v) => v,
-                None => break,
-            };
-
-            if let Some(limit) = request.method().0 {
-                let buffer_size = buffer.len();
-                let remaining = limit - buffer_size;
-                if buffer_size >= limit {
-                    break;
-                } else if remaining < MAX_PER_PAGE {
-                    current_url
-                        .query_pairs_mut()
-                        .append_pair("per_page", &remaining.to_string());
-                }
-            }
-            info!("Fetching next page...")
-        }
+    /// Abandon requests which take longer than `timeout` to connect, or to
+    /// complete once connected, with `ErrorKind::Timeout`.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        ReqwestClient::configured(Some(timeout), self.proxy)
+    }
 
-        Ok(buffer)
+    /// Send every request through `proxy`.
+    ///
+    /// By default the system proxies, such as the ones set by the
+    /// `HTTPS_PROXY` and `NO_PROXY` environment variables, are used.
+    pub fn with_proxy(self, proxy: Proxy) -> Self {
+        ReqwestClient::configured(self.timeout, Some(proxy))
     }
 
-    // Delete requests do not return content.
-    pub(crate) fn delete<V>(&self, request: Request<Delete, V>) -> Result<(), Error> {
-        info!("DELETE {:?}", request.url());
-        let req = self.client.delete(request.url().clone());
+    fn configured(timeout: Option<Duration>, proxy: Option<Proxy>) -> Self {
+        ReqwestClient {
+            blocking: OnceLock::new(),
+            #[cfg(feature = "async")]
+            asynchronous: async_client(timeout, proxy.clone()),
+            timeout,
+            proxy,
+        }
+    }
 
-        let response = self.fetch(req)?;
+    fn blocking(&self) -> &Client {
+        // `Client::new()` panics on failure as well.
+        self.blocking.get_or_init(|| {
+            let mut builder = Client::builder();
+            if let Some(timeout) = self.timeout {
+                builder = builder.connect_timeout(timeout).timeout(timeout);
+            }
+            if let Some(ref proxy) = self.proxy {
+                builder = builder.proxy(proxy.clone());
+            }
+            builder.build().expect(CLIENT_BUILD_ERROR)
+        })
+    }
+}
 
-        match response.status() {
-            // Successes
-            StatusCode::NO_CONTENT => (), // Delete success
-            // Errors
-            e => Err(ErrorKind::UnexpectedStatus(e))?,
-        };
+impl Default for ReqwestClient {
+    fn default() -> Self {
+        ReqwestClient::new()
+    }
+}
 
-        Ok(())
+impl HttpClient for ReqwestClient {
+    fn execute(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+    ) -> Result<RawResponse, Error> {
+        self.execute_with_timeout(method, url, headers, body, None)
     }
 
-    pub(crate) fn post<V>(&self, request: Request<Create, V>) -> Result<V, Error>
-    where
-        V: HasResponse,
-    {
-        info!("POST {:?}", request.url());
-        let req = self
-            .client
-            .post(request.url().clone())
-            .json(&request.body().clone());
-
-        let mut response = self.fetch(req)?;
-
-        match response.status() {
-            // Successes
-            StatusCode::CREATED => (),  // Post Success
-            StatusCode::ACCEPTED => (), // Post Success (async)
-            // Errors
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                Err(ErrorKind::UnprocessableEntity(response.json()?))?
-            }
-            e => Err(ErrorKind::UnexpectedStatus(e))?,
-        };
+    fn execute_with_timeout(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        let mut dispatch = self
+            .blocking()
+            .request(method, url.as_str())
+            .headers(headers);
+        if let Some(body) = body {
+            dispatch = dispatch.body(body);
+        }
+        if let Some(timeout) = timeout {
+            dispatch = dispatch.timeout(timeout);
+        }
+        let response = dispatch.send().map_err(transport_error)?;
 
-        let deserialized: V::Response = response.json()?;
-        Ok(deserialized.value())
+        Ok(RawResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().map_err(transport_error)?.to_vec(),
+        })
     }
 
-    pub(crate) fn put<V>(&self, request: Request<Update, V>) -> Result<V, Error>
-    where
-        V: HasResponse,
-    {
-        info!("PUT {:?}", request.url());
-        let req = self
-            .client
-            .put(request.url().clone())
-            .json(&request.body().clone());
-
-        let mut response = self.fetch(req)?;
-
-        match response.status() {
-            // Successes
-            StatusCode::OK => (), // Update success
-            // Errors
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                Err(ErrorKind::UnprocessableEntity(response.json()?))?
-            }
-            e => Err(ErrorKind::UnexpectedStatus(e))?,
-        };
+    fn execute_stream(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<StreamResponse, Error> {
+        let mut dispatch = self
+            .blocking()
+            .request(method, url.as_str())
+            .headers(headers);
+        if let Some(body) = body {
+            dispatch = dispatch.body(body);
+        }
+        if let Some(timeout) = timeout {
+            dispatch = dispatch.timeout(timeout);
+        }
+        let response = dispatch.send().map_err(transport_error)?;
 
-        let deserialized: V::Response = response.json()?;
-        Ok(deserialized.value())
+        Ok(StreamResponse::new(
+            response.status(),
+            response.headers().clone(),
+            response,
+        ))
     }
+}
 
-    fn fetch(&self, dispatch: RequestBuilder) -> Result<Response, Error> {
-        let response = dispatch.bearer_auth(self.token.clone()).send()?;
+#[cfg(feature = "async")]
+impl AsyncHttpClient for ReqwestClient {
+    async fn execute_async(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<RawResponse, Error> {
+        // The async client may use another version of `url`, so the URL is
+        // passed along as a string.
+        let mut dispatch = self
+            .asynchronous
+            .request(method, url.as_str())
+            .headers(headers);
+        if let Some(body) = body {
+            dispatch = dispatch.body(body);
+        }
+        if let Some(timeout) = timeout {
+            dispatch = dispatch.timeout(timeout);
+        }
+        let response = dispatch.send().await.map_err(transport_error)?;
 
-        info!("Response status: {:?}", response.status());
-        Ok(response)
+        Ok(RawResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().await.map_err(transport_error)?.to_vec(),
+        })
+    }
+}
+
+/// Builds the async client, with an optional connect and request timeout and
+/// proxy.
+#[cfg(feature = "async")]
+fn async_client(timeout: Option<Duration>, proxy: Option<Proxy>) -> AsyncClient {
+    let mut builder = AsyncClient::builder();
+    if let Some(timeout) = timeout {
+        builder = builder.connect_timeout(timeout).timeout(timeout);
+    }
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(proxy);
     }
+    // `AsyncClient::new()` panics on failure as well.
+    builder.build().expect(CLIENT_BUILD_ERROR)
 }
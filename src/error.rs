@@ -5,23 +5,122 @@
 //! While functions that return `Error` could return errors from other libraries, the errors in
 //! `ErrorKind` have special semantic meaning to this crate.
 
+// `failure_derive` generates its impls inside of an anonymous `const`, and
+// they (like those of `Debug`) match on the deprecated variants too.
+#![allow(non_local_definitions, deprecated)]
+
+use crate::client::REDACTED;
 use reqwest;
 use serde_json;
 
 /// Errors which have crate specific meanings.
 #[derive(Debug, Fail)]
 pub enum ErrorKind {
-    /// The item does not exist or otherwise cannot be found.
+    /// The item does not exist or otherwise cannot be found. This is returned
+    /// for a `404` from any request, including deletes of items which are
+    /// already gone.
     #[fail(display = "Not Found")]
     NotFound,
     /// The reqest's API key is invalid or not authorized to view this resource.
+    /// This is returned for a `401` from any request.
     #[fail(display = "Unauthorized")]
     Unauthorized,
     /// An unexpected status code was returned from the API. Please raise a ticket.
     #[fail(display = "Unexpected status code: {}", _0)]
     UnexpectedStatus(reqwest::StatusCode),
+    /// The API rejected the request, or failed to process it.
+    ///
+    /// `id` identifies the kind of error, such as `"unprocessable_entity"` when
+    /// the item exists (possibly on another account), the limit on this item
+    /// has been reached, or the request is otherwise unprocessable. When the
+    /// API did not describe the error the `id` is empty and the `message` is
+    /// the raw body of the response.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#introduction)
+    #[fail(display = "API error {} ({}): {}", status, id, message)]
+    Api {
+        status: u16,
+        id: String,
+        message: String,
+    },
     /// The item exists (possibly on another account), the limit on this item has been reached,
     /// or this request is otherwise unprocessable.
+    ///
+    /// This is no longer returned: a `422` is surfaced as `ErrorKind::Api` with the `id`
+    /// `"unprocessable_entity"` instead. It is kept so that matches on it still compile.
+    #[deprecated(
+        since = "0.0.5",
+        note = "a `422` is returned as `ErrorKind::Api` with the id \"unprocessable_entity\""
+    )]
     #[fail(display = "Unprocessable entity: {}", _0)]
     UnprocessableEntity(serde_json::Value),
+    /// The request did not complete within the timeout set on the client, or
+    /// waiting for something (such as an action) outlasted its
+    /// [`WaitPolicy`](../wait/struct.WaitPolicy.html).
+    #[fail(display = "Timed out")]
+    Timeout,
+    /// An action which was waited on finished with the status "errored".
+    #[fail(display = "Action {} failed", _0)]
+    ActionFailed(usize),
+    /// A load balancer which was waited on finished with the status
+    /// "errored".
+    #[fail(display = "Load balancer {} failed", _0)]
+    LoadBalancerFailed(String),
+    /// A [`DigitalOceanBuilder`](../struct.DigitalOceanBuilder.html) was
+    /// given an invalid option, such as an empty API key.
+    #[fail(display = "Invalid client configuration: {}", _0)]
+    InvalidConfig(String),
+    /// A slug (such as a [`RegionSlug`](../api/struct.RegionSlug.html)) was
+    /// parsed from a string which is not made up of lowercase letters,
+    /// digits, and dashes.
+    #[fail(display = "Invalid slug: {:?}", _0)]
+    InvalidSlug(String),
+    /// A droplet could not be created from the region, size, and image given
+    /// to [`DigitalOcean::validate_droplet_spec`](../struct.DigitalOcean.html#method.validate_droplet_spec),
+    /// such as when the size is not available in the region.
+    #[fail(display = "Invalid droplet spec: {}", _0)]
+    InvalidDropletSpec(String),
+    /// The body of a response could not be deserialized, such as when the API
+    /// returns a value this crate does not expect. Please raise a ticket.
+    ///
+    /// `body` is the raw body of the response, truncated to
+    /// `MAX_DESERIALIZE_BODY` bytes.
+    #[fail(display = "Failed to deserialize response: {}", source)]
+    Deserialize {
+        body: String,
+        #[cause]
+        source: serde_json::Error,
+    },
+}
+
+/// The most bytes of a response body kept in `ErrorKind::Deserialize`.
+pub const MAX_DESERIALIZE_BODY: usize = 4096;
+
+impl ErrorKind {
+    /// Wraps a failure to deserialize `body`, keeping (the start of) `body`.
+    pub(crate) fn deserialize(body: &[u8], source: serde_json::Error) -> Self {
+        let truncated = body.len() > MAX_DESERIALIZE_BODY;
+        let body = &body[..body.len().min(MAX_DESERIALIZE_BODY)];
+        let mut body = String::from_utf8_lossy(body).into_owned();
+        if truncated {
+            body.push_str("...");
+        }
+        ErrorKind::Deserialize { body, source }
+    }
+
+    /// Wraps a failure to deserialize a body which holds secrets (such as
+    /// credentials), keeping neither the body nor any value `source` quotes
+    /// from it.
+    pub(crate) fn deserialize_secret(source: serde_json::Error) -> Self {
+        let source = serde::de::Error::custom(format!(
+            "{:?} error at line {} column {}",
+            source.classify(),
+            source.line(),
+            source.column()
+        ));
+        ErrorKind::Deserialize {
+            body: REDACTED.to_string(),
+            source,
+        }
+    }
 }
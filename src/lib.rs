@@ -33,7 +33,7 @@ the request or transform it into some other request.
 ```rust,no_run
 extern crate digitalocean;
 use digitalocean::DigitalOcean;
-use digitalocean::api::Domain;
+use digitalocean::api::{DnsRecordType, Domain};
 
 fn main() {
     // Gets details of a specific domain.
@@ -45,7 +45,7 @@ fn main() {
     let req = Domain::get("foo.com").records();
 
     // Create a new record for a domain
-    let req = Domain::get("foo.com").records().create("CNAME", "test", "127.0.0.1");
+    let req = Domain::get("foo.com").records().create(DnsRecordType::Cname, "test", "example.com.");
 }
 ```
 
@@ -54,6 +54,31 @@ In order to realize any action, `.execute()` must be called with a `DigitalOcean
 
 In order to use the entire API, it is recommended to reference the various `Request` types.
 
+## Async Usage
+
+With the `async` feature enabled, every request may also be executed without
+blocking the calling thread via `.execute_async()` (or
+`do_client.execute_async(some_request)`). The returned `Future` must be driven
+by an async runtime such as `tokio`.
+
+```toml
+[dependencies]
+digitalocean = { version = "*", features = ["async"] }
+```
+
+```rust,no_run
+extern crate digitalocean;
+use digitalocean::prelude::*;
+
+# #[cfg(feature = "async")]
+async fn droplets(client: &DigitalOcean) -> Vec<Droplet> {
+    Droplet::list()
+        .execute_async(&client)
+        .await
+        .unwrap()
+}
+```
+
 ## Design
 
 The crate is founded on a few design considerations:
@@ -82,6 +107,11 @@ This crate is in a prototype state.
 Not all endpoints have been fully end-to-end tested on the production DigitalOcean API. It's very
 likely that some endpoints will have parsing errors due to unexpected values returned from the API.
 
+Statuses, states and types which are represented as enums (such as
+[`DropletStatus`](api/enum.DropletStatus.html) or
+[`ImageType`](api/enum.ImageType.html)) parse values this crate does not know
+about as their `Unknown` variant instead of failing.
+
 **If something does not work please file a bug!**
 
 Feedback, patches, and new features are encouraged.
@@ -99,19 +129,38 @@ extern crate serde_json;
 extern crate failure_derive;
 
 pub mod api;
-mod client;
+pub mod client;
 pub mod error;
 pub mod method;
 pub mod prelude;
 pub mod request;
+pub mod response;
+pub mod retry;
+pub mod wait;
 
 use failure::Error;
 
-use crate::api::HasResponse;
-use crate::method::Method;
+use crate::api::{Account, HasPagination, HasResponse, Image, Region, Size};
+#[cfg(feature = "async")]
+use crate::client::AsyncHttpClient;
+use crate::client::{HttpClient, ReqwestClient, REDACTED};
+use crate::error::ErrorKind;
+use crate::method::{List, Method};
+#[cfg(feature = "async")]
+use crate::request::ExecutableAsync;
 use crate::request::{Executable, Request};
+use crate::response::{PagedResponse, Response};
+use crate::retry::RetryPolicy;
+use reqwest::header::HeaderValue;
+use reqwest::{NoProxy, Proxy};
+use std::fmt;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
 use url::Url;
 
+pub(crate) const DEFAULT_USER_AGENT: &str = concat!("digitalocean-rs/", env!("CARGO_PKG_VERSION"));
+const BATCH_POISONED_ERROR: &str = "A request of the batch panicked.";
 const STATIC_URL_ERROR: &str = "Staticly constructed DigitalOcean URL is malformed.";
 lazy_static! {
     static ref ROOT_URL: Url =
@@ -119,22 +168,169 @@ lazy_static! {
 }
 
 /// A DigitalOcean Client that holds an API key.
+///
+/// The API key is never included in the `Debug` output of the client, in
+/// logs, or in errors.
+///
+/// Requests are sent with a [`ReqwestClient`](client/struct.ReqwestClient.html)
+/// unless another [`HttpClient`](client/trait.HttpClient.html) is given.
 #[derive(Clone)]
-pub struct DigitalOcean {
-    client: client::Client,
+pub struct DigitalOcean<C = ReqwestClient> {
+    client: C,
     token: String,
+    retry: Option<RetryPolicy>,
+    base_url: Url,
+    user_agent: String,
 }
 
 impl DigitalOcean {
     /// Create a DigitalOcean client with the given API key.
     pub fn new<T: Into<String>>(token: T) -> Result<Self, Error> {
+        DigitalOcean::with_client(token, ReqwestClient::new())
+    }
+
+    /// Create a DigitalOcean client with the given API key, failing with
+    /// `ErrorKind::Unauthorized` if the API rejects it.
+    ///
+    /// *Note:* Unlike [`new()`](#method.new) this makes a request, to fetch
+    /// the [`Account`](api/struct.Account.html).
+    pub fn new_validated<T: Into<String>>(token: T) -> Result<Self, Error> {
+        let client = DigitalOcean::new(token)?;
+        client.validate()?;
+        Ok(client)
+    }
+
+    /// Configure a DigitalOcean client with the given API key, for when more
+    /// than the API key needs to be set.
+    pub fn builder<T: Into<String>>(token: T) -> DigitalOceanBuilder {
+        DigitalOceanBuilder::new(token)
+    }
+
+    /// Abandon requests which take longer than `timeout` to connect, or to
+    /// complete once connected, with `ErrorKind::Timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client = self.client.with_timeout(timeout);
+        self
+    }
+}
+
+#[cfg(feature = "async")]
+impl<C> DigitalOcean<C>
+where
+    C: AsyncHttpClient + Sync,
+{
+    /// Execute the request without blocking the calling thread.
+    pub async fn execute_async<A, V>(&self, request: Request<A, V>) -> Result<V, Error>
+    where
+        A: Method,
+        Request<A, V>: ExecutableAsync<V>,
+        V: HasResponse,
+    {
+        request.execute_async(self).await
+    }
+}
+
+impl<C> DigitalOcean<C>
+where
+    C: HttpClient,
+{
+    /// Create a DigitalOcean client with the given API key, which sends
+    /// requests with the given HTTP client.
+    pub fn with_client<T: Into<String>>(token: T, client: C) -> Result<Self, Error> {
         info!("Created.");
         Ok(DigitalOcean {
-            client: client::Client::new(),
+            client,
             token: token.into(),
+            retry: None,
+            base_url: ROOT_URL.clone(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
         })
     }
 
+    /// Send requests to `base_url` instead of `https://api.digitalocean.com/v2`,
+    /// such as a mock server or a gateway in front of the API.
+    ///
+    /// ```rust,no_run
+    /// # extern crate digitalocean;
+    /// # extern crate url;
+    /// # use digitalocean::DigitalOcean;
+    /// # use url::Url;
+    /// let client = DigitalOcean::new("API_KEY")
+    ///     .unwrap()
+    ///     .with_base_url(Url::parse("http://localhost:8080/v2").unwrap());
+    /// ```
+    pub fn with_base_url(mut self, base_url: Url) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Retry requests which were rate limited or failed on the server
+    /// according to the given policy. By default requests are not retried.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Check that the API key of this client is accepted, by fetching the
+    /// [`Account`](api/struct.Account.html). Fails with
+    /// `ErrorKind::Unauthorized` if it is rejected.
+    pub fn validate(&self) -> Result<(), Error> {
+        Account::get().execute(self).map(|_| ())
+    }
+
+    /// Check that a droplet of the given size and image can be created in the
+    /// region, by cross-checking the regions, sizes, and the image. Fails with
+    /// `ErrorKind::InvalidDropletSpec` describing the first problem found,
+    /// such as the size not being available in the region.
+    ///
+    /// `image` is either an `id` (numeric) or a `slug` (string).
+    pub fn validate_droplet_spec<R, S, I>(&self, region: R, size: S, image: I) -> Result<(), Error>
+    where
+        R: AsRef<str>,
+        S: AsRef<str>,
+        I: fmt::Display,
+    {
+        let (region, size) = (region.as_ref(), size.as_ref());
+        let invalid = |message: String| Err(ErrorKind::InvalidDropletSpec(message).into());
+
+        let regions = Region::list().execute(self)?;
+        let found = match regions.iter().find(|found| found.slug() == region) {
+            Some(found) => found,
+            None => return invalid(format!("region {} does not exist", region)),
+        };
+        if !*found.available() {
+            return invalid(format!("region {} does not accept new droplets", region));
+        }
+        let sizes = Size::list().execute(self)?;
+        if !sizes.iter().any(|found| found.slug() == size) {
+            return invalid(format!("size {} does not exist", size));
+        }
+        if !found.supports_size(size) {
+            return invalid(format!(
+                "size {} is not available in region {}",
+                size, region
+            ));
+        }
+
+        let image_regions = match Image::get(&image).execute(self) {
+            Ok(found) => found.regions().clone(),
+            Err(error) => match error.downcast_ref::<ErrorKind>() {
+                Some(ErrorKind::NotFound) => {
+                    return invalid(format!("image {} does not exist", image));
+                }
+                _ => return Err(error),
+            },
+        };
+        if !image_regions.iter().any(|found| found == region) {
+            return invalid(format!(
+                "image {} is not available in region {}",
+                image, region
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn execute<A, V>(&self, request: Request<A, V>) -> Result<V, Error>
     where
         A: Method,
@@ -143,4 +339,270 @@ impl DigitalOcean {
     {
         request.execute(self)
     }
+
+    /// Fetch only the first page of a list request, keeping the `links` and
+    /// `meta` the API reported alongside it.
+    pub fn execute_paged<V>(
+        &self,
+        request: Request<List, Vec<V>>,
+    ) -> Result<PagedResponse<V>, Error>
+    where
+        Vec<V>: HasResponse,
+        <Vec<V> as HasResponse>::Response: HasPagination,
+    {
+        request.execute_paged(self)
+    }
+
+    /// Execute the requests concurrently, on up to `concurrency` threads at a
+    /// time, returning their results in the same order as the requests.
+    ///
+    /// Every thread shares this client (and its connection pool). Keep
+    /// `concurrency` low enough to respect the rate limit of the account, or
+    /// combine this with [`with_retry()`](#method.with_retry). A
+    /// `concurrency` of `0` is treated as `1`.
+    ///
+    /// ```rust,no_run
+    /// # extern crate digitalocean;
+    /// # use digitalocean::prelude::*;
+    /// let client = DigitalOcean::new("API_KEY").unwrap();
+    /// let requests = (1..=50).map(Droplet::get);
+    /// for droplet in client.execute_batch(requests, 8) {
+    ///     println!("{:?}", droplet.map(|droplet| droplet.name().clone()));
+    /// }
+    /// ```
+    pub fn execute_batch<A, V, I>(&self, requests: I, concurrency: usize) -> Vec<Result<V, Error>>
+    where
+        C: Sync,
+        A: Method,
+        I: IntoIterator<Item = Request<A, V>>,
+        Request<A, V>: Executable<V> + Send,
+        V: HasResponse + Send,
+    {
+        let requests: Vec<Request<A, V>> = requests.into_iter().collect();
+        let count = requests.len();
+        let queue = Mutex::new(requests.into_iter().enumerate());
+        let results = Mutex::new((0..count).map(|_| None).collect::<Vec<_>>());
+
+        thread::scope(|scope| {
+            for _ in 0..concurrency.clamp(1, count.max(1)) {
+                scope.spawn(|| loop {
+                    let next = queue.lock().expect(BATCH_POISONED_ERROR).next();
+                    let (index, request) = match next {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    let result = request.execute(self);
+                    results.lock().expect(BATCH_POISONED_ERROR)[index] = Some(result);
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .expect(BATCH_POISONED_ERROR)
+            .into_iter()
+            .map(|result| result.expect("Every request of a batch is executed."))
+            .collect()
+    }
+
+    /// Execute the request with `token` instead of the API key of this
+    /// client, such as when managing several accounts. The HTTP client (and
+    /// its connection pool) is shared rather than rebuilt for the call.
+    ///
+    /// ```rust,no_run
+    /// # extern crate digitalocean;
+    /// # use digitalocean::prelude::*;
+    /// let client = DigitalOcean::new("API_KEY").unwrap();
+    /// let other = client.execute_with_token(Account::get(), "OTHER_API_KEY");
+    /// ```
+    pub fn execute_with_token<A, V, T>(&self, request: Request<A, V>, token: T) -> Result<V, Error>
+    where
+        A: Method,
+        Request<A, V>: Executable<V>,
+        V: HasResponse,
+        T: Into<String>,
+    {
+        let instance = DigitalOcean {
+            client: &self.client,
+            token: token.into(),
+            retry: self.retry,
+            base_url: self.base_url.clone(),
+            user_agent: self.user_agent.clone(),
+        };
+        request.execute(&instance)
+    }
+
+    /// Execute the request, keeping metadata (such as the rate limit) from
+    /// the HTTP response alongside the value.
+    pub fn execute_with_meta<A, V>(&self, request: Request<A, V>) -> Result<Response<V>, Error>
+    where
+        A: Method,
+        Request<A, V>: Executable<V>,
+        V: HasResponse,
+    {
+        request.execute_with_meta(self)
+    }
+}
+
+// The API key is never printed, so that it can not leak into logs.
+impl<C> fmt::Debug for DigitalOcean<C>
+where
+    C: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DigitalOcean")
+            .field("client", &self.client)
+            .field("token", &REDACTED)
+            .field("retry", &self.retry)
+            .field("base_url", &self.base_url)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
+/// A builder of [`DigitalOcean`](struct.DigitalOcean.html) clients.
+///
+/// ```rust,no_run
+/// # extern crate digitalocean;
+/// # use digitalocean::retry::RetryPolicy;
+/// # use digitalocean::DigitalOcean;
+/// # use std::time::Duration;
+/// let client = DigitalOcean::builder("API_KEY")
+///     .timeout(Duration::from_secs(30))
+///     .retry(RetryPolicy::default())
+///     .base_url("http://localhost:8080/v2")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct DigitalOceanBuilder {
+    token: String,
+    timeout: Option<Duration>,
+    retry: Option<RetryPolicy>,
+    base_url: Option<String>,
+    user_agent: Option<String>,
+    proxy: Option<String>,
+}
+
+impl fmt::Debug for DigitalOceanBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DigitalOceanBuilder")
+            .field("token", &REDACTED)
+            .field("timeout", &self.timeout)
+            .field("retry", &self.retry)
+            .field("base_url", &self.base_url)
+            .field("user_agent", &self.user_agent)
+            .field("proxy", &self.proxy)
+            .finish()
+    }
+}
+
+impl DigitalOceanBuilder {
+    pub fn new<T: Into<String>>(token: T) -> Self {
+        DigitalOceanBuilder {
+            token: token.into(),
+            timeout: None,
+            retry: None,
+            base_url: None,
+            user_agent: None,
+            proxy: None,
+        }
+    }
+
+    /// Abandon requests which take longer than `timeout` to connect, or to
+    /// complete once connected, with `ErrorKind::Timeout`.
+    ///
+    /// *Note:* This only applies to clients created with
+    /// [`build()`](#method.build).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry requests which were rate limited or failed on the server
+    /// according to the given policy. By default requests are not retried.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Send requests to `base_url` instead of `https://api.digitalocean.com/v2`,
+    /// such as a mock server or a gateway in front of the API.
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Identify requests with `user_agent` instead of
+    /// `digitalocean-rs/<version>`.
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Send every request through the HTTP or SOCKS proxy at `proxy`, such as
+    /// `http://proxy.example.com:8080`. Hosts listed in the `NO_PROXY`
+    /// environment variable are still reached directly.
+    ///
+    /// By default the system proxies, such as the ones set by the
+    /// `HTTPS_PROXY` and `NO_PROXY` environment variables, are used.
+    ///
+    /// *Note:* This only applies to clients created with
+    /// [`build()`](#method.build).
+    pub fn proxy<S: Into<String>>(mut self, proxy: S) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Create the client, sending requests with a
+    /// [`ReqwestClient`](client/struct.ReqwestClient.html).
+    ///
+    /// Fails with `ErrorKind::InvalidConfig` if the API key is empty, the
+    /// base URL or proxy does not parse or the user agent is not a valid
+    /// header value.
+    pub fn build(mut self) -> Result<DigitalOcean, Error> {
+        let mut client = ReqwestClient::new();
+        if let Some(timeout) = self.timeout {
+            client = client.with_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy.take() {
+            let proxy = Proxy::all(proxy.as_str()).map_err(|e| {
+                ErrorKind::InvalidConfig(format!("the proxy {:?} is invalid: {}", proxy, e))
+            })?;
+            client = client.with_proxy(proxy.no_proxy(NoProxy::from_env()));
+        }
+        self.build_with_client(client)
+    }
+
+    /// Create the client, sending requests with the given HTTP client.
+    ///
+    /// Fails with `ErrorKind::InvalidConfig` if the API key is empty, the
+    /// base URL does not parse or the user agent is not a valid header value.
+    pub fn build_with_client<C>(self, client: C) -> Result<DigitalOcean<C>, Error>
+    where
+        C: HttpClient,
+    {
+        if self.token.trim().is_empty() {
+            return Err(ErrorKind::InvalidConfig("the API key is empty".to_string()).into());
+        }
+        let base_url = match self.base_url {
+            Some(base_url) => Url::parse(&base_url).map_err(|e| {
+                ErrorKind::InvalidConfig(format!("the base URL {:?} is invalid: {}", base_url, e))
+            })?,
+            None => ROOT_URL.clone(),
+        };
+
+        if let Some(ref user_agent) = self.user_agent {
+            HeaderValue::from_str(user_agent).map_err(|_| {
+                ErrorKind::InvalidConfig(format!("the user agent {:?} is invalid", user_agent))
+            })?;
+        }
+
+        let mut instance = DigitalOcean::with_client(self.token, client)?.with_base_url(base_url);
+        instance.retry = self.retry;
+        if let Some(user_agent) = self.user_agent {
+            instance.user_agent = user_agent;
+        }
+        Ok(instance)
+    }
 }
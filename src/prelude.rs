@@ -1,9 +1,13 @@
 //! Wildcard glob this module to have access to all commonly used items.
 
 pub use crate::api::{
-    Account, Action, Certificate, CustomImage, Domain, DomainRecord, Droplet, FloatingIp, Image,
-    LoadBalancer, Region, Size, Snapshot, SshKey, Tag, Volume,
+    Account, Action, AlertPolicy, CdnEndpoint, Certificate, CustomImage, DatabaseCluster, Domain,
+    DomainRecord, Droplet, Firewall, FloatingIp, Image, Invoice, KubernetesCluster, LoadBalancer,
+    OneClick, Project, Region, Registry, ReservedIp, Size, Snapshot, SpacesKey, SshKey, Tag,
+    UptimeCheck, Volume, Vpc,
 };
 pub use crate::request::Executable;
+#[cfg(feature = "async")]
+pub use crate::request::ExecutableAsync;
 pub use crate::request::Request;
 pub use crate::DigitalOcean;
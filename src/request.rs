@@ -2,30 +2,62 @@
 //!
 //!
 
-use crate::api::{HasPagination, HasResponse};
+use crate::api::{HasPagination, HasResponse, MAX_PER_PAGE};
+#[cfg(feature = "async")]
+use crate::client::AsyncHttpClient;
+use crate::client::{
+    list_url, request_headers, set_per_page, HeaderMap, HttpClient, Method as HttpMethod,
+    PageCursor, ReqwestClient, IDEMPOTENCY_KEY, REDACTED,
+};
 use crate::method::{Create, Delete, Get, List, Method, Update};
-use crate::DigitalOcean;
+use crate::response::{PagedResponse, RateLimit, Response};
+use crate::{DigitalOcean, DEFAULT_USER_AGENT};
 use failure::Error;
 use getset::{Getters, MutGetters, Setters};
+use log::info;
+use reqwest::header::HeaderValue;
 use serde_json::Value;
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::marker::PhantomData;
+use std::time::Duration;
 use url::Url;
 use url_serde;
 
+const STATIC_HEADERS_ERROR: &str = "Staticly constructed DigitalOcean headers are malformed.";
+
 /// A type alias with [`Request<_, Account>`](struct.Request.html) specific functions.
 pub type AccountRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Action>`](struct.Request.html) specific functions.
 pub type ActionRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, AlertPolicy>`](struct.Request.html) specific functions.
+pub type AlertPolicyRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, Balance>`](struct.Request.html) specific functions.
+pub type BalanceRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, BillingEvent>`](struct.Request.html) specific functions.
+pub type BillingEventRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, CdnEndpoint>`](struct.Request.html) specific functions.
+pub type CdnEndpointRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Certificate>`](struct.Request.html) specific functions.
 pub type CertificateRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, DomainRecord>`](struct.Request.html) specific functions.
 pub type DomainRecordRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, Database>`](struct.Request.html) specific functions.
+pub type DatabaseRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, DatabaseCluster>`](struct.Request.html) specific functions.
+pub type DatabaseClusterRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, DatabaseUser>`](struct.Request.html) specific functions.
+pub type DatabaseUserRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Domain>`](struct.Request.html) specific functions.
 pub type DomainRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, DropletAction>`](struct.Request.html) specific functions.
 pub type DropletActionRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, MetricSeries>`](struct.Request.html) specific functions.
+pub type DropletMetricsRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Droplet>`](struct.Request.html) specific functions.
 pub type DropletRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, Firewall>`](struct.Request.html) specific functions.
+pub type FirewallRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, FloatingIpAction>`](struct.Request.html) specific functions.
 pub type FloatingIpActionRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, FloatingIp>`](struct.Request.html) specific functions.
@@ -34,24 +66,50 @@ pub type FloatingIpRequest<M, V> = Request<M, V>;
 pub type ImageActionRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Image>`](struct.Request.html) specific functions.
 pub type ImageRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, ConnectionPool>`](struct.Request.html) specific functions.
+pub type ConnectionPoolRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, CustomImage>`](struct.Request.html) specific functions.
 pub type CustomImageRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, Invoice>`](struct.Request.html) specific functions.
+pub type InvoiceRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, KubernetesCluster>`](struct.Request.html) specific functions.
+pub type KubernetesClusterRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, LoadBalancer>`](struct.Request.html) specific functions.
 pub type LoadBalancerRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, NodePool>`](struct.Request.html) specific functions.
+pub type NodePoolRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, OneClick>`](struct.Request.html) specific functions.
+pub type OneClickRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, Project>`](struct.Request.html) specific functions.
+pub type ProjectRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Region>`](struct.Request.html) specific functions.
 pub type RegionRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, Registry>`](struct.Request.html) specific functions.
+pub type RegistryRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, ReservedIpAction>`](struct.Request.html) specific functions.
+pub type ReservedIpActionRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, ReservedIp>`](struct.Request.html) specific functions.
+pub type ReservedIpRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Size>`](struct.Request.html) specific functions.
 pub type SizeRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Snapshot>`](struct.Request.html) specific functions.
 pub type SnapshotRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, SpacesKey>`](struct.Request.html) specific functions.
+pub type SpacesKeyRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, SshKey>`](struct.Request.html) specific functions.
 pub type SshKeyRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Tag>`](struct.Request.html) specific functions.
 pub type TagRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, UptimeAlert>`](struct.Request.html) specific functions.
+pub type UptimeAlertRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, UptimeCheck>`](struct.Request.html) specific functions.
+pub type UptimeCheckRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, VolumeAction>`](struct.Request.html) specific functions.
 pub type VolumeActionRequest<M, V> = Request<M, V>;
 /// A type alias with [`Request<_, Volume>`](struct.Request.html) specific functions.
 pub type VolumeRequest<M, V> = Request<M, V>;
+/// A type alias with [`Request<_, Vpc>`](struct.Request.html) specific functions.
+pub type VpcRequest<M, V> = Request<M, V>;
 
 /// A consuming builder which can be used to build up API calls.
 ///
@@ -62,6 +120,7 @@ pub struct Request<A, R>
 where
     A: Method,
 {
+    /// The URL of the request, including its query.
     #[get_mut = "pub"]
     #[set = "pub"]
     #[get = "pub"]
@@ -72,6 +131,12 @@ where
     #[set = "pub"]
     #[get = "pub"]
     body: Value,
+    /// Sent as the `Idempotency-Key` header of create requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) idempotency_key: Option<String>,
+    /// Overrides the timeout of the client for this request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) timeout: Option<Duration>,
     #[get = "pub"]
     method: A,
     value: PhantomData<R>,
@@ -87,26 +152,292 @@ where
         Request {
             url,
             body: Value::Null,
+            idempotency_key: None,
+            timeout: None,
             method: A::default(),
             value: PhantomData,
         }
     }
+    /// The HTTP method the request is sent with, such as `POST` for a
+    /// `Request<Create, _>`.
+    pub fn http_method(&self) -> HttpMethod {
+        A::http_method()
+    }
+    /// Abandon the request with `ErrorKind::Timeout` once `timeout` has
+    /// passed, instead of after the timeout the client was configured with
+    /// (see [`DigitalOcean::with_timeout()`](../struct.DigitalOcean.html#method.with_timeout)).
+    ///
+    /// This is useful to give slow calls (such as creating a Droplet) more
+    /// time, or to fail fast calls sooner. Each retry of the request is given
+    /// the same timeout.
+    ///
+    /// ```rust,no_run
+    /// # extern crate digitalocean;
+    /// # use digitalocean::prelude::*;
+    /// # use std::time::Duration;
+    /// # let client = DigitalOcean::new("token").unwrap();
+    /// let regions = Region::list()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .execute(&client)
+    ///     .unwrap();
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
     pub(crate) fn transmute<C, D>(self) -> Request<C, D>
     where
         C: Method,
     {
         let mut req = Request::new(self.url);
         req.set_body(self.body);
+        req.idempotency_key = self.idempotency_key;
+        req.timeout = self.timeout;
         req
     }
 }
 
+/// A description of the exact HTTP call a request would make, built without
+/// sending it or needing a [`DigitalOcean`](../struct.DigitalOcean.html)
+/// client.
+///
+/// Created by [`Request::dry_run()`](struct.Request.html#method.dry_run). The
+/// headers are those the client sends, except that the API key in the
+/// `Authorization` header is redacted, so a dry run is safe to log. The
+/// `User-Agent` is the default one, not one set with
+/// [`DigitalOceanBuilder::user_agent()`](../struct.DigitalOceanBuilder.html#method.user_agent).
+#[derive(Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct DryRun {
+    method: HttpMethod,
+    /// The full URL of the call, including its query. For list requests this
+    /// is the first page.
+    url: Url,
+    headers: HeaderMap,
+    /// The JSON body of the call, if one would be sent.
+    body: Option<Value>,
+}
+
+impl DryRun {
+    fn new(method: HttpMethod, url: Url, body: Option<&Value>) -> Self {
+        let headers = request_headers(REDACTED, DEFAULT_USER_AGENT, body.is_some())
+            .expect(STATIC_HEADERS_ERROR);
+        DryRun {
+            method,
+            url,
+            headers,
+            body: body.cloned(),
+        }
+    }
+}
+
+impl<V> Request<Get, V> {
+    /// Describe the HTTP call this request would make, without sending it.
+    pub fn dry_run(&self) -> DryRun {
+        DryRun::new(HttpMethod::GET, self.url.clone(), None)
+    }
+}
+
+impl<V> Request<Create, V> {
+    /// Send `key` as the `Idempotency-Key` header, which identifies retries
+    /// of the same create request.
+    ///
+    /// Requests with a key are retried like any other by a
+    /// [`RetryPolicy`](../retry/struct.RetryPolicy.html). Without one they
+    /// are only retried when they certainly were not acted on, so that a
+    /// retry can not create a second resource. The key should be unique to
+    /// the resource being created, such as a UUID.
+    pub fn idempotency_key<S>(mut self, key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Describe the HTTP call this request would make, without sending it.
+    pub fn dry_run(&self) -> DryRun {
+        let body = Some(&self.body).filter(|body| !body.is_null());
+        let mut dry_run = DryRun::new(HttpMethod::POST, self.url.clone(), body);
+        if let Some(key) = &self.idempotency_key {
+            if let Ok(key) = HeaderValue::from_str(key) {
+                dry_run.headers.insert(IDEMPOTENCY_KEY, key);
+            }
+        }
+        dry_run
+    }
+}
+
+impl<V> Request<Update, V> {
+    /// Describe the HTTP call this request would make, without sending it.
+    pub fn dry_run(&self) -> DryRun {
+        DryRun::new(HttpMethod::PUT, self.url.clone(), Some(&self.body))
+    }
+}
+
+impl<V> Request<Delete, V> {
+    /// Describe the HTTP call this request would make, without sending it.
+    pub fn dry_run(&self) -> DryRun {
+        let body = Some(&self.body).filter(|body| !body.is_null());
+        DryRun::new(HttpMethod::DELETE, self.url.clone(), body)
+    }
+}
+
 impl<V> Request<List, V> {
     /// Impose a limit on the number of values which may be retrieved from a request.
     pub fn limit(mut self, limit: Option<usize>) -> Self {
         self.method.0 = limit;
         self
     }
+
+    /// Fetch `per_page` values with each request, instead of the most the
+    /// API allows. The value is clamped between `1` and `MAX_PER_PAGE`, and
+    /// a smaller [`limit()`](#method.limit) takes precedence.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#links)
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        set_per_page(&mut self.url, per_page.clamp(1, MAX_PER_PAGE));
+        self
+    }
+
+    /// Add the query parameter `key=value` to the request, such as a filter
+    /// which this crate does not support (yet). Parameters are appended, so
+    /// calling this repeatedly (even with the same key) keeps every one.
+    ///
+    /// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#introduction)
+    pub fn query<K, S>(mut self, key: K, value: S) -> Self
+    where
+        K: AsRef<str>,
+        S: AsRef<str>,
+    {
+        self.url
+            .query_pairs_mut()
+            .append_pair(key.as_ref(), value.as_ref());
+        self
+    }
+
+    /// Describe the HTTP call this request would make for its first page,
+    /// without sending it. The `per_page` query parameter the client would
+    /// add is included.
+    pub fn dry_run(&self) -> DryRun {
+        DryRun::new(HttpMethod::GET, list_url(self), None)
+    }
+}
+
+impl<V> Request<List, Vec<V>>
+where
+    Vec<V>: HasResponse,
+    <Vec<V> as HasResponse>::Response: HasPagination,
+{
+    /// Iterate over each page of the request, transparently following the
+    /// `next` link of each page until every page (or the
+    /// [`limit()`](#method.limit)) has been fetched.
+    ///
+    /// Pages are fetched lazily, one HTTP call per page. Errors are yielded
+    /// as they occur, after which the iteration ends.
+    ///
+    /// ```rust,no_run
+    /// # extern crate digitalocean;
+    /// # use digitalocean::prelude::*;
+    /// # let client = DigitalOcean::new("token").unwrap();
+    /// for page in Droplet::list().paginate(&client) {
+    ///     for droplet in page.unwrap() {
+    ///         println!("{}", droplet.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn paginate<C>(self, instance: &DigitalOcean<C>) -> Pages<'_, V, C>
+    where
+        C: HttpClient,
+    {
+        Pages {
+            instance,
+            cursor: PageCursor::new(&self),
+            request: self,
+        }
+    }
+
+    /// Fetch every page of the request (up to the [`limit()`](#method.limit),
+    /// if one is set) and collect the values into a single `Vec`.
+    ///
+    /// **Note:** This makes one HTTP call per page, which may be many calls
+    /// for large collections. Unless the request carries its own `per_page`
+    /// query parameter the largest page size the API allows is used, to
+    /// minimize the number of round trips.
+    ///
+    /// The first page which fails to be fetched aborts the call and its error
+    /// is returned; a partial result is never returned.
+    pub fn fetch_all<C>(self, instance: &DigitalOcean<C>) -> Result<Vec<V>, Error>
+    where
+        C: HttpClient,
+    {
+        instance.list(self).map(Response::into_value)
+    }
+
+    /// Fetch only the first page of the request, keeping the `links` to the
+    /// other pages and the `meta` (such as the total number of values) the
+    /// API reported alongside it.
+    ///
+    /// Other pages may be fetched by creating a request for one of the links.
+    ///
+    /// ```rust,no_run
+    /// # extern crate digitalocean;
+    /// # use digitalocean::method::List;
+    /// # use digitalocean::prelude::*;
+    /// # let client = DigitalOcean::new("token").unwrap();
+    /// let page = Droplet::list().execute_paged(&client).unwrap();
+    /// println!("{} of {:?}", page.items().len(), page.meta().total());
+    ///
+    /// if let Some(next) = page.links().next() {
+    ///     let next: Request<List, Vec<Droplet>> = Request::new(next.clone());
+    ///     let page = next.execute_paged(&client).unwrap();
+    /// }
+    /// ```
+    pub fn execute_paged<C>(self, instance: &DigitalOcean<C>) -> Result<PagedResponse<V>, Error>
+    where
+        C: HttpClient,
+    {
+        instance.list_paged(&list_url(&self), self.timeout)
+    }
+}
+
+/// An iterator over the pages of a list request.
+///
+/// Created by [`Request::paginate()`](struct.Request.html#method.paginate).
+pub struct Pages<'a, V, C = ReqwestClient> {
+    instance: &'a DigitalOcean<C>,
+    request: Request<List, Vec<V>>,
+    cursor: PageCursor,
+}
+
+impl<'a, V, C> Pages<'a, V, C> {
+    /// The rate limit reported alongside the most recently fetched page.
+    pub fn rate_limit(&self) -> &Option<RateLimit> {
+        self.cursor.rate_limit()
+    }
+}
+
+impl<'a, V, C> Iterator for Pages<'a, V, C>
+where
+    C: HttpClient,
+    Vec<V>: HasResponse,
+    <Vec<V> as HasResponse>::Response: HasPagination,
+{
+    type Item = Result<Vec<V>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let url = self.cursor.next_url()?;
+        if self.cursor.fetched() > 0 {
+            info!("Fetching next page...");
+        }
+
+        let page = self
+            .instance
+            .list_page(&url, self.request.timeout)
+            .map(|(page, next_page)| self.cursor.advance(&self.request, page, next_page));
+
+        Some(page)
+    }
 }
 
 /// Describes an API call which can be executed.
@@ -115,7 +446,18 @@ where
     T: HasResponse,
 {
     /// Execute the corresponding call.
-    fn execute(self, instance: &DigitalOcean) -> Result<T, Error>;
+    fn execute<C>(self, instance: &DigitalOcean<C>) -> Result<T, Error>
+    where
+        C: HttpClient,
+    {
+        self.execute_with_meta(instance).map(Response::into_value)
+    }
+
+    /// Execute the corresponding call, keeping metadata (such as the rate
+    /// limit) from the HTTP response alongside the value.
+    fn execute_with_meta<C>(self, instance: &DigitalOcean<C>) -> Result<Response<T>, Error>
+    where
+        C: HttpClient;
 }
 
 impl<V> Executable<Vec<V>> for Request<List, Vec<V>>
@@ -123,9 +465,11 @@ where
     Vec<V>: HasResponse,
     <Vec<V> as HasResponse>::Response: HasPagination,
 {
-    fn execute(self, instance: &DigitalOcean) -> Result<Vec<V>, Error> {
-        let response: Vec<V> = instance.list(self)?;
-        Ok(response)
+    fn execute_with_meta<C>(self, instance: &DigitalOcean<C>) -> Result<Response<Vec<V>>, Error>
+    where
+        C: HttpClient,
+    {
+        instance.list(self)
     }
 }
 
@@ -133,9 +477,11 @@ impl<V> Executable<V> for Request<Create, V>
 where
     V: HasResponse,
 {
-    fn execute(self, instance: &DigitalOcean) -> Result<V, Error> {
-        let response = instance.post(self)?;
-        Ok(response)
+    fn execute_with_meta<C>(self, instance: &DigitalOcean<C>) -> Result<Response<V>, Error>
+    where
+        C: HttpClient,
+    {
+        instance.post(self)
     }
 }
 
@@ -143,9 +489,11 @@ impl<V> Executable<V> for Request<Update, V>
 where
     V: HasResponse,
 {
-    fn execute(self, instance: &DigitalOcean) -> Result<V, Error> {
-        let response = instance.put(self)?;
-        Ok(response)
+    fn execute_with_meta<C>(self, instance: &DigitalOcean<C>) -> Result<Response<V>, Error>
+    where
+        C: HttpClient,
+    {
+        instance.put(self)
     }
 }
 
@@ -153,14 +501,103 @@ impl<V> Executable<V> for Request<Get, V>
 where
     V: HasResponse,
 {
-    fn execute(self, instance: &DigitalOcean) -> Result<V, Error> {
-        let response = instance.get(self)?;
-        Ok(response)
+    fn execute_with_meta<C>(self, instance: &DigitalOcean<C>) -> Result<Response<V>, Error>
+    where
+        C: HttpClient,
+    {
+        instance.get(self)
     }
 }
 
 impl Executable<()> for Request<Delete, ()> {
-    fn execute(self, instance: &DigitalOcean) -> Result<(), Error> {
+    fn execute_with_meta<C>(self, instance: &DigitalOcean<C>) -> Result<Response<()>, Error>
+    where
+        C: HttpClient,
+    {
         instance.delete(self)
     }
 }
+
+/// Describes an API call which can be executed without blocking the calling
+/// thread.
+///
+/// This mirrors [`Executable`](trait.Executable.html), but returns a
+/// `Future` which must be driven by an async runtime (such as `tokio`).
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub trait ExecutableAsync<T>: Sized
+where
+    T: HasResponse,
+{
+    /// Execute the corresponding call.
+    fn execute_async<C>(
+        self,
+        instance: &DigitalOcean<C>,
+    ) -> impl Future<Output = Result<T, Error>> + Send + '_
+    where
+        C: AsyncHttpClient + Sync;
+}
+
+#[cfg(feature = "async")]
+impl<V> ExecutableAsync<Vec<V>> for Request<List, Vec<V>>
+where
+    V: Send + 'static,
+    Vec<V>: HasResponse,
+    <Vec<V> as HasResponse>::Response: HasPagination,
+{
+    async fn execute_async<C>(self, instance: &DigitalOcean<C>) -> Result<Vec<V>, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        instance.list_async(self).await.map(Response::into_value)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<V> ExecutableAsync<V> for Request<Create, V>
+where
+    V: HasResponse + Send + 'static,
+{
+    async fn execute_async<C>(self, instance: &DigitalOcean<C>) -> Result<V, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        instance.post_async(self).await.map(Response::into_value)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<V> ExecutableAsync<V> for Request<Update, V>
+where
+    V: HasResponse + Send + 'static,
+{
+    async fn execute_async<C>(self, instance: &DigitalOcean<C>) -> Result<V, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        instance.put_async(self).await.map(Response::into_value)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<V> ExecutableAsync<V> for Request<Get, V>
+where
+    V: HasResponse + Send + 'static,
+{
+    async fn execute_async<C>(self, instance: &DigitalOcean<C>) -> Result<V, Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        instance.get_async(self).await.map(Response::into_value)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ExecutableAsync<()> for Request<Delete, ()> {
+    async fn execute_async<C>(self, instance: &DigitalOcean<C>) -> Result<(), Error>
+    where
+        C: AsyncHttpClient + Sync,
+    {
+        instance.delete_async(self).await.map(Response::into_value)
+    }
+}
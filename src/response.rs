@@ -0,0 +1,122 @@
+//! Values returned from executed requests alongside details of the HTTP response.
+
+use chrono::{DateTime, Utc};
+use getset::Getters;
+use url::Url;
+use url_serde;
+
+/// The value returned from an executed request together with metadata from
+/// the HTTP response it was parsed from.
+///
+/// Returned by [`Executable::execute_with_meta()`](../request/trait.Executable.html#tymethod.execute_with_meta).
+#[derive(Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct Response<V> {
+    /// The deserialized value of the response.
+    value: V,
+    /// The rate limit reported by the API. For paginated requests this is
+    /// the rate limit reported alongside the last page.
+    rate_limit: Option<RateLimit>,
+}
+
+impl<V> Response<V> {
+    pub(crate) fn new(value: V, rate_limit: Option<RateLimit>) -> Self {
+        Response { value, rate_limit }
+    }
+
+    /// Discard the metadata, returning only the value.
+    pub fn into_value(self) -> V {
+        self.value
+    }
+}
+
+/// A single page of a list request, together with what the API reported
+/// about the rest of the pages.
+///
+/// Returned by [`Request::execute_paged()`](../request/struct.Request.html#method.execute_paged).
+#[derive(Debug, Clone, Getters)]
+#[get = "pub"]
+pub struct PagedResponse<V> {
+    /// The deserialized values of the page.
+    items: Vec<V>,
+    /// The links to the other pages.
+    links: Links,
+    /// The total number of values across every page.
+    meta: Meta,
+    /// The rate limit reported alongside the page.
+    rate_limit: Option<RateLimit>,
+}
+
+impl<V> PagedResponse<V> {
+    pub(crate) fn new(
+        items: Vec<V>,
+        links: Links,
+        meta: Meta,
+        rate_limit: Option<RateLimit>,
+    ) -> Self {
+        PagedResponse {
+            items,
+            links,
+            meta,
+            rate_limit,
+        }
+    }
+
+    /// Discard the metadata, returning only the values of the page.
+    pub fn into_items(self) -> Vec<V> {
+        self.items
+    }
+}
+
+/// The links to the pages of a list response. A link is only given when the
+/// page exists, so none are given when there is only one page.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#links)
+#[derive(Deserialize, Serialize, Debug, Clone, Default, Getters)]
+#[get = "pub"]
+pub struct Links {
+    #[serde(with = "url_serde", default)]
+    first: Option<Url>,
+    #[serde(with = "url_serde", default)]
+    prev: Option<Url>,
+    #[serde(with = "url_serde", default)]
+    next: Option<Url>,
+    #[serde(with = "url_serde", default)]
+    last: Option<Url>,
+}
+
+/// The metadata of a list response.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#meta)
+#[derive(Deserialize, Serialize, Debug, Clone, Default, Getters)]
+#[get = "pub"]
+pub struct Meta {
+    /// The total number of values across every page, if the API reported it.
+    #[serde(default)]
+    total: Option<usize>,
+}
+
+/// The state of the account's rate limit, parsed from the `RateLimit-Limit`,
+/// `RateLimit-Remaining`, and `RateLimit-Reset` headers.
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#rate-limit)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters)]
+#[get = "pub"]
+pub struct RateLimit {
+    /// The number of requests that can be made per hour.
+    limit: usize,
+    /// The number of requests that remain before the limit is hit.
+    remaining: usize,
+    /// When the oldest request will expire, freeing up a request.
+    reset: DateTime<Utc>,
+}
+
+impl RateLimit {
+    pub(crate) fn new(limit: usize, remaining: usize, reset: DateTime<Utc>) -> Self {
+        RateLimit {
+            limit,
+            remaining,
+            reset,
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Retrying requests which were rejected by the rate limit, failed on the
+//! server, or were cut off by the network.
+
+use crate::client::RawResponse;
+use crate::error::ErrorKind;
+use chrono::Utc;
+use failure::Error;
+use reqwest::StatusCode;
+use std::error::Error as StdError;
+use std::io;
+use std::time::Duration;
+
+/// How requests which fail with a `429 Too Many Requests` or a `5xx` status
+/// should be retried.
+///
+/// When the response carries a `Retry-After` header (or, for a `429`, a
+/// `RateLimit-Reset` header) the wait is taken from it. Otherwise the wait
+/// starts at `base_delay` and doubles with every attempt.
+///
+/// Other `4xx` statuses are never retried.
+///
+/// Requests which fail before a response is received are retried (with the
+/// same doubling wait) if the failure is transient: the connection could not
+/// be made (such as a DNS or TLS failure), was reset, or timed out. Other
+/// errors, such as an invalid URL, are returned straight away.
+///
+/// Create requests may have been acted on even though they failed, and so
+/// are only retried when they certainly were not (after a `429`, or when no
+/// connection could be made), unless they were given an
+/// [`idempotency_key()`](../request/struct.Request.html#method.idempotency_key).
+///
+/// ```rust,no_run
+/// # use digitalocean::DigitalOcean;
+/// # use digitalocean::retry::RetryPolicy;
+/// let client = DigitalOcean::new("API_KEY")
+///     .unwrap()
+///     .with_retry(RetryPolicy::default());
+/// ```
+///
+/// [Digital Ocean Documentation.](https://developers.digitalocean.com/documentation/v2/#rate-limit)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The number of times a request is retried before its failure is returned.
+    pub max_retries: usize,
+    /// The wait before the first retry, when the response does not say how long to wait.
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: usize, base_delay: Duration) -> Self {
+        RetryPolicy {
+            max_retries,
+            base_delay,
+        }
+    }
+
+    /// How long to wait before retrying the request which produced `response`,
+    /// or `None` if it should not be retried. `attempt` is the number of
+    /// retries which have already been made, and `replayable` whether the
+    /// request may be sent again after the server acted on it.
+    pub(crate) fn delay(
+        &self,
+        attempt: usize,
+        response: &RawResponse,
+        replayable: bool,
+    ) -> Option<Duration> {
+        let status = response.status;
+        if attempt >= self.max_retries
+            || !(status == StatusCode::TOO_MANY_REQUESTS
+                || (replayable && status.is_server_error()))
+        {
+            return None;
+        }
+
+        let retry_after = response
+            .headers
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs);
+        if let Some(delay) = retry_after {
+            return Some(delay);
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            if let Some(rate_limit) = response.rate_limit() {
+                let until_reset = rate_limit.reset().signed_duration_since(Utc::now());
+                return Some(until_reset.to_std().unwrap_or_default());
+            }
+        }
+
+        Some(self.backoff(attempt))
+    }
+
+    /// How long to wait before retrying a request which failed with `error`
+    /// before a response was received, or `None` if it should not be retried.
+    pub(crate) fn transport_delay(
+        &self,
+        attempt: usize,
+        error: &Error,
+        replayable: bool,
+    ) -> Option<Duration> {
+        let retry = if replayable {
+            is_transient(error)
+        } else {
+            is_unsent(error)
+        };
+        if attempt >= self.max_retries || !retry {
+            return None;
+        }
+        Some(self.backoff(attempt))
+    }
+
+    fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 2u32.saturating_pow(attempt as u32);
+        self.base_delay.saturating_mul(factor)
+    }
+}
+
+/// Whether `error` is a failure of the network which may pass, rather than a
+/// problem with the request itself.
+fn is_transient(error: &Error) -> bool {
+    if let Some(ErrorKind::Timeout) = error.downcast_ref::<ErrorKind>() {
+        return true;
+    }
+    let mut source: Option<&(dyn StdError + 'static)> = match error.downcast_ref::<reqwest::Error>()
+    {
+        Some(error) if error.is_connect() || error.is_timeout() => return true,
+        Some(error) => Some(error),
+        None => error.downcast_ref::<io::Error>().map(|error| error as _),
+    };
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<io::Error>() {
+            return matches!(
+                error.kind(),
+                io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::BrokenPipe
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::UnexpectedEof
+            );
+        }
+        source = error.source();
+    }
+    false
+}
+
+impl Default for RetryPolicy {
+    /// Three retries, starting at one second.
+    fn default() -> Self {
+        RetryPolicy::new(3, Duration::from_secs(1))
+    }
+}
+
+/// Whether `error` means the request never reached the server, because no
+/// connection could be made.
+fn is_unsent(error: &Error) -> bool {
+    match error.downcast_ref::<reqwest::Error>() {
+        Some(error) => error.is_connect(),
+        None => matches!(
+            error.downcast_ref::<io::Error>().map(io::Error::kind),
+            Some(io::ErrorKind::ConnectionRefused)
+        ),
+    }
+}
@@ -0,0 +1,101 @@
+//! Waiting for asynchronous work, such as actions, to finish.
+
+use crate::error::ErrorKind;
+use failure::Error;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often something is polled while waiting for it, and how long to wait
+/// before giving up with `ErrorKind::Timeout`.
+///
+/// ```rust,no_run
+/// # use digitalocean::prelude::*;
+/// # use digitalocean::wait::WaitPolicy;
+/// # use std::time::Duration;
+/// # let client = DigitalOcean::new("API_KEY").unwrap();
+/// let action = Droplet::get(1)
+///     .reboot()
+///     .execute(&client)
+///     .unwrap()
+///     .wait(&client, WaitPolicy::new(Duration::from_secs(2), Duration::from_secs(120)))
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitPolicy {
+    /// The wait between polls.
+    pub interval: Duration,
+    /// The longest time to wait in total before giving up.
+    pub timeout: Duration,
+}
+
+impl WaitPolicy {
+    pub fn new(interval: Duration, timeout: Duration) -> Self {
+        WaitPolicy { interval, timeout }
+    }
+
+    /// How long to wait before polling again, having waited since `start`.
+    fn next_interval(&self, start: Instant) -> Result<Duration, Error> {
+        match self.timeout.checked_sub(start.elapsed()) {
+            Some(remaining) if remaining > Duration::from_secs(0) => {
+                Ok(self.interval.min(remaining))
+            }
+            _ => Err(ErrorKind::Timeout.into()),
+        }
+    }
+}
+
+impl Default for WaitPolicy {
+    /// Polls every five seconds, for up to ten minutes.
+    fn default() -> Self {
+        WaitPolicy::new(Duration::from_secs(5), Duration::from_secs(600))
+    }
+}
+
+/// Polls `current` with `fetch` until `finished` is `Ok(true)`, returning
+/// the last value fetched. Fails with the error of `finished` if it will never
+/// be, or `ErrorKind::Timeout` if it still is not when the policy's timeout
+/// runs out.
+pub(crate) fn poll<T, F, D>(
+    mut current: T,
+    policy: WaitPolicy,
+    mut fetch: F,
+    finished: D,
+) -> Result<T, Error>
+where
+    F: FnMut(&T) -> Result<T, Error>,
+    D: Fn(&T) -> Result<bool, Error>,
+{
+    let start = Instant::now();
+    loop {
+        if finished(&current)? {
+            return Ok(current);
+        }
+        thread::sleep(policy.next_interval(start)?);
+        current = fetch(&current)?;
+    }
+}
+
+/// Polls without blocking the calling thread. This mirrors `poll`.
+#[cfg(feature = "async")]
+pub(crate) async fn poll_async<T, F, R, D>(
+    mut current: T,
+    policy: WaitPolicy,
+    mut fetch: F,
+    finished: D,
+) -> Result<T, Error>
+where
+    F: FnMut(&T) -> R,
+    R: Future<Output = Result<T, Error>>,
+    D: Fn(&T) -> Result<bool, Error>,
+{
+    let start = Instant::now();
+    loop {
+        if finished(&current)? {
+            return Ok(current);
+        }
+        tokio::time::sleep(policy.next_interval(start)?).await;
+        current = fetch(&current).await?;
+    }
+}
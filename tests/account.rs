@@ -1,6 +1,7 @@
 extern crate digitalocean;
 #[macro_use]
 extern crate log;
+#[macro_use]
 extern crate serde_json;
 extern crate url;
 extern crate url_serde;
@@ -9,8 +10,10 @@ mod utils;
 
 use serde_json::Value;
 
-use digitalocean::api::Account;
-use digitalocean::method::Get;
+use digitalocean::api::{Account, Balance, BillingEvent};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -27,3 +30,130 @@ fn get_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn get_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/account",
+        StatusCode::OK,
+        json!({
+            "account": {
+                "droplet_limit": 25,
+                "floating_ip_limit": 3,
+                "volume_limit": 100,
+                "email": "sammy@digitalocean.com",
+                "uuid": "b6fr89dbf6d9156cace5f3c78dc9851d957381ef",
+                "email_verified": true,
+                "status": "active",
+                "status_message": "",
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let account = Account::get().execute(&client).unwrap();
+
+    assert_eq!(*account.droplet_limit(), 25);
+    assert_eq!(*account.floating_ip_limit(), 3);
+    assert_eq!(*account.volume_limit(), 100);
+    assert_eq!(account.email(), "sammy@digitalocean.com");
+    assert!(*account.email_verified());
+    assert_eq!(account.status(), "active");
+}
+
+#[test]
+fn balance_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/customers/my/balance";
+
+    let req: Request<Get, Balance> = Account::balance();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn balance_parses_amounts() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/customers/my/balance",
+        StatusCode::OK,
+        json!({
+            "month_to_date_balance": "23.44",
+            "account_balance": "12.23",
+            "month_to_date_usage": "11.21",
+            "generated_at": "2019-07-09T15:01:12Z",
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let balance = Account::balance().execute(&client).unwrap();
+
+    assert_eq!(balance.month_to_date_balance().cents(), 2344);
+    assert_eq!(balance.account_balance().to_string(), "12.23");
+    assert_eq!(balance.month_to_date_usage().cents(), 1121);
+    assert_eq!(
+        balance.generated_at().to_rfc3339(),
+        "2019-07-09T15:01:12+00:00"
+    );
+}
+
+#[test]
+fn billing_history_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/customers/my/billing_history";
+
+    let req: Request<List, Vec<BillingEvent>> = Account::billing_history();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn billing_history_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/customers/my/billing_history",
+        StatusCode::OK,
+        json!({
+            "billing_history": [
+                {
+                    "description": "Invoice for May 2018",
+                    "amount": "12.34",
+                    "invoice_id": "123",
+                    "invoice_uuid": "example-uuid",
+                    "date": "2018-06-01T08:44:38Z",
+                    "type": "Invoice",
+                },
+                {
+                    "description": "Payment (MC 2018)",
+                    "amount": "-12.34",
+                    "date": "2018-06-02T08:44:38Z",
+                    "type": "Payment",
+                },
+            ],
+            "links": {},
+            "meta": { "total": 2 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let history = Account::billing_history().execute(&client).unwrap();
+
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].invoice_uuid().as_deref(), Some("example-uuid"));
+    assert_eq!(history[1].kind(), "Payment");
+    assert_eq!(history[1].amount().cents(), -1234);
+    assert!(history[1].invoice_id().is_none());
+}
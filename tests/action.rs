@@ -0,0 +1,264 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::{Action, ActionStatus, Droplet};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::error::ErrorKind;
+use digitalocean::method::{Get, List};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+use digitalocean::wait::WaitPolicy;
+use std::time::Duration;
+
+use crate::utils::before;
+
+fn action(status: &str) -> Value {
+    json!({
+        "action": {
+            "id": 36804636,
+            "status": status,
+            "type": "reboot",
+            "started_at": "2014-11-14T16:29:21Z",
+            "completed_at": null,
+            "resource_id": 3164444,
+            "resource_type": "droplet",
+            "region_slug": "nyc3",
+        }
+    })
+}
+
+fn policy() -> WaitPolicy {
+    WaitPolicy::new(Duration::from_millis(1), Duration::from_secs(5))
+}
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/actions";
+
+    let req: Request<List, Vec<Action>> = Action::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let action_id = 123;
+    let correct_url = format!("https://api.digitalocean.com/v2/actions/{}", action_id);
+
+    let req: Request<Get, Action> = Action::get(action_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn list_follows_pages_across_the_account() {
+    before();
+
+    let mut image = action("completed")["action"].clone();
+    image["id"] = json!(36805022);
+    image["type"] = json!("transfer");
+    image["resource_type"] = json!("image");
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/actions",
+            StatusCode::OK,
+            json!({
+                "actions": [action("completed")["action"]],
+                "links": { "pages": { "next": "https://api.digitalocean.com/v2/actions?page=2" } },
+                "meta": { "total": 2 },
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/actions",
+            StatusCode::OK,
+            json!({
+                "actions": [image],
+                "links": {},
+                "meta": { "total": 2 },
+            }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let actions = Action::list().execute(&client).unwrap();
+
+    assert_eq!(actions.len(), 2);
+    assert_eq!(actions[0].kind(), "reboot");
+    assert_eq!(actions[0].resource_type(), "droplet");
+    assert_eq!(actions[1].kind(), "transfer");
+    assert_eq!(actions[1].resource_type(), "image");
+    assert_eq!(mock.requests()[1].url().query(), Some("page=2"));
+}
+
+#[test]
+fn get_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/actions/36804636",
+        StatusCode::OK,
+        action("in-progress"),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let action = Action::get(36804636).execute(&client).unwrap();
+
+    assert_eq!(*action.id(), 36804636);
+    assert_eq!(action.kind(), "reboot");
+    assert_eq!(*action.status(), ActionStatus::InProgress);
+    assert_eq!(*action.region_slug(), Some("nyc3".to_string()));
+}
+
+#[test]
+fn wait_polls_until_completed() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::POST,
+            "/v2/droplets/3164444/actions",
+            StatusCode::CREATED,
+            action("in-progress"),
+        )
+        .respond(
+            Method::GET,
+            "/v2/actions/36804636",
+            StatusCode::OK,
+            action("in-progress"),
+        )
+        .respond(
+            Method::GET,
+            "/v2/actions/36804636",
+            StatusCode::OK,
+            action("completed"),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let action = Droplet::get(3164444)
+        .reboot()
+        .execute(&client)
+        .unwrap()
+        .wait(&client, policy())
+        .unwrap();
+
+    assert_eq!(action.status(), "completed");
+    assert_eq!(mock.requests().len(), 3);
+}
+
+#[test]
+fn wait_fails_when_errored() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/actions/36804636",
+        StatusCode::OK,
+        action("errored"),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let action = Action::get(36804636).execute(&client).unwrap();
+    let error = action.wait(&client, policy()).unwrap_err();
+
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::ActionFailed(36804636)) => (),
+        _ => panic!("Unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn wait_times_out() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/actions/36804636",
+        StatusCode::OK,
+        action("in-progress"),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let action = Action::get(36804636).execute(&client).unwrap();
+    let error = action
+        .wait(
+            &client,
+            WaitPolicy::new(Duration::from_millis(1), Duration::from_secs(0)),
+        )
+        .unwrap_err();
+
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Timeout) => (),
+        _ => panic!("Unexpected error: {}", error),
+    }
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn status_parses_known_and_unknown_values() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/actions/36804636",
+            StatusCode::OK,
+            action("in-progress"),
+        )
+        .respond(
+            Method::GET,
+            "/v2/actions/36804636",
+            StatusCode::OK,
+            action("paused"),
+        );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let action = Action::get(36804636).execute(&client).unwrap();
+    assert_eq!(*action.status(), ActionStatus::InProgress);
+
+    let action = Action::get(36804636).execute(&client).unwrap();
+    assert_eq!(
+        *action.status(),
+        ActionStatus::Unknown("paused".to_string())
+    );
+    assert_eq!(action.status().to_string(), "paused");
+    assert_eq!(
+        serde_json::to_value(ActionStatus::Completed).unwrap(),
+        json!("completed")
+    );
+}
+
+#[test]
+fn timestamps_parse_into_utc() {
+    before();
+
+    let mut fixture = action("completed")["action"].clone();
+    fixture["started_at"] = json!("2014-11-14T17:29:21.123+01:00");
+    fixture["completed_at"] = json!("2014-11-14T16:30:21Z");
+
+    let action: Action = serde_json::from_value(fixture).unwrap();
+    let started_at = action.started_at();
+    let completed_at = action.completed_at().unwrap();
+
+    assert_eq!(started_at.to_rfc3339(), "2014-11-14T16:29:21.123+00:00");
+    assert!(*started_at < completed_at);
+    assert_eq!((completed_at - *started_at).num_milliseconds(), 59_877);
+}
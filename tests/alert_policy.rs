@@ -0,0 +1,205 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::alert_policy_fields::SlackDetails;
+use digitalocean::api::AlertPolicy;
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/monitoring/alerts";
+
+    let req: Request<List, Vec<AlertPolicy>> = AlertPolicy::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let uuid = "78b3da62-27e5-49ba-ac70-5db0b5935c64";
+    let correct_url = format!("https://api.digitalocean.com/v2/monitoring/alerts/{}", uuid);
+
+    let req: Request<Get, AlertPolicy> = AlertPolicy::get(uuid);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/monitoring/alerts";
+
+    let req: Request<Create, AlertPolicy> = AlertPolicy::create(
+        "v1/insights/droplet/cpu",
+        "CPU Alert",
+        "GreaterThan",
+        80.0,
+        "5m",
+    )
+    .entities(&["192018292"])
+    .tags(&["production_droplets"])
+    .email(&["bob@example.com"])
+    .slack((
+        "Production Alerts",
+        "https://hooks.slack.com/services/T1234567/AAAAAAAA/ZZZZZZ",
+    ))
+    .slack(SlackDetails::new(
+        "Staging Alerts",
+        "https://hooks.slack.com/services/T1234567/BBBBBBBB/YYYYYY",
+    ));
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "v1/insights/droplet/cpu",
+            "description": "CPU Alert",
+            "compare": "GreaterThan",
+            "value": 80.0,
+            "window": "5m",
+            "entities": ["192018292"],
+            "tags": ["production_droplets"],
+            "alerts": {
+                "email": ["bob@example.com"],
+                "slack": [
+                    {
+                        "channel": "Production Alerts",
+                        "url": "https://hooks.slack.com/services/T1234567/AAAAAAAA/ZZZZZZ",
+                    },
+                    {
+                        "channel": "Staging Alerts",
+                        "url": "https://hooks.slack.com/services/T1234567/BBBBBBBB/YYYYYY",
+                    },
+                ],
+            },
+            "enabled": true,
+        })
+    );
+}
+
+#[test]
+fn update_produces_correct_request() {
+    before();
+
+    let uuid = "78b3da62-27e5-49ba-ac70-5db0b5935c64";
+    let correct_url = format!("https://api.digitalocean.com/v2/monitoring/alerts/{}", uuid);
+
+    let req: Request<Update, AlertPolicy> = AlertPolicy::update(
+        uuid,
+        "v1/insights/droplet/memory_utilization_percent",
+        "Memory Alert",
+        "GreaterThan",
+        90.0,
+        "10m",
+    )
+    .email(&["bob@example.com"])
+    .enabled(false);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "v1/insights/droplet/memory_utilization_percent",
+            "description": "Memory Alert",
+            "compare": "GreaterThan",
+            "value": 90.0,
+            "window": "10m",
+            "entities": [],
+            "tags": [],
+            "alerts": {
+                "email": ["bob@example.com"],
+                "slack": [],
+            },
+            "enabled": false,
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let uuid = "78b3da62-27e5-49ba-ac70-5db0b5935c64";
+    let correct_url = format!("https://api.digitalocean.com/v2/monitoring/alerts/{}", uuid);
+
+    let req: Request<Delete, ()> = AlertPolicy::delete(uuid);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/monitoring/alerts",
+        StatusCode::OK,
+        json!({
+            "policy": {
+                "uuid": "78b3da62-27e5-49ba-ac70-5db0b5935c64",
+                "type": "v1/insights/droplet/cpu",
+                "description": "CPU Alert",
+                "compare": "GreaterThan",
+                "value": 80,
+                "window": "5m",
+                "entities": ["192018292"],
+                "tags": ["production_droplets"],
+                "alerts": {
+                    "email": ["bob@example.com"],
+                    "slack": [
+                        {
+                            "channel": "Production Alerts",
+                            "url": "https://hooks.slack.com/services/T1234567/AAAAAAAA/ZZZZZZ",
+                        },
+                    ],
+                },
+                "enabled": true,
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let policy = AlertPolicy::create(
+        "v1/insights/droplet/cpu",
+        "CPU Alert",
+        "GreaterThan",
+        80.0,
+        "5m",
+    )
+    .execute(&client)
+    .unwrap();
+
+    assert_eq!(policy.uuid(), "78b3da62-27e5-49ba-ac70-5db0b5935c64");
+    assert_eq!(policy.kind(), "v1/insights/droplet/cpu");
+    assert!(*policy.enabled());
+    assert_eq!(*policy.value(), 80.0);
+    assert_eq!(policy.alerts().slack[0].channel, "Production Alerts");
+}
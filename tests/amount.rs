@@ -0,0 +1,57 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate serde_json;
+
+use digitalocean::api::Amount;
+
+#[test]
+fn parses_decimal_strings_exactly() {
+    let amount: Amount = "23.44".parse().unwrap();
+    assert_eq!(amount.micros(), 23_440_000);
+    assert_eq!(amount.cents(), 2344);
+
+    let amount: Amount = "-12.5".parse().unwrap();
+    assert_eq!(amount.cents(), -1250);
+
+    let amount: Amount = "0.00744".parse().unwrap();
+    assert_eq!(amount.micros(), 7_440);
+    assert_eq!(amount.cents(), 1);
+
+    let amount: Amount = "5".parse().unwrap();
+    assert_eq!(amount.cents(), 500);
+}
+
+#[test]
+fn rejects_invalid_amounts() {
+    assert!("".parse::<Amount>().is_err());
+    assert!("1.2.3".parse::<Amount>().is_err());
+    assert!("$5.00".parse::<Amount>().is_err());
+    assert!("0.0000001".parse::<Amount>().is_err());
+}
+
+#[test]
+fn displays_with_two_or_more_places() {
+    assert_eq!(Amount::from_micros(23_440_000).to_string(), "23.44");
+    assert_eq!(Amount::from_micros(5_000_000).to_string(), "5.00");
+    assert_eq!(Amount::from_micros(7_440).to_string(), "0.00744");
+    assert_eq!(Amount::from_micros(-12_340_000).to_string(), "-12.34");
+}
+
+#[test]
+fn round_trips_through_json() {
+    let amount: Amount = serde_json::from_value(json!("23.44")).unwrap();
+    assert_eq!(serde_json::to_value(amount).unwrap(), json!("23.44"));
+
+    let amount: Amount = serde_json::from_value(json!(0.00744)).unwrap();
+    assert_eq!(amount.micros(), 7_440);
+
+    // Numbers are floats, which are rounded to the nearest micro-dollar.
+    let amount: Amount = serde_json::from_value(json!(0.00743999984115362)).unwrap();
+    assert_eq!(amount.micros(), 7_440);
+    let amount: Amount = serde_json::from_value(json!(-0.0000005)).unwrap();
+    assert_eq!(amount.micros(), -1);
+    let amount: Amount = serde_json::from_value(json!(0.0000001)).unwrap();
+    assert_eq!(amount.micros(), 0);
+    let amount: Amount = serde_json::from_value(json!(1.5e10)).unwrap();
+    assert_eq!(amount.micros(), 15_000_000_000_000_000);
+}
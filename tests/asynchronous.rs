@@ -0,0 +1,149 @@
+#![cfg(feature = "async")]
+
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate tokio;
+
+mod utils;
+
+use digitalocean::api::{Account, Action, ActionStatus, SshKey};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::prelude::*;
+use digitalocean::wait::WaitPolicy;
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::utils::before;
+
+fn ssh_key(id: usize) -> Value {
+    json!({
+        "id": id,
+        "fingerprint": "3b:16:bf:e4:8b:00:8b:b8:59:8c:a9:d3:f0:19:45:fa",
+        "public_key": "ssh-rsa AEXAMPLEaC1yc2EAAAADAQABAAAAQQDDHr/jh2Jy4yALcK4JyWbVkPRaWmhck3IgCoeOO3z1e2dBowLh64QAM+Qb72pxekALga2oi4GvT+TlWNhzPH4V example",
+        "name": "My SSH Public Key"
+    })
+}
+
+fn action(status: &str) -> Value {
+    json!({
+        "action": {
+            "id": 36804636,
+            "status": status,
+            "type": "reboot",
+            "started_at": "2014-11-14T16:29:21Z",
+            "completed_at": null,
+            "resource_id": 3164444,
+            "resource_type": "droplet",
+            "region_slug": "nyc3",
+        }
+    })
+}
+
+#[tokio::test]
+async fn execute_async_gets() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/account",
+        StatusCode::OK,
+        json!({
+            "account": {
+                "droplet_limit": 25,
+                "floating_ip_limit": 3,
+                "volume_limit": 100,
+                "email": "sammy@digitalocean.com",
+                "uuid": "b6fr89dbf6d9156cace5f3c78dc9851d957381ef",
+                "email_verified": true,
+                "status": "active",
+                "status_message": ""
+            }
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let account = client.execute_async(Account::get()).await.unwrap();
+    info!("{:#?}", account);
+
+    assert_eq!(account.email(), "sammy@digitalocean.com");
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(*requests[0].method(), Method::GET);
+    assert!(requests[0].headers().contains_key("authorization"));
+}
+
+#[tokio::test]
+async fn list_async_follows_pages() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({
+                "ssh_keys": [ssh_key(1)],
+                "links": { "pages": { "next": "https://api.digitalocean.com/v2/account/keys?page=2" } },
+                "meta": { "total": 2 }
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({
+                "ssh_keys": [ssh_key(2)],
+                "links": {},
+                "meta": { "total": 2 }
+            }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let keys = SshKey::list().execute_async(&client).await.unwrap();
+    info!("{:#?}", keys);
+
+    assert_eq!(keys.len(), 2);
+    assert_eq!(*keys[0].id(), 1);
+    assert_eq!(*keys[1].id(), 2);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].url().query(), Some("page=2"));
+}
+
+#[tokio::test]
+async fn wait_async_polls_until_completed() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/actions/36804636",
+            StatusCode::OK,
+            action("in-progress"),
+        )
+        .respond(
+            Method::GET,
+            "/v2/actions/36804636",
+            StatusCode::OK,
+            action("completed"),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let action = Action::get(36804636).execute_async(&client).await.unwrap();
+    assert_eq!(*action.status(), ActionStatus::InProgress);
+
+    let action = action
+        .wait_async(
+            &client,
+            WaitPolicy::new(Duration::from_millis(1), Duration::from_secs(5)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(*action.status(), ActionStatus::Completed);
+    assert_eq!(mock.requests().len(), 2);
+}
@@ -0,0 +1,158 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::CdnEndpoint;
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/cdn/endpoints";
+
+    let req: Request<List, Vec<CdnEndpoint>> = CdnEndpoint::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let endpoint_id = "19f06b6a-3ace-4315-b086-499a0e521b76";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/cdn/endpoints/{}",
+        endpoint_id
+    );
+
+    let req: Request<Get, CdnEndpoint> = CdnEndpoint::get(endpoint_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/cdn/endpoints";
+    let origin = "static-images.nyc3.digitaloceanspaces.com";
+
+    let req: Request<Create, CdnEndpoint> = CdnEndpoint::create(origin)
+        .ttl(3600)
+        .certificate_id("892071a0-bb95-49bc-8021-3afd67a210bf")
+        .custom_domain("static.example.com");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "origin": origin,
+            "ttl": 3600,
+            "certificate_id": "892071a0-bb95-49bc-8021-3afd67a210bf",
+            "custom_domain": "static.example.com",
+        })
+    );
+}
+
+#[test]
+fn update_produces_correct_request() {
+    before();
+
+    let endpoint_id = "19f06b6a-3ace-4315-b086-499a0e521b76";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/cdn/endpoints/{}",
+        endpoint_id
+    );
+
+    let req: Request<Update, CdnEndpoint> = CdnEndpoint::update(endpoint_id).ttl(600);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "ttl": 600,
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let endpoint_id = "19f06b6a-3ace-4315-b086-499a0e521b76";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/cdn/endpoints/{}",
+        endpoint_id
+    );
+
+    let req: Request<Delete, ()> = CdnEndpoint::delete(endpoint_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn purge_cache_produces_correct_request() {
+    before();
+
+    let endpoint_id = "19f06b6a-3ace-4315-b086-499a0e521b76";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/cdn/endpoints/{}/cache",
+        endpoint_id
+    );
+
+    let req: Request<Delete, ()> =
+        CdnEndpoint::get(endpoint_id).purge_cache(&["assets/img/hero.png", "assets/css/*"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "files": ["assets/img/hero.png", "assets/css/*"],
+        })
+    );
+}
+
+#[test]
+fn purge_cache_sends_files() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::DELETE,
+        "/v2/cdn/endpoints/19f06b6a-3ace-4315-b086-499a0e521b76/cache",
+        StatusCode::NO_CONTENT,
+        Value::Null,
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    CdnEndpoint::get("19f06b6a-3ace-4315-b086-499a0e521b76")
+        .purge_cache(&["*"])
+        .execute(&client)
+        .unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(*requests[0].body(), Some(json!({ "files": ["*"] })));
+}
@@ -10,11 +10,13 @@ mod utils;
 
 use serde_json::Value;
 
-use digitalocean::api::Certificate;
+use digitalocean::api::{Certificate, CertificateState, CertificateType};
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::{Create, Delete, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
-use crate::utils::before;
+use crate::utils::{assert_timestamp, before};
 
 #[test]
 fn list_produces_correct_request() {
@@ -91,3 +93,113 @@ fn delete_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn create_lets_encrypt_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/certificates";
+
+    let req: Request<Create, Certificate> = Certificate::create_lets_encrypt(
+        "web-cert-02",
+        vec!["www.example.com".into(), "example.com".into()],
+    );
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "web-cert-02",
+            "type": "lets_encrypt",
+            "dns_names": ["www.example.com", "example.com"],
+        })
+    );
+}
+
+#[test]
+fn list_parses_custom_and_pending_lets_encrypt() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/certificates",
+        StatusCode::OK,
+        json!({
+            "certificates": [
+                {
+                    "id": "892071a0-bb95-49bc-8021-3afd67a210bf",
+                    "name": "web-cert-01",
+                    "not_after": "2017-02-22T00:23:00Z",
+                    "sha1_fingerprint": "dfcc9f57d86bf58e321c2c6c31c7a971be244ac7",
+                    "created_at": "2017-02-08T16:02:37Z",
+                    "dns_names": [],
+                    "state": "verified",
+                    "type": "custom",
+                },
+                {
+                    "id": "ba9b9c18-6c59-46c2-99df-70da170a42ba",
+                    "name": "web-cert-02",
+                    "not_after": "",
+                    "sha1_fingerprint": "",
+                    "created_at": "2017-02-08T16:02:37Z",
+                    "dns_names": ["www.example.com", "example.com"],
+                    "state": "pending",
+                    "type": "lets_encrypt",
+                },
+            ],
+            "links": {},
+            "meta": { "total": 2 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let certificates = Certificate::list().execute(&client).unwrap();
+
+    assert_eq!(certificates.len(), 2);
+    assert_eq!(certificates[0].state(), "verified");
+    assert_eq!(certificates[0].kind(), "custom");
+    assert_eq!(
+        certificates[0].sha1_fingerprint(),
+        "dfcc9f57d86bf58e321c2c6c31c7a971be244ac7"
+    );
+    assert_eq!(
+        certificates[0].not_after().unwrap().to_rfc3339(),
+        "2017-02-22T00:23:00+00:00"
+    );
+    assert_eq!(certificates[1].state(), "pending");
+    assert_eq!(certificates[1].kind(), "lets_encrypt");
+    assert_eq!(*certificates[0].state(), CertificateState::Verified);
+    assert_eq!(*certificates[1].kind(), CertificateType::LetsEncrypt);
+    assert_eq!(certificates[1].not_after(), &None);
+    assert_eq!(certificates[1].dns_names().len(), 2);
+    assert_timestamp(certificates[0].created_at(), "2017-02-08T16:02:37Z");
+}
+
+#[test]
+fn round_trips_through_serde() {
+    before();
+
+    let issued = json!({
+        "id": "892071a0-bb95-49bc-8021-3afd67a210bf",
+        "name": "web-cert-01",
+        "not_after": "2017-02-22T00:23:00Z",
+        "sha1_fingerprint": "dfcc9f57d86bf58e321c2c6c31c7a971be244ac7",
+        "created_at": "2017-02-08T16:02:37Z",
+        "dns_names": [],
+        "state": "verified",
+        "type": "custom",
+    });
+    let mut pending = issued.clone();
+    pending["not_after"] = json!("");
+
+    for fixture in [issued, pending] {
+        let parsed: Certificate = serde_json::from_value(fixture.clone()).unwrap();
+        let serialized = serde_json::to_value(&parsed).unwrap();
+        let reparsed: Certificate = serde_json::from_value(serialized.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&reparsed).unwrap(), serialized);
+        assert_eq!(reparsed.not_after(), parsed.not_after());
+        assert_eq!(serialized["type"], fixture["type"]);
+    }
+}
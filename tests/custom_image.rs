@@ -8,11 +8,13 @@ extern crate url_serde;
 
 mod utils;
 
-use digitalocean::api::CustomImage;
+use digitalocean::api::{CustomImage, CustomImageStatus, Image, ImageType};
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::Create;
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
-use crate::utils::before;
+use crate::utils::{assert_timestamp, before};
 
 #[test]
 fn create_produces_correct_request() {
@@ -43,3 +45,83 @@ fn create_produces_correct_request() {
         })
     );
 }
+
+#[test]
+fn create_custom_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/images";
+
+    let req: Request<Create, CustomImage> = Image::create_custom(
+        "ubuntu-18.04-minimal",
+        "http://cloud-images.ubuntu.com/minimal/releases/bionic/release/ubuntu-18.04-minimal-cloudimg-amd64.img",
+        "nyc3",
+    );
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "ubuntu-18.04-minimal",
+            "url": "http://cloud-images.ubuntu.com/minimal/releases/bionic/release/ubuntu-18.04-minimal-cloudimg-amd64.img",
+            "region": "nyc3",
+        })
+    );
+
+    let req: Request<Create, CustomImage> =
+        Image::create_custom("a", "http://foo.bar/baz.raw", "nyc3")
+            .distribution("Ubuntu")
+            .description("Cloud-optimized image w/ small footprint")
+            .tags(vec!["base-image", "prod"]);
+    info!("{:#?}", req);
+
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "a",
+            "url": "http://foo.bar/baz.raw",
+            "region": "nyc3",
+            "distribution": "Ubuntu",
+            "description": "Cloud-optimized image w/ small footprint",
+            "tags": ["base-image", "prod"],
+        })
+    );
+}
+
+#[test]
+fn create_custom_parses_status() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/images",
+        StatusCode::ACCEPTED,
+        json!({
+            "image": {
+                "created_at": "2018-09-20T19:28:00Z",
+                "description": "Cloud-optimized image w/ small footprint",
+                "distribution": "Ubuntu",
+                "error_message": "",
+                "id": 38413969,
+                "name": "ubuntu-18.04-minimal",
+                "regions": [],
+                "type": "custom",
+                "tags": ["base-image", "prod"],
+                "status": "NEW",
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let image = Image::create_custom("ubuntu-18.04-minimal", "http://foo.bar/baz.raw", "nyc3")
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(*image.id(), 38413969);
+    assert_eq!(image.status(), "NEW");
+    assert_eq!(image.kind(), "custom");
+    assert_eq!(*image.status(), CustomImageStatus::New);
+    assert_eq!(*image.kind(), ImageType::Custom);
+    assert_timestamp(image.created_at(), "2018-09-20T19:28:00Z");
+}
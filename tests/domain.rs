@@ -13,7 +13,10 @@ use std::net::IpAddr;
 use std::str::FromStr;
 
 use digitalocean::api::Domain;
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::error::ErrorKind;
 use digitalocean::method::{Create, Delete, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -52,6 +55,31 @@ fn create_produces_correct_request() {
     );
 }
 
+#[test]
+fn create_sends_the_apex_address() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/domains",
+        StatusCode::CREATED,
+        json!({ "domain": { "name": "example.com", "ttl": 1800, "zone_file": null } }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let ip_address = IpAddr::from_str("1.2.3.4").unwrap();
+    let domain = Domain::create("example.com", ip_address)
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(domain.name(), "example.com");
+    assert_eq!(*domain.zone_file(), None);
+    assert_eq!(
+        *mock.requests()[0].body(),
+        Some(json!({ "name": "example.com", "ip_address": "1.2.3.4" }))
+    );
+}
+
 #[test]
 fn get_produces_correct_request() {
     before();
@@ -79,3 +107,50 @@ fn delete_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn round_trips_through_serde() {
+    before();
+
+    let fixture = json!({
+        "name": "example.com",
+        "ttl": 1800,
+        "zone_file": "$ORIGIN example.com.\n$TTL 1800\n",
+    });
+
+    let parsed: Domain = serde_json::from_value(fixture.clone()).unwrap();
+    let serialized = serde_json::to_value(&parsed).unwrap();
+    let reparsed: Domain = serde_json::from_value(serialized.clone()).unwrap();
+
+    assert_eq!(serialized, fixture);
+    assert_eq!(serde_json::to_value(&reparsed).unwrap(), fixture);
+}
+
+#[test]
+fn zone_file_returns_the_zone() {
+    before();
+
+    let zone = "$ORIGIN example.com.\n$TTL 1800\nexample.com. IN SOA ns1.digitalocean.com. hostmaster.example.com. 1415982609 10800 3600 604800 1800\n";
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/domains/example.com",
+            StatusCode::OK,
+            json!({ "domain": { "name": "example.com", "ttl": 1800, "zone_file": zone } }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/domains/example.org",
+            StatusCode::OK,
+            json!({ "domain": { "name": "example.org", "ttl": null, "zone_file": null } }),
+        );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    assert_eq!(Domain::get("example.com").zone_file(&client).unwrap(), zone);
+
+    let error = Domain::get("example.org").zone_file(&client).unwrap_err();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::NotFound) => (),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+}
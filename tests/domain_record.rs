@@ -10,8 +10,10 @@ mod utils;
 
 use serde_json::Value;
 
-use digitalocean::api::{Domain, DomainRecord};
+use digitalocean::api::{DnsRecordType, Domain, DomainRecord};
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -122,3 +124,130 @@ fn delete_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn filters_produce_correct_request() {
+    before();
+
+    let domain = "example.com";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/domains/{}/records?type=A&name=www.example.com",
+        domain
+    );
+
+    let req: Request<List, Vec<DomainRecord>> = Domain::get(domain)
+        .records()
+        .filter_type("A")
+        .filter_name("www.example.com");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn record_produces_correct_requests() {
+    before();
+
+    let domain = "example.com";
+    let record_id = 123;
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/domains/{}/records/{}",
+        domain, record_id
+    );
+
+    let req: Request<Get, DomainRecord> = Domain::get(domain).record(record_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+
+    let req: Request<Update, DomainRecord> = Domain::get(domain)
+        .record(record_id)
+        .update()
+        .data("192.168.0.2")
+        .priority(Some(10));
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "data": "192.168.0.2",
+            "priority": 10,
+        })
+    );
+
+    let req: Request<Delete, ()> = Domain::get(domain).record(record_id).delete();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn record_types_are_typed() {
+    before();
+
+    let req: Request<Create, DomainRecord> = Domain::get("example.com")
+        .records()
+        .create(DnsRecordType::Srv, "_sip._tcp", "sip.example.com.")
+        .priority(Some(10))
+        .weight(Some(5))
+        .port(Some(5060));
+    assert_eq!(req.body()["type"], json!("SRV"));
+
+    let req: Request<List, Vec<DomainRecord>> = Domain::get("example.com")
+        .records()
+        .filter_type(DnsRecordType::Aaaa);
+    assert_eq!(req.url().query(), Some("type=AAAA"));
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/domains/example.com/records",
+        StatusCode::OK,
+        json!({
+            "domain_records": [
+                {
+                    "id": 28448429,
+                    "type": "MX",
+                    "name": "@",
+                    "data": "mail.example.com",
+                    "priority": 10,
+                    "port": null,
+                    "ttl": 1800,
+                    "weight": null,
+                },
+                {
+                    "id": 28448430,
+                    "type": "HTTPS",
+                    "name": "@",
+                    "data": "1 . alpn=h2",
+                    "priority": null,
+                    "port": null,
+                    "ttl": 1800,
+                    "weight": null,
+                },
+            ],
+            "links": {},
+            "meta": { "total": 2 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let records = Domain::get("example.com")
+        .records()
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(*records[0].kind(), DnsRecordType::Mx);
+    assert_eq!(*records[0].priority(), Some(10));
+    assert_eq!(
+        *records[1].kind(),
+        DnsRecordType::Unknown("HTTPS".to_string())
+    );
+    assert_eq!(
+        serde_json::to_value(&records[1]).unwrap()["type"],
+        json!("HTTPS")
+    );
+}
@@ -9,12 +9,84 @@ extern crate url_serde;
 mod utils;
 
 use serde_json::Value;
-
-use digitalocean::api::{Droplet, Snapshot};
+use std::collections::HashSet;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use digitalocean::api::droplet_fields::{Kernel, NetworkType};
+use digitalocean::api::{
+    Droplet, DropletFeature, DropletId, DropletStatus, NeighborIds, Snapshot, VolumeId,
+};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::error::ErrorKind;
 use digitalocean::method::{Create, Delete, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
-
-use crate::utils::before;
+use digitalocean::wait::WaitPolicy;
+
+use crate::utils::{assert_timestamp, before};
+
+fn droplet(id: usize, tag: &str) -> Value {
+    json!({
+        "id": id,
+        "name": format!("example-{}", id),
+        "memory": 1024,
+        "vcpus": 1,
+        "disk": 25,
+        "locked": false,
+        "created_at": "2020-07-21T18:37:44Z",
+        "status": "active",
+        "backup_ids": [],
+        "snapshot_ids": [],
+        "features": ["monitoring"],
+        "region": {
+            "name": "New York 3",
+            "slug": "nyc3",
+            "sizes": ["s-1vcpu-1gb"],
+            "available": true,
+            "features": ["metadata"],
+        },
+        "image": {
+            "id": 63663980,
+            "name": "20.04 (LTS) x64",
+            "type": "base",
+            "distribution": "Ubuntu",
+            "slug": "ubuntu-20-04-x64",
+            "public": true,
+            "regions": ["nyc3"],
+            "min_disk_size": 15,
+            "size_gigabytes": 2.36,
+            "created_at": "2020-05-15T05:47:50Z",
+        },
+        "size": {
+            "slug": "s-1vcpu-1gb",
+            "available": true,
+            "transfer": 1.0,
+            "price_monthly": 5.0,
+            "price_hourly": 0.00743,
+            "memory": 1024,
+            "vcpus": 1,
+            "disk": 25,
+            "regions": ["nyc3"],
+        },
+        "size_slug": "s-1vcpu-1gb",
+        "networks": {
+            "v4": [
+                {
+                    "gateway": "104.236.0.1",
+                    "ip_address": "104.236.32.182",
+                    "netmask": "255.255.192.0",
+                    "type": "public",
+                },
+            ],
+            "v6": [],
+        },
+        "kernel": null,
+        "next_backup_window": null,
+        "tags": [tag],
+        "volume_ids": [],
+    })
+}
 
 #[test]
 fn create_produces_correct_request() {
@@ -45,6 +117,185 @@ fn create_produces_correct_request() {
     );
 }
 
+#[test]
+fn create_sets_network_and_agent_flags() {
+    before();
+
+    let vpc_uuid = "5a4981aa-9653-4bd1-bef5-d6bff52042e4";
+    let flags = json!({
+        "ipv6": true,
+        "private_networking": false,
+        "with_droplet_agent": true,
+        "vpc_uuid": vpc_uuid,
+    });
+
+    let req: Request<Create, Droplet> = Droplet::create("bear", "tor1", "5gb", "ubuntu-14-04-x64")
+        .ipv6(true)
+        .private_networking(false)
+        .with_droplet_agent(true)
+        .vpc(vpc_uuid);
+    info!("{:#?}", req);
+
+    for key in [
+        "ipv6",
+        "private_networking",
+        "with_droplet_agent",
+        "vpc_uuid",
+    ] {
+        assert_eq!(req.body()[key], flags[key]);
+    }
+
+    let req: Request<Create, Vec<Droplet>> =
+        Droplet::create_multiple(vec!["bear", "badger"], "tor1", "5gb", "ubuntu-14-04-x64")
+            .ipv6(true)
+            .private_networking(false)
+            .with_droplet_agent(true)
+            .vpc(vpc_uuid);
+    info!("{:#?}", req);
+
+    for key in [
+        "ipv6",
+        "private_networking",
+        "with_droplet_agent",
+        "vpc_uuid",
+    ] {
+        assert_eq!(req.body()[key], flags[key]);
+    }
+}
+
+#[test]
+fn create_accepts_any_collection() {
+    before();
+
+    let volumes: &[&str] = &["12e97116-7280-11ed-b3d0-0a58ac146812"];
+    let tags = vec![String::from("web"), String::from("prod")];
+    let req: Request<Create, Droplet> = Droplet::create("bear", "tor1", "5gb", "ubuntu-14-04-x64")
+        .ssh_keys([512189, 512190])
+        .volumes(volumes)
+        .tags(tags.clone());
+    info!("{:#?}", req);
+
+    assert_eq!(req.body()["ssh_keys"], json!([512189, 512190]));
+    assert_eq!(
+        req.body()["volumes"],
+        json!(["12e97116-7280-11ed-b3d0-0a58ac146812"])
+    );
+    assert_eq!(req.body()["tags"], json!(tags));
+
+    let req: Request<Create, Vec<Droplet>> =
+        Droplet::create_multiple(vec!["bear", "badger"], "tor1", "5gb", "ubuntu-14-04-x64")
+            .ssh_keys(vec!["3b:16:bf:e4:8b:00:8b:b8:59:8c:a9:d3:f0:19:45:fa"])
+            .tags(["web"].iter().copied());
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.body()["ssh_keys"],
+        json!(["3b:16:bf:e4:8b:00:8b:b8:59:8c:a9:d3:f0:19:45:fa"])
+    );
+    assert_eq!(req.body()["tags"], json!(["web"]));
+}
+
+#[test]
+fn status_parses_known_and_unknown_values() {
+    before();
+
+    let mut unknown = droplet(2, "bear");
+    unknown["status"] = json!("hibernating");
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/droplets",
+        StatusCode::OK,
+        json!({
+            "droplets": [droplet(1, "bear"), unknown],
+            "links": {},
+            "meta": { "total": 2 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let droplets = Droplet::list().execute(&client).unwrap();
+
+    assert_eq!(*droplets[0].status(), DropletStatus::Active);
+    assert_eq!(
+        *droplets[1].status(),
+        DropletStatus::Unknown("hibernating".to_string())
+    );
+    assert_eq!(droplets[1].status().as_str(), "hibernating");
+    assert_eq!(
+        serde_json::to_value(&droplets[1]).unwrap()["status"],
+        json!("hibernating")
+    );
+}
+
+#[test]
+fn volume_ids_are_typed() {
+    before();
+
+    let mut fixture = droplet(1, "bear");
+    fixture["volume_ids"] = json!(["506f78a4-e098-11e5-ad9f-000f53306ae1"]);
+    let droplet: Droplet = serde_json::from_value(fixture).unwrap();
+
+    assert_eq!(
+        *droplet.volume_ids(),
+        vec![VolumeId::from("506f78a4-e098-11e5-ad9f-000f53306ae1")]
+    );
+}
+
+#[test]
+fn features_and_tags_are_checked() {
+    before();
+
+    let mut fixture = droplet(1, "bear");
+    fixture["features"] = json!(["monitoring", "private_networking", "hyperthreading"]);
+    let droplet: Droplet = serde_json::from_value(fixture).unwrap();
+
+    assert!(droplet.has_feature(&DropletFeature::Monitoring));
+    assert!(droplet.has_feature(&DropletFeature::PrivateNetworking));
+    assert!(!droplet.has_feature(&DropletFeature::Backups));
+    assert!(droplet.has_feature(&DropletFeature::from("hyperthreading")));
+    assert!(droplet.has_tag("bear"));
+    assert!(!droplet.has_tag("tiger"));
+    assert_timestamp(droplet.created_at(), "2020-07-21T18:37:44Z");
+    assert_timestamp(droplet.image().created_at(), "2020-05-15T05:47:50Z");
+    assert_eq!(
+        serde_json::to_value(&droplet).unwrap()["features"],
+        json!(["monitoring", "private_networking", "hyperthreading"])
+    );
+}
+
+#[test]
+fn create_multiple_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/droplets",
+        StatusCode::ACCEPTED,
+        json!({
+            "droplets": [droplet(1, "bear"), droplet(2, "bear")],
+            "links": {
+                "actions": [
+                    { "id": 1, "rel": "create", "href": "https://api.digitalocean.com/v2/actions/1" },
+                    { "id": 2, "rel": "create", "href": "https://api.digitalocean.com/v2/actions/2" },
+                ],
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let names = vec![String::from("example-1"), String::from("example-2")];
+    let droplets = Droplet::create_multiple(names, "nyc3", "s-1vcpu-1gb", "ubuntu-20-04-x64")
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(droplets.len(), 2);
+    assert_eq!(*droplets[1].id(), DropletId(2));
+    assert_eq!(
+        mock.requests()[0].body().as_ref().unwrap()["names"],
+        json!(["example-1", "example-2"])
+    );
+}
+
 #[test]
 fn create_many_produces_correct_request() {
     before();
@@ -119,6 +370,86 @@ fn list_by_tag_produces_correct_request() {
     assert_eq!(*req.body(), Value::Null);
 }
 
+#[test]
+fn list_by_tag_follows_pages() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/droplets",
+            StatusCode::OK,
+            json!({
+                "droplets": [droplet(1, "bear")],
+                "links": { "pages": { "next": "https://api.digitalocean.com/v2/droplets?page=2&tag_name=bear" } },
+                "meta": { "total": 2 },
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/droplets",
+            StatusCode::OK,
+            json!({
+                "droplets": [droplet(2, "bear")],
+                "links": {},
+                "meta": { "total": 2 },
+            }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let droplets = Droplet::list_by_tag("bear").execute(&client).unwrap();
+
+    assert_eq!(droplets.len(), 2);
+    assert_eq!(droplets[1].tags(), &["bear"]);
+
+    let requests = mock.requests();
+    assert_eq!(
+        requests[0].url().query(),
+        Some("tag_name=bear&per_page=200")
+    );
+    assert_eq!(requests[1].url().query(), Some("page=2&tag_name=bear"));
+}
+
+#[test]
+fn get_by_name_scans_pages() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/droplets",
+            StatusCode::OK,
+            json!({
+                "droplets": [droplet(1, "bear")],
+                "links": { "pages": { "next": "https://api.digitalocean.com/v2/droplets?page=2" } },
+                "meta": { "total": 2 },
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/droplets",
+            StatusCode::OK,
+            json!({
+                "droplets": [droplet(2, "bear")],
+                "links": {},
+                "meta": { "total": 2 },
+            }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let found = Droplet::get_by_name(&client, "example-2").unwrap();
+
+    assert_eq!(*found.id(), DropletId(2));
+    assert_eq!(mock.requests().len(), 2);
+
+    // The mock now repeats the last page, which has no such droplet.
+    let error = Droplet::get_by_name(&client, "example").unwrap_err();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::NotFound) => (),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+}
+
 #[test]
 fn delete_produces_correct_request() {
     before();
@@ -150,6 +481,31 @@ fn delete_by_tag_produces_correct_request() {
     assert_eq!(*req.body(), Value::Null);
 }
 
+#[test]
+fn delete_by_tag_sends_single_delete() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::DELETE,
+        "/v2/droplets",
+        StatusCode::NO_CONTENT,
+        json!(null),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    Droplet::delete_by_tag("ephemeral")
+        .execute(&client)
+        .unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(*requests[0].method(), Method::DELETE);
+    assert_eq!(
+        requests[0].url().as_str(),
+        "https://api.digitalocean.com/v2/droplets?tag_name=ephemeral"
+    );
+}
+
 #[test]
 fn neighbors_produces_correct_request() {
     before();
@@ -163,6 +519,39 @@ fn neighbors_produces_correct_request() {
     assert_eq!(*req.body(), Value::Null);
 }
 
+#[test]
+fn neighbor_ids_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/reports/droplet_neighbors_ids";
+
+    let req: Request<Get, NeighborIds> = Droplet::neighbor_ids();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn neighbor_ids_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/reports/droplet_neighbors_ids",
+        StatusCode::OK,
+        json!({
+            "neighbor_ids": [[168671828, 168663509, 168671815], [168671883, 168671750]],
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let NeighborIds(groups) = Droplet::neighbor_ids().execute(&client).unwrap();
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[1], vec![DropletId(168671883), DropletId(168671750)]);
+}
+
 #[test]
 fn snapshots_produces_correct_request() {
     before();
@@ -213,3 +602,263 @@ fn get_neighbors_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn kernels_produces_correct_request() {
+    before();
+
+    let droplet_id = 123;
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/droplets/{}/kernels",
+        droplet_id
+    );
+
+    let req: Request<List, Vec<Kernel>> = Droplet::get(droplet_id).kernels();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn kernels_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/droplets/123/kernels",
+        StatusCode::OK,
+        json!({
+            "kernels": [
+                {
+                    "id": 7515,
+                    "name": "DigitalOcean GrubLoader v0.2 (20160714)",
+                    "version": "2016.07.13-DigitalOcean_loader_Ubuntu",
+                },
+            ],
+            "links": {},
+            "meta": { "total": 1 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let kernels = Droplet::get(123).kernels().execute(&client).unwrap();
+
+    assert_eq!(kernels.len(), 1);
+    assert_eq!(kernels[0].id, 7515);
+}
+
+#[test]
+fn get_neighbors_parses_unpaginated_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/droplets/1/neighbors",
+        StatusCode::OK,
+        json!({
+            "droplets": [droplet(2, "bear")],
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let neighbors = Droplet::get(1).neighbors().execute(&client).unwrap();
+
+    assert_eq!(neighbors.len(), 1);
+    assert_eq!(*neighbors[0].id(), DropletId(2));
+}
+
+#[test]
+fn round_trips_through_serde() {
+    before();
+
+    let fixture = droplet(1, "bear");
+
+    let parsed: Droplet = serde_json::from_value(fixture.clone()).unwrap();
+    let serialized = serde_json::to_value(&parsed).unwrap();
+    let reparsed: Droplet = serde_json::from_value(serialized.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(&reparsed).unwrap(), serialized);
+    assert_eq!(serialized["image"]["type"], fixture["image"]["type"]);
+    assert_eq!(serialized["networks"], fixture["networks"]);
+    assert_eq!(serialized["created_at"], fixture["created_at"]);
+    assert_eq!(*reparsed.status(), DropletStatus::Active);
+}
+
+#[test]
+fn droplets_are_identified_by_id() {
+    before();
+
+    let active: Droplet = serde_json::from_value(droplet(1, "bear")).unwrap();
+    let mut fixture = droplet(1, "bear");
+    fixture["status"] = json!("off");
+    let off: Droplet = serde_json::from_value(fixture).unwrap();
+    let other: Droplet = serde_json::from_value(droplet(2, "bear")).unwrap();
+
+    assert_eq!(active, off);
+    assert_ne!(active, other);
+
+    let droplets: HashSet<Droplet> = vec![active, off, other].into_iter().collect();
+    assert_eq!(droplets.len(), 2);
+}
+
+#[test]
+fn networks_parse_into_typed_addresses() {
+    before();
+
+    let mut fixture = droplet(1, "bear");
+    fixture["networks"] = json!({
+        "v4": [
+            {
+                "gateway": "10.128.0.1",
+                "ip_address": "10.128.0.2",
+                "netmask": "255.255.0.0",
+                "type": "private",
+            },
+            {
+                "gateway": "104.236.0.1",
+                "ip_address": "104.236.32.182",
+                "netmask": "255.255.192.0",
+                "type": "public",
+            },
+        ],
+        "v6": [
+            {
+                "gateway": "2604:a880:0:1010::1",
+                "ip_address": "2604:a880:0:1010::18a:a001",
+                "netmask": 64,
+                "type": "public",
+            },
+        ],
+    });
+
+    let droplet: Droplet = serde_json::from_value(fixture).unwrap();
+    let networks = droplet.networks();
+
+    assert_eq!(networks.v4[0].kind, NetworkType::Private);
+    assert_eq!(networks.v4[1].kind, NetworkType::Public);
+    assert_eq!(
+        networks.v4_of(NetworkType::Public).unwrap().ip_address,
+        Ipv4Addr::new(104, 236, 32, 182)
+    );
+    assert_eq!(
+        networks.v4_of(NetworkType::Private).unwrap().netmask,
+        Ipv4Addr::new(255, 255, 0, 0)
+    );
+    assert_eq!(
+        networks.v6_of(NetworkType::Public).unwrap().ip_address,
+        "2604:a880:0:1010::18a:a001".parse::<Ipv6Addr>().unwrap()
+    );
+    assert!(networks.v6_of(NetworkType::Private).is_none());
+}
+
+#[test]
+fn ipv4_addresses_are_found_by_type() {
+    before();
+
+    let mut fixture = droplet(1, "bear");
+    let public: Droplet = serde_json::from_value(fixture.clone()).unwrap();
+
+    assert_eq!(public.public_ipv4(), Some(Ipv4Addr::new(104, 236, 32, 182)));
+    assert_eq!(public.private_ipv4(), None);
+
+    fixture["networks"]["v4"] = json!([
+        {
+            "gateway": "10.128.0.1",
+            "ip_address": "10.128.0.2",
+            "netmask": "255.255.0.0",
+            "type": "private",
+        },
+    ]);
+    let private: Droplet = serde_json::from_value(fixture).unwrap();
+
+    assert_eq!(private.public_ipv4(), None);
+    assert_eq!(private.private_ipv4(), Some(Ipv4Addr::new(10, 128, 0, 2)));
+}
+
+#[test]
+fn create_and_wait_polls_until_active() {
+    before();
+
+    let mut new = droplet(3164444, "bear");
+    new["status"] = json!("new");
+    new["networks"] = json!({ "v4": [], "v6": [] });
+    let mock = MockClient::new()
+        .respond(
+            Method::POST,
+            "/v2/droplets",
+            StatusCode::ACCEPTED,
+            json!({ "droplet": new.clone() }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/droplets/3164444",
+            StatusCode::OK,
+            json!({ "droplet": new }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/droplets/3164444",
+            StatusCode::OK,
+            json!({ "droplet": droplet(3164444, "bear") }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let droplet = Droplet::create("example", "nyc3", "s-1vcpu-1gb", "ubuntu-20-04-x64")
+        .create_and_wait(
+            &client,
+            WaitPolicy::new(Duration::from_millis(1), Duration::from_secs(5)),
+        )
+        .unwrap();
+
+    assert_eq!(*droplet.status(), DropletStatus::Active);
+    assert_eq!(
+        droplet.public_ipv4(),
+        Some(Ipv4Addr::new(104, 236, 32, 182))
+    );
+    assert_eq!(mock.requests().len(), 3);
+}
+
+#[test]
+fn wait_active_times_out() {
+    before();
+
+    let mut new = droplet(3164444, "bear");
+    new["status"] = json!("new");
+    let mock = MockClient::new();
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let droplet: Droplet = serde_json::from_value(new).unwrap();
+    let error = droplet
+        .wait_active(
+            &client,
+            WaitPolicy::new(Duration::from_millis(1), Duration::from_secs(0)),
+        )
+        .unwrap_err();
+
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Timeout) => (),
+        _ => panic!("Unexpected error: {}", error),
+    }
+    assert_eq!(mock.requests().len(), 0);
+}
+
+#[test]
+fn create_sends_user_data() {
+    before();
+
+    let user_data = "#cloud-config\nruncmd:\n  - touch /test.txt\n";
+
+    let req: Request<Create, Droplet> =
+        Droplet::create("bear", "tor1", "5gb", "ubuntu-14-04-x64").user_data(user_data);
+    info!("{:#?}", req);
+
+    assert_eq!(req.body()["user_data"], json!(user_data));
+
+    let req: Request<Create, Vec<Droplet>> =
+        Droplet::create_multiple(vec!["bear", "badger"], "tor1", "5gb", "ubuntu-14-04-x64")
+            .user_data(String::from(user_data));
+    info!("{:#?}", req);
+
+    assert_eq!(req.body()["user_data"], json!(user_data));
+}
@@ -10,8 +10,11 @@ mod utils;
 
 use serde_json::Value;
 
+use digitalocean::api::droplet_fields::ImageRef;
 use digitalocean::api::{Action, Droplet};
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::{Create, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -179,7 +182,7 @@ fn power_produces_correct_request() {
 }
 
 #[test]
-fn restore_produces_correct_request() {
+fn power_on_and_off_produce_correct_requests() {
     before();
 
     let droplet_id = 123;
@@ -188,21 +191,39 @@ fn restore_produces_correct_request() {
         droplet_id
     );
 
-    // As slug
-    let image_id = "test";
-    let req: Request<Create, Action> = Droplet::get(droplet_id).restore(image_id);
+    let req: Request<Create, Action> = Droplet::get(droplet_id).power_on();
     info!("{:#?}", req);
 
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(
         *req.body(),
         json!({
-            "type": "restore",
-            "image": image_id.to_string(),
+            "type": "power_on",
         })
     );
 
-    // As id
+    let req: Request<Create, Action> = Droplet::get(droplet_id).power_off();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "power_off",
+        })
+    );
+}
+
+#[test]
+fn restore_produces_correct_request() {
+    before();
+
+    let droplet_id = 123;
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/droplets/{}/actions",
+        droplet_id
+    );
+
     let image_id = 456;
     let req: Request<Create, Action> = Droplet::get(droplet_id).restore(image_id);
     info!("{:#?}", req);
@@ -212,7 +233,7 @@ fn restore_produces_correct_request() {
         *req.body(),
         json!({
             "type": "restore",
-            "image": image_id.to_string(),
+            "image": image_id,
         })
     );
 }
@@ -273,8 +294,9 @@ fn rebuild_produces_correct_request() {
         "https://api.digitalocean.com/v2/droplets/{}/actions",
         droplet_id
     );
-    let image_id = String::from("test");
 
+    // As slug
+    let image_id = String::from("test");
     let req: Request<Create, Action> = Droplet::get(droplet_id).rebuild(image_id.clone());
     info!("{:#?}", req);
 
@@ -286,6 +308,19 @@ fn rebuild_produces_correct_request() {
             "image": image_id,
         })
     );
+
+    // As id
+    let req: Request<Create, Action> = Droplet::get(droplet_id).rebuild(ImageRef::Id(456));
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "rebuild",
+            "image": 456,
+        })
+    );
 }
 
 #[test]
@@ -336,6 +371,29 @@ fn kernel_produces_correct_request() {
     );
 }
 
+#[test]
+fn change_kernel_produces_correct_request() {
+    before();
+
+    let droplet_id = 123;
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/droplets/{}/actions",
+        droplet_id
+    );
+
+    let req: Request<Create, Action> = Droplet::get(droplet_id).change_kernel(7515);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "change_kernel",
+            "kernel": 7515,
+        })
+    );
+}
+
 #[test]
 fn enable_ipv6_kernel_produces_correct_request() {
     before();
@@ -421,3 +479,85 @@ fn get_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn action_by_tag_produces_correct_requests() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/droplets/actions?tag_name=web";
+
+    let req: Request<Create, Vec<Action>> = Droplet::action_by_tag("web").power_off();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), json!({ "type": "power_off" }));
+
+    let req: Request<Create, Vec<Action>> = Droplet::action_by_tag("web").shutdown();
+    assert_eq!(*req.body(), json!({ "type": "shutdown" }));
+
+    let req: Request<Create, Vec<Action>> = Droplet::action_by_tag("web").snapshot("web-nightly");
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({ "type": "snapshot", "name": "web-nightly" })
+    );
+}
+
+#[test]
+fn action_by_tag_sends_the_bodies_of_single_droplets() {
+    before();
+
+    let tagged = Droplet::action_by_tag("web");
+    let droplet = Droplet::get(123);
+
+    assert_eq!(
+        tagged.clone().power_cycle().body(),
+        droplet.clone().power_cycle().body()
+    );
+    assert_eq!(
+        tagged.clone().power(true).body(),
+        droplet.clone().power(true).body()
+    );
+    assert_eq!(
+        tagged.clone().enable_private_networking().body(),
+        droplet.clone().enable_private_networking().body()
+    );
+    assert_eq!(
+        tagged.snapshot("web-nightly").body(),
+        droplet.snapshot("web-nightly").body()
+    );
+}
+
+#[test]
+fn action_by_tag_parses_every_action() {
+    before();
+
+    let action = |id: usize, resource_id: usize| {
+        json!({
+            "id": id,
+            "status": "in-progress",
+            "type": "power_on",
+            "started_at": "2014-11-14T16:29:21Z",
+            "completed_at": null,
+            "resource_id": resource_id,
+            "resource_type": "droplet",
+            "region_slug": "nyc3",
+        })
+    };
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/droplets/actions",
+        StatusCode::CREATED,
+        json!({ "actions": [action(1, 101), action(2, 102)] }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let actions = Droplet::action_by_tag("web")
+        .power_on()
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(actions.len(), 2);
+    assert_eq!(*actions[1].resource_id(), 102);
+    assert_eq!(mock.requests()[0].url().query(), Some("tag_name=web"));
+}
@@ -0,0 +1,128 @@
+extern crate chrono;
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use chrono::{TimeZone, Utc};
+use serde_json::Value;
+
+use digitalocean::api::MetricSeries;
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::Get;
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn cpu_produces_correct_request() {
+    before();
+
+    let start = Utc.timestamp_opt(1635386400, 0).unwrap();
+    let end = Utc.timestamp_opt(1635390000, 0).unwrap();
+    let correct_url = "https://api.digitalocean.com/v2/monitoring/metrics/droplet/cpu?host_id=123&start=1635386400&end=1635390000";
+
+    let req: Request<Get, Vec<MetricSeries>> = Droplet::metrics(123).cpu(start, end);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn bandwidth_produces_correct_request() {
+    before();
+
+    let start = Utc.timestamp_opt(1635386400, 0).unwrap();
+    let end = Utc.timestamp_opt(1635390000, 0).unwrap();
+    let correct_url = "https://api.digitalocean.com/v2/monitoring/metrics/droplet/bandwidth?host_id=123&interface=public&direction=inbound&start=1635386400&end=1635390000";
+
+    let req: Request<Get, Vec<MetricSeries>> =
+        Droplet::metrics(123).bandwidth("inbound", "public", start, end);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn memory_available_produces_correct_request() {
+    before();
+
+    let start = Utc.timestamp_opt(1635386400, 0).unwrap();
+    let end = Utc.timestamp_opt(1635390000, 0).unwrap();
+    let correct_url = "https://api.digitalocean.com/v2/monitoring/metrics/droplet/memory_available?host_id=123&start=1635386400&end=1635390000";
+
+    let req: Request<Get, Vec<MetricSeries>> = Droplet::metrics(123).memory_available(start, end);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+}
+
+#[test]
+fn filesystem_free_produces_correct_request() {
+    before();
+
+    let start = Utc.timestamp_opt(1635386400, 0).unwrap();
+    let end = Utc.timestamp_opt(1635390000, 0).unwrap();
+    let correct_url = "https://api.digitalocean.com/v2/monitoring/metrics/droplet/filesystem_free?host_id=123&start=1635386400&end=1635390000";
+
+    let req: Request<Get, Vec<MetricSeries>> = Droplet::metrics(123).filesystem_free(start, end);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+}
+
+#[test]
+fn cpu_parses_values() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/monitoring/metrics/droplet/cpu",
+        StatusCode::OK,
+        json!({
+            "status": "success",
+            "data": {
+                "resultType": "matrix",
+                "result": [
+                    {
+                        "metric": { "host_id": "123", "mode": "idle" },
+                        "values": [
+                            [1635386880, "122901.18"],
+                            [1635387000.5, "123020.92"],
+                        ],
+                    },
+                ],
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let start = Utc.timestamp_opt(1635386400, 0).unwrap();
+    let end = Utc.timestamp_opt(1635390000, 0).unwrap();
+    let series = Droplet::metrics(123)
+        .cpu(start, end)
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0].metric()["mode"], "idle");
+    assert_eq!(
+        series[0].values()[0],
+        (Utc.timestamp_opt(1635386880, 0).unwrap(), 122901.18)
+    );
+    assert_eq!(
+        series[0].values()[1],
+        (
+            Utc.timestamp_opt(1635387000, 500_000_000).unwrap(),
+            123020.92
+        )
+    );
+}
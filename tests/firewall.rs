@@ -0,0 +1,362 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::firewall_fields::{InboundRule, OutboundRule, Targets};
+use digitalocean::api::{DropletId, Firewall, FirewallStatus};
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::request::Request;
+
+use crate::utils::{assert_timestamp, before};
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/firewalls";
+
+    let req: Request<List, Vec<Firewall>> = Firewall::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!("https://api.digitalocean.com/v2/firewalls/{}", firewall_id);
+
+    let req: Request<Get, Firewall> = Firewall::get(firewall_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/firewalls";
+    let name = "firewall";
+
+    let req: Request<Create, Firewall> = Firewall::create(name)
+        .inbound_rule(("tcp", "22", Targets::new().address("0.0.0.0/0").tag("web")))
+        .inbound_rule(InboundRule::new(
+            "icmp",
+            None,
+            Targets::new().load_balancer("4de7ac8b-495b-4884-9a69-1050c6793cd6"),
+        ))
+        .outbound_rule(("udp", "53", Targets::new().droplet(8043964)))
+        .droplets(vec![DropletId(8043964)])
+        .tags(vec!["web"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": name,
+            "inbound_rules": [
+                {
+                    "protocol": "tcp",
+                    "ports": "22",
+                    "sources": {
+                        "addresses": ["0.0.0.0/0"],
+                        "tags": ["web"],
+                    },
+                },
+                {
+                    "protocol": "icmp",
+                    "sources": {
+                        "load_balancer_uids": ["4de7ac8b-495b-4884-9a69-1050c6793cd6"],
+                    },
+                },
+            ],
+            "outbound_rules": [
+                {
+                    "protocol": "udp",
+                    "ports": "53",
+                    "destinations": {
+                        "droplet_ids": [8043964],
+                    },
+                },
+            ],
+            "droplet_ids": [8043964],
+            "tags": ["web"],
+        })
+    );
+}
+
+#[test]
+fn update_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!("https://api.digitalocean.com/v2/firewalls/{}", firewall_id);
+    let name = "firewall";
+
+    let req: Request<Update, Firewall> = Firewall::update(firewall_id, name)
+        .inbound_rule(("tcp", "80", Targets::new().address("::/0")))
+        .droplets(vec![DropletId(8043964)]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": name,
+            "inbound_rules": [
+                {
+                    "protocol": "tcp",
+                    "ports": "80",
+                    "sources": {
+                        "addresses": ["::/0"],
+                    },
+                },
+            ],
+            "droplet_ids": [8043964],
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!("https://api.digitalocean.com/v2/firewalls/{}", firewall_id);
+
+    let req: Request<Delete, ()> = Firewall::delete(firewall_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn add_rules_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/firewalls/{}/rules",
+        firewall_id
+    );
+
+    let req: Request<Create, ()> = Firewall::get(firewall_id).add_rules(
+        vec![("tcp", "22", Targets::new().tag("web")).into()],
+        vec![("tcp", "all", Targets::new().address("0.0.0.0/0")).into()],
+    );
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "inbound_rules": [
+                {
+                    "protocol": "tcp",
+                    "ports": "22",
+                    "sources": {
+                        "tags": ["web"],
+                    },
+                },
+            ],
+            "outbound_rules": [
+                {
+                    "protocol": "tcp",
+                    "ports": "all",
+                    "destinations": {
+                        "addresses": ["0.0.0.0/0"],
+                    },
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn remove_rules_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/firewalls/{}/rules",
+        firewall_id
+    );
+
+    let req: Request<Delete, ()> = Firewall::get(firewall_id).remove_rules(
+        vec![],
+        vec![OutboundRule::new(
+            "icmp",
+            None,
+            Targets::new().address("0.0.0.0/0"),
+        )],
+    );
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "inbound_rules": [],
+            "outbound_rules": [
+                {
+                    "protocol": "icmp",
+                    "destinations": {
+                        "addresses": ["0.0.0.0/0"],
+                    },
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn add_droplets_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/firewalls/{}/droplets",
+        firewall_id
+    );
+
+    let req: Request<Create, ()> =
+        Firewall::get(firewall_id).add_droplets(vec![DropletId(49696269)]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "droplet_ids": [49696269],
+        })
+    );
+}
+
+#[test]
+fn remove_droplets_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/firewalls/{}/droplets",
+        firewall_id
+    );
+
+    let req: Request<Delete, ()> =
+        Firewall::get(firewall_id).remove_droplets(vec![DropletId(49696269)]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "droplet_ids": [49696269],
+        })
+    );
+}
+
+#[test]
+fn add_tags_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/firewalls/{}/tags",
+        firewall_id
+    );
+
+    let req: Request<Create, ()> = Firewall::get(firewall_id).add_tags(["frontend"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "tags": ["frontend"],
+        })
+    );
+}
+
+#[test]
+fn add_tags_accepts_owned_strings() {
+    before();
+
+    let tags = vec![String::from("frontend"), String::from("backend")];
+    let req: Request<Create, ()> =
+        Firewall::get("bb4b2611-3d72-467b-8602-280330ecd65c").add_tags(tags);
+    info!("{:#?}", req);
+
+    assert_eq!(
+        *req.body(),
+        json!({
+            "tags": ["frontend", "backend"],
+        })
+    );
+}
+
+#[test]
+fn remove_tags_produces_correct_request() {
+    before();
+
+    let firewall_id = "bb4b2611-3d72-467b-8602-280330ecd65c";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/firewalls/{}/tags",
+        firewall_id
+    );
+
+    let req: Request<Delete, ()> = Firewall::get(firewall_id).remove_tags(["frontend"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "tags": ["frontend"],
+        })
+    );
+}
+
+#[test]
+fn droplet_ids_are_typed() {
+    before();
+
+    let firewall: Firewall = serde_json::from_value(json!({
+        "id": "bb4b2611-3d72-467b-8602-280330ecd65c",
+        "status": "waiting",
+        "created_at": "2017-05-23T21:24:00Z",
+        "pending_changes": [
+            { "droplet_id": 8043964, "removing": false, "status": "waiting" }
+        ],
+        "name": "firewall",
+        "inbound_rules": [],
+        "outbound_rules": [],
+        "droplet_ids": [8043964],
+        "tags": []
+    }))
+    .unwrap();
+
+    assert_eq!(*firewall.droplet_ids(), vec![DropletId(8043964)]);
+    assert_eq!(*firewall.status(), FirewallStatus::Waiting);
+    assert_timestamp(firewall.created_at(), "2017-05-23T21:24:00Z");
+    assert_eq!(firewall.pending_changes()[0].droplet_id, DropletId(8043964));
+
+    // The ids of a firewall may be handed straight to another.
+    let req: Request<Create, ()> = Firewall::get("fb6045f1-cf1d-4ca3-bfac-18832663025b")
+        .add_droplets(firewall.droplet_ids().clone());
+    assert_eq!(req.body()["droplet_ids"], json!([8043964]));
+}
@@ -0,0 +1,133 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use digitalocean::api::{
+    Droplet, DropletId, ImageId, LoadBalancer, LoadBalancerId, RegionSlug, SizeSlug, Volume,
+    VolumeId,
+};
+use digitalocean::error::ErrorKind;
+use digitalocean::method::{Create, Get};
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn ids_are_accepted_alongside_primitives() {
+    before();
+
+    let droplet_id = DropletId(123);
+    let req: Request<Get, Droplet> = Droplet::get(droplet_id);
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/droplets/123"
+    );
+    assert_eq!(Droplet::get(123).url(), req.url());
+
+    let volume_id = VolumeId::from("506f78a4-e098-11e5-ad9f-000f53306ae1");
+    let req: Request<Get, Volume> = Volume::get(volume_id.clone());
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/volumes/506f78a4-e098-11e5-ad9f-000f53306ae1"
+    );
+    assert_eq!(Volume::get(volume_id.as_ref()).url(), req.url());
+
+    let req: Request<Get, LoadBalancer> =
+        LoadBalancer::get(LoadBalancerId::from("4de7ac8b-495b-4884-9a69-1050c6793cd6"));
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/load_balancers/4de7ac8b-495b-4884-9a69-1050c6793cd6"
+    );
+}
+
+#[test]
+fn ids_serialize_as_their_values() {
+    before();
+
+    let req: Request<Create, _> =
+        Volume::get("506f78a4-e098-11e5-ad9f-000f53306ae1").attach(DropletId(123));
+    info!("{:#?}", req);
+
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "attach",
+            "droplet_id": 123,
+        })
+    );
+
+    let req = Droplet::get(123).restore(ImageId(456));
+    assert_eq!(req.body()["image"], json!(456));
+
+    assert_eq!(
+        serde_json::to_value(VolumeId::from("abc")).unwrap(),
+        json!("abc")
+    );
+    assert_eq!(
+        serde_json::from_value::<DropletId>(json!(123)).unwrap(),
+        DropletId(123)
+    );
+    assert_eq!(DropletId(123).to_string(), "123");
+    assert_eq!(usize::from(DropletId(123)), 123);
+}
+
+#[test]
+fn slugs_are_parsed_and_displayed() {
+    before();
+
+    let region: RegionSlug = "nyc3".parse().unwrap();
+    let size: SizeSlug = "s-1vcpu-1gb".parse().unwrap();
+
+    assert_eq!(region.to_string(), "nyc3");
+    assert_eq!(size.as_ref(), "s-1vcpu-1gb");
+    assert_eq!(serde_json::to_value(&region).unwrap(), json!("nyc3"));
+
+    for invalid in ["", "NYC3", "nyc 3", "nyc_3"] {
+        match invalid.parse::<RegionSlug>() {
+            Err(ErrorKind::InvalidSlug(slug)) => assert_eq!(slug, invalid),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    let req: Request<Create, Droplet> = Droplet::create(
+        "example".to_string(),
+        region.to_string(),
+        size.to_string(),
+        "ubuntu-20-04-x64".to_string(),
+    );
+    assert_eq!(req.body()["region"], json!("nyc3"));
+    assert_eq!(req.body()["size"], json!("s-1vcpu-1gb"));
+}
+
+#[test]
+fn volume_ids_are_attached_at_creation() {
+    before();
+
+    let volumes = [
+        VolumeId::from("506f78a4-e098-11e5-ad9f-000f53306ae1"),
+        VolumeId::from("7724db7c-e098-11e5-b522-000f53304e51"),
+    ];
+    let req: Request<Create, Droplet> =
+        Droplet::create("example", "nyc3", "s-1vcpu-1gb", "ubuntu-20-04-x64").volumes(&volumes);
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.body()["volumes"],
+        json!([
+            "506f78a4-e098-11e5-ad9f-000f53306ae1",
+            "7724db7c-e098-11e5-b522-000f53304e51"
+        ])
+    );
+}
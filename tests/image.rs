@@ -14,7 +14,7 @@ use digitalocean::api::Image;
 use digitalocean::method::{Delete, Get, List, Update};
 use digitalocean::request::Request;
 
-use crate::utils::before;
+use crate::utils::{assert_timestamp, before};
 
 #[test]
 fn list_produces_correct_request() {
@@ -29,6 +29,45 @@ fn list_produces_correct_request() {
     assert_eq!(*req.body(), Value::Null);
 }
 
+#[test]
+fn list_distribution_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/images?type=distribution";
+
+    let req: Request<List, Vec<Image>> = Image::list_distribution();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn list_application_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/images?type=application";
+
+    let req: Request<List, Vec<Image>> = Image::list_application();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn list_user_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/images?private=true";
+
+    let req: Request<List, Vec<Image>> = Image::list_user();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
 #[test]
 fn get_produces_correct_request() {
     before();
@@ -51,7 +90,7 @@ fn update_produces_correct_request() {
     let correct_url = format!("https://api.digitalocean.com/v2/images/{}", image_id);
     let name = "blah-blah";
 
-    let req: Request<Update, Image> = Image::update(image_id).name(name);
+    let req: Request<Update, Image> = Image::update(image_id, name);
     info!("{:#?}", req);
 
     assert_eq!(req.url().as_str(), correct_url);
@@ -76,3 +115,26 @@ fn delete_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn parses_fields() {
+    before();
+
+    let image: Image = serde_json::from_value(json!({
+        "id": 7555620,
+        "name": "Nifty New Snapshot",
+        "distribution": "Ubuntu",
+        "slug": null,
+        "public": false,
+        "regions": ["nyc2", "nyc3"],
+        "created_at": "2014-11-04T22:23:02Z",
+        "type": "snapshot",
+        "min_disk_size": 20,
+        "size_gigabytes": 2.34,
+    }))
+    .unwrap();
+
+    assert_eq!(*image.id(), 7555620);
+    assert_eq!(image.kind(), "snapshot");
+    assert_timestamp(image.created_at(), "2014-11-04T22:23:02Z");
+}
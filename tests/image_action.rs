@@ -11,7 +11,9 @@ mod utils;
 use serde_json::Value;
 
 use digitalocean::api::{Action, Image};
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::{Create, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -96,3 +98,39 @@ fn convert_produces_correct_request() {
         })
     );
 }
+
+#[test]
+fn transfer_parses_action() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/images/7938269/actions",
+        StatusCode::CREATED,
+        json!({
+            "action": {
+                "id": 36805527,
+                "status": "in-progress",
+                "type": "transfer",
+                "started_at": "2014-11-14T16:42:45Z",
+                "completed_at": null,
+                "resource_id": 7938269,
+                "resource_type": "image",
+                "region_slug": "nyc3",
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let action = Image::get(7938269)
+        .transfer("nyc2")
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(*action.id(), 36805527);
+    assert_eq!(action.resource_type(), "image");
+    assert_eq!(
+        mock.requests()[0].body(),
+        &Some(json!({ "type": "transfer", "region": "nyc2" }))
+    );
+}
@@ -0,0 +1,325 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+use std::io::Read;
+use std::time::Duration;
+
+use digitalocean::api::{Invoice, InvoiceDocument, InvoiceItem, InvoiceSummary};
+use digitalocean::client::{HeaderMap, Method, MockClient, RawResponse, StatusCode};
+use digitalocean::error::ErrorKind;
+use digitalocean::method::{Get, List};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+const INVOICE_UUID: &str = "22737513-0ea7-4206-8ceb-98a575af7681";
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/customers/my/invoices";
+
+    let req: Request<List, Vec<Invoice>> = Invoice::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/customers/my/invoices/{}",
+        INVOICE_UUID
+    );
+
+    let req: Request<List, Vec<InvoiceItem>> = Invoice::get(INVOICE_UUID);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn summary_produces_correct_request() {
+    before();
+
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/customers/my/invoices/{}/summary",
+        INVOICE_UUID
+    );
+
+    let req: Request<Get, InvoiceSummary> = Invoice::get(INVOICE_UUID).summary();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn documents_produce_correct_requests() {
+    before();
+
+    let req: Request<Get, InvoiceDocument> = Invoice::get(INVOICE_UUID).csv();
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        format!(
+            "https://api.digitalocean.com/v2/customers/my/invoices/{}/csv",
+            INVOICE_UUID
+        )
+    );
+
+    let req: Request<Get, InvoiceDocument> = Invoice::get(INVOICE_UUID).pdf();
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        format!(
+            "https://api.digitalocean.com/v2/customers/my/invoices/{}/pdf",
+            INVOICE_UUID
+        )
+    );
+}
+
+#[test]
+fn list_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/customers/my/invoices",
+        StatusCode::OK,
+        json!({
+            "invoices": [
+                {
+                    "invoice_uuid": INVOICE_UUID,
+                    "amount": "27.13",
+                    "invoice_period": "2019-12",
+                    "updated_at": "2020-01-23T06:31:50Z",
+                },
+            ],
+            "invoice_preview": {
+                "invoice_uuid": "1afe95e6-0958-4eb0-8d9a-9c5060d3ef03",
+                "amount": "34.73",
+                "invoice_period": "2020-02",
+                "updated_at": "2020-02-23T06:31:50Z",
+            },
+            "links": {},
+            "meta": { "total": 1 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let invoices = Invoice::list().execute(&client).unwrap();
+
+    assert_eq!(invoices.len(), 1);
+    assert_eq!(invoices[0].amount().cents(), 2713);
+    assert_eq!(invoices[0].invoice_period(), "2019-12");
+}
+
+#[test]
+fn get_parses_items() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        format!("/v2/customers/my/invoices/{}", INVOICE_UUID),
+        StatusCode::OK,
+        json!({
+            "invoice_items": [
+                {
+                    "product": "Droplets",
+                    "resource_uuid": "711157cb-37c8-4817-be95-bfc2a7d5b83c",
+                    "group_description": "",
+                    "description": "My Example Droplet",
+                    "amount": "12.34",
+                    "duration": "672",
+                    "duration_unit": "Hours",
+                    "start_time": "2020-01-01T00:00:00Z",
+                    "end_time": "2020-02-01T00:00:00Z",
+                    "project_name": "My project",
+                },
+            ],
+            "links": {},
+            "meta": { "total": 1 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let items = Invoice::get(INVOICE_UUID).execute(&client).unwrap();
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].product(), "Droplets");
+    assert_eq!(items[0].amount().to_string(), "12.34");
+}
+
+#[test]
+fn summary_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        format!("/v2/customers/my/invoices/{}/summary", INVOICE_UUID),
+        StatusCode::OK,
+        json!({
+            "invoice_uuid": INVOICE_UUID,
+            "billing_period": "2020-01",
+            "amount": "27.13",
+            "user_name": "Sammy Shark",
+            "user_billing_address": {
+                "address_line1": "101 Shark Row",
+                "address_line2": " ",
+                "city": "Atlantis",
+                "region": "OC",
+                "postal_code": "12345",
+                "country_iso2_code": "US",
+                "created_at": "2019-09-03T16:34:46.000+00:00",
+                "updated_at": "2019-09-03T16:34:46.000+00:00",
+            },
+            "user_company": "DigitalOcean",
+            "user_email": "sammy@digitalocean.com",
+            "product_charges": {
+                "name": "Product usage charges",
+                "amount": "12.34",
+                "items": [
+                    { "amount": "10.00", "name": "Spaces Subscription", "count": "1" },
+                    { "amount": "2.34", "name": "Database Clusters", "count": "1" },
+                ],
+            },
+            "overages": { "name": "Overages", "amount": "3.45" },
+            "taxes": { "name": "Taxes", "amount": "4.56" },
+            "credits_and_adjustments": { "name": "Credits & adjustments", "amount": "6.78" },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let summary = Invoice::get(INVOICE_UUID)
+        .summary()
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(summary.billing_period(), "2020-01");
+    assert_eq!(summary.product_charges().items.len(), 2);
+    assert_eq!(summary.taxes().amount.cents(), 456);
+    assert_eq!(
+        summary.user_billing_address().as_ref().unwrap().city,
+        "Atlantis"
+    );
+}
+
+#[test]
+fn pdf_returns_raw_bytes() {
+    before();
+
+    let pdf = b"%PDF-1.4\n\x00\xff\xfe binary".to_vec();
+    let mock = MockClient::new().respond_with(
+        Method::GET,
+        format!("/v2/customers/my/invoices/{}/pdf", INVOICE_UUID),
+        RawResponse::new(StatusCode::OK, HeaderMap::new(), pdf.clone()),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let document = Invoice::get(INVOICE_UUID).pdf().execute(&client).unwrap();
+
+    assert_eq!(*document.bytes(), pdf);
+}
+
+#[test]
+fn csv_returns_raw_bytes() {
+    before();
+
+    let csv = "product,group_description,description,hours,start,end,USD,project_name,category\n";
+    let mock = MockClient::new().respond_with(
+        Method::GET,
+        format!("/v2/customers/my/invoices/{}/csv", INVOICE_UUID),
+        RawResponse::new(StatusCode::OK, HeaderMap::new(), csv.as_bytes().to_vec()),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let document = Invoice::get(INVOICE_UUID).csv().execute(&client).unwrap();
+
+    assert_eq!(document.bytes().as_slice(), csv.as_bytes());
+}
+
+#[test]
+fn pdf_can_be_streamed() {
+    before();
+
+    let pdf = b"%PDF-1.4\n\x00\xff\xfe binary".to_vec();
+    let mock = MockClient::new().respond_with(
+        Method::GET,
+        format!("/v2/customers/my/invoices/{}/pdf", INVOICE_UUID),
+        RawResponse::new(StatusCode::OK, HeaderMap::new(), pdf.clone()),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let mut document = Invoice::get(INVOICE_UUID)
+        .pdf()
+        .execute_stream(&client)
+        .unwrap();
+    let mut bytes = Vec::new();
+    document.read_to_end(&mut bytes).unwrap();
+
+    assert_eq!(*document.status(), StatusCode::OK);
+    assert_eq!(bytes, pdf);
+}
+
+#[test]
+fn streams_honour_the_request_timeout() {
+    before();
+
+    let mock = MockClient::new().respond_with(
+        Method::GET,
+        format!("/v2/customers/my/invoices/{}/csv", INVOICE_UUID),
+        RawResponse::new(StatusCode::OK, HeaderMap::new(), b"product,amount".to_vec()),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    Invoice::get(INVOICE_UUID)
+        .csv()
+        .timeout(Duration::from_secs(300))
+        .execute_stream(&client)
+        .unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(*requests[0].method(), Method::GET);
+    assert_eq!(*requests[0].timeout(), Some(Duration::from_secs(300)));
+}
+
+#[test]
+fn streaming_fails_on_error_statuses() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        format!("/v2/customers/my/invoices/{}/csv", INVOICE_UUID),
+        StatusCode::NOT_FOUND,
+        json!({ "id": "not_found", "message": "The resource you requested could not be found." }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let error = Invoice::get(INVOICE_UUID)
+        .csv()
+        .execute_stream(&client)
+        .unwrap_err();
+
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::NotFound) => (),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+}
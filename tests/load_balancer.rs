@@ -10,11 +10,64 @@ mod utils;
 
 use serde_json::Value;
 
-use digitalocean::api::LoadBalancer;
+use digitalocean::api::{LoadBalancer, LoadBalancerStatus};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::error::ErrorKind;
 use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
+use digitalocean::wait::WaitPolicy;
+use std::time::Duration;
+
+use crate::utils::{assert_timestamp, before};
+
+const LOAD_BALANCER_ID: &str = "4de7ac8b-495b-4884-9a69-1050c6793cd6";
+
+fn load_balancer(status: &str) -> Value {
+    json!({
+        "load_balancer": {
+            "id": LOAD_BALANCER_ID,
+            "name": "example-lb-01",
+            "ip": "104.131.186.241",
+            "algorithm": "round_robin",
+            "status": status,
+            "created_at": "2017-02-01T22:22:58Z",
+            "forwarding_rules": [
+                {
+                    "entry_protocol": "http",
+                    "entry_port": 80,
+                    "target_protocol": "http",
+                    "target_port": 80,
+                    "certificate_id": "",
+                    "tls_passthrough": false
+                }
+            ],
+            "health_check": {
+                "protocol": "http",
+                "port": 80,
+                "path": "/healthz",
+                "check_interval_seconds": 10,
+                "response_timeout_seconds": 5,
+                "healthy_threshold": 5,
+                "unhealthy_threshold": 3
+            },
+            "sticky_sessions": { "type": "none" },
+            "region": {
+                "name": "New York 3",
+                "slug": "nyc3",
+                "sizes": ["s-1vcpu-1gb"],
+                "features": ["private_networking"],
+                "available": true
+            },
+            "droplet_ids": [3164444],
+            "redirect_http_to_https": false
+        }
+    })
+}
 
-use crate::utils::before;
+fn policy() -> WaitPolicy {
+    WaitPolicy::new(Duration::from_millis(1), Duration::from_secs(5))
+}
 
 #[test]
 fn list_produces_correct_request() {
@@ -90,6 +143,124 @@ fn create_produces_correct_request() {
     );
 }
 
+#[test]
+fn create_with_health_check_and_sticky_sessions_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/load_balancers";
+    let (name, region) = ("test", "nyc3");
+
+    let req: Request<Create, LoadBalancer> = LoadBalancer::create(name, region)
+        .forwarding_rule(("http", 80, "http", 8080))
+        .health_check("http", 8080, Some("/health"), Some(10), None, None, Some(3))
+        .sticky_sessions("cookies", Some("DO-LB"), Some(300))
+        .redirect_http_to_https(true)
+        .droplets(vec![3164444, 3164445]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": name,
+            "region": region,
+            "forwarding_rules": [
+                {
+                    "entry_protocol": "http",
+                    "entry_port": 80,
+                    "target_protocol": "http",
+                    "target_port": 8080,
+                    "certificate_id": Value::Null,
+                    "tls_passthrough": false,
+                },
+            ],
+            "health_check": {
+                "protocol": "http",
+                "port": 8080,
+                "path": "/health",
+                "check_interval_seconds": 10,
+                "healthy_threshold": 3,
+            },
+            "sticky_sessions": {
+                "type": "cookies",
+                "cookie_name": "DO-LB",
+                "cookie_ttl_seconds": 300,
+            },
+            "redirect_http_to_https": true,
+            "droplet_ids": [3164444, 3164445],
+        })
+    );
+}
+
+#[test]
+fn get_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/load_balancers/4de7ac8b-495b-4884-9a69-1050c6793cd6",
+        StatusCode::OK,
+        json!({
+            "load_balancer": {
+                "id": "4de7ac8b-495b-4884-9a69-1050c6793cd6",
+                "name": "example-lb-01",
+                "ip": "104.131.186.241",
+                "algorithm": "round_robin",
+                "status": "new",
+                "created_at": "2017-02-01T22:22:58Z",
+                "forwarding_rules": [
+                    {
+                        "entry_protocol": "tcp",
+                        "entry_port": 22,
+                        "target_protocol": "tcp",
+                        "target_port": 22,
+                        "certificate_id": "",
+                        "tls_passthrough": false
+                    }
+                ],
+                "health_check": {
+                    "protocol": "tcp",
+                    "port": 22,
+                    "check_interval_seconds": 10,
+                    "response_timeout_seconds": 5,
+                    "healthy_threshold": 5,
+                    "unhealthy_threshold": 3
+                },
+                "sticky_sessions": {
+                    "type": "cookies",
+                    "cookie_name": "DO-LB",
+                    "cookie_ttl_seconds": 300
+                },
+                "region": {
+                    "name": "New York 3",
+                    "slug": "nyc3",
+                    "sizes": ["s-1vcpu-1gb"],
+                    "features": ["private_networking"],
+                    "available": true
+                },
+                "droplet_ids": [3164444, 3164445],
+                "redirect_http_to_https": false
+            }
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let load_balancer = LoadBalancer::get("4de7ac8b-495b-4884-9a69-1050c6793cd6")
+        .execute(&client)
+        .unwrap();
+    info!("{:#?}", load_balancer);
+
+    assert_eq!(
+        load_balancer.sticky_sessions().cookie_ttl_seconds,
+        Some(300)
+    );
+    assert_eq!(*load_balancer.status(), LoadBalancerStatus::New);
+    assert_eq!(load_balancer.health_check().path, "");
+    assert_eq!(load_balancer.tag(), "");
+    assert_timestamp(load_balancer.created_at(), "2017-02-01T22:22:58Z");
+    assert_eq!(*load_balancer.droplet_ids(), vec![3164444, 3164445]);
+}
+
 #[test]
 fn update_produces_correct_request() {
     before();
@@ -261,3 +432,84 @@ fn remove_forwarding_rule_produces_correct_request() {
         })
     );
 }
+
+#[test]
+fn create_and_wait_polls_until_active() {
+    before();
+
+    let path = format!("/v2/load_balancers/{}", LOAD_BALANCER_ID);
+    let mock = MockClient::new()
+        .respond(
+            Method::POST,
+            "/v2/load_balancers",
+            StatusCode::ACCEPTED,
+            load_balancer("new"),
+        )
+        .respond(
+            Method::GET,
+            path.as_str(),
+            StatusCode::OK,
+            load_balancer("new"),
+        )
+        .respond(
+            Method::GET,
+            path.as_str(),
+            StatusCode::OK,
+            load_balancer("active"),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let load_balancer = LoadBalancer::create("example-lb-01", "nyc3")
+        .forwarding_rule(("http", 80, "http", 80))
+        .create_and_wait(&client, policy())
+        .unwrap();
+
+    assert_eq!(*load_balancer.status(), LoadBalancerStatus::Active);
+    assert_eq!(load_balancer.health_check().path, "/healthz");
+    assert_eq!(mock.requests().len(), 3);
+}
+
+#[test]
+fn wait_active_fails_when_errored() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        format!("/v2/load_balancers/{}", LOAD_BALANCER_ID),
+        StatusCode::OK,
+        load_balancer("errored"),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let new: LoadBalancer =
+        serde_json::from_value(load_balancer("new")["load_balancer"].clone()).unwrap();
+    let error = new.wait_active(&client, policy()).unwrap_err();
+
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::LoadBalancerFailed(id)) => assert_eq!(id, LOAD_BALANCER_ID),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn wait_active_times_out() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        format!("/v2/load_balancers/{}", LOAD_BALANCER_ID),
+        StatusCode::OK,
+        load_balancer("new"),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let new: LoadBalancer =
+        serde_json::from_value(load_balancer("new")["load_balancer"].clone()).unwrap();
+    let policy = WaitPolicy::new(Duration::from_millis(1), Duration::from_millis(20));
+    let error = new.wait_active(&client, policy).unwrap_err();
+
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Timeout) => (),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+}
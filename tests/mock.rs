@@ -0,0 +1,1198 @@
+extern crate digitalocean;
+extern crate failure;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use digitalocean::api::{Account, Firewall, SshKey};
+use digitalocean::client::{HeaderMap, HttpClient, Method, MockClient, RawResponse, StatusCode};
+use digitalocean::error::{ErrorKind, MAX_DESERIALIZE_BODY};
+use digitalocean::method::{Create, Delete, List};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+use digitalocean::retry::RetryPolicy;
+use failure::Error;
+use std::io::{self, BufRead, BufReader};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use url::Url;
+
+use crate::utils::before;
+
+fn account() -> serde_json::Value {
+    json!({
+        "account": {
+            "droplet_limit": 25,
+            "floating_ip_limit": 3,
+            "volume_limit": 100,
+            "email": "sammy@digitalocean.com",
+            "uuid": "b6fr89dbf6d9156cace5f3c78dc9851d957381ef",
+            "email_verified": true,
+            "status": "active",
+            "status_message": ""
+        }
+    })
+}
+
+fn ssh_key(id: usize) -> serde_json::Value {
+    json!({
+        "id": id,
+        "fingerprint": "3b:16:bf:e4:8b:00:8b:b8:59:8c:a9:d3:f0:19:45:fa",
+        "public_key": "ssh-rsa AEXAMPLEaC1yc2EAAAADAQABAAAAQQDDHr/jh2Jy4yALcK4JyWbVkPRaWmhck3IgCoeOO3z1e2dBowLh64QAM+Qb72pxekALga2oi4GvT+TlWNhzPH4V example",
+        "name": "My SSH Public Key"
+    })
+}
+
+#[test]
+fn get_returns_canned_response() {
+    before();
+
+    let mock = MockClient::new().respond(Method::GET, "/v2/account", StatusCode::OK, account());
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let account = Account::get().execute(&client).unwrap();
+    info!("{:#?}", account);
+
+    assert_eq!(*account.droplet_limit(), 25);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(*requests[0].method(), Method::GET);
+    assert_eq!(
+        requests[0].url().as_str(),
+        "https://api.digitalocean.com/v2/account"
+    );
+    assert_eq!(requests[0].headers()["authorization"], "Bearer token");
+    assert!(requests[0].headers()["user-agent"]
+        .to_str()
+        .unwrap()
+        .starts_with("digitalocean-rs/"));
+    assert_eq!(*requests[0].body(), None);
+}
+
+#[test]
+fn token_can_be_overridden_per_request() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(Method::GET, "/v2/account", StatusCode::OK, account())
+        .respond(Method::GET, "/v2/account", StatusCode::OK, account());
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    client
+        .execute_with_token(Account::get(), "other-token")
+        .unwrap();
+    client.execute(Account::get()).unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].headers()["authorization"], "Bearer other-token");
+    assert_eq!(requests[1].headers()["authorization"], "Bearer token");
+}
+
+#[test]
+fn execute_paged_keeps_links_and_meta() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({
+                "ssh_keys": [ssh_key(1)],
+                "links": {
+                    "pages": {
+                        "next": "https://api.digitalocean.com/v2/account/keys?page=2&per_page=1",
+                        "last": "https://api.digitalocean.com/v2/account/keys?page=2&per_page=1"
+                    }
+                },
+                "meta": { "total": 2 }
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({ "ssh_keys": [ssh_key(2)], "links": {}, "meta": { "total": 2 } }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let page = SshKey::list()
+        .limit(Some(1))
+        .execute_paged(&client)
+        .unwrap();
+    info!("{:#?}", page);
+
+    assert_eq!(page.items().len(), 1);
+    assert_eq!(*page.meta().total(), Some(2));
+    assert_eq!(*page.links().prev(), None);
+    let next = page.links().next().clone().unwrap();
+    assert_eq!(next.query(), Some("page=2&per_page=1"));
+
+    let page = client
+        .execute_paged(Request::<List, Vec<SshKey>>::new(next))
+        .unwrap();
+
+    assert_eq!(*page.items()[0].id(), 2);
+    assert_eq!(*page.links().next(), None);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].url().query(), Some("per_page=1"));
+    assert_eq!(requests[1].url().query(), Some("page=2&per_page=1"));
+}
+
+#[test]
+fn create_sends_json_body() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/account/keys",
+        StatusCode::CREATED,
+        json!({ "ssh_key": ssh_key(512190) }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let key = SshKey::create("foo", "bar").execute(&client).unwrap();
+    info!("{:#?}", key);
+
+    assert_eq!(*key.id(), 512190);
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].headers()["content-type"], "application/json");
+    assert_eq!(
+        *requests[0].body(),
+        Some(json!({
+            "name": "foo",
+            "public_key": "bar",
+        }))
+    );
+}
+
+#[test]
+fn list_follows_pages() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({
+                "ssh_keys": [ssh_key(1)],
+                "links": { "pages": { "next": "https://api.digitalocean.com/v2/account/keys?page=2" } },
+                "meta": { "total": 2 }
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({
+                "ssh_keys": [ssh_key(2)],
+                "links": {},
+                "meta": { "total": 2 }
+            }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let keys = SshKey::list().execute(&client).unwrap();
+    info!("{:#?}", keys);
+
+    assert_eq!(keys.len(), 2);
+    assert_eq!(*keys[1].id(), 2);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].url().query(), Some("page=2"));
+}
+
+#[test]
+fn list_combines_per_page_and_limit() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({
+                "ssh_keys": [ssh_key(1), ssh_key(2)],
+                "links": { "pages": { "next": "https://api.digitalocean.com/v2/account/keys?page=2&per_page=2" } },
+                "meta": { "total": 6 }
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({
+                "ssh_keys": [ssh_key(3), ssh_key(4)],
+                "links": { "pages": { "next": "https://api.digitalocean.com/v2/account/keys?page=3&per_page=2" } },
+                "meta": { "total": 6 }
+            }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let keys = SshKey::list()
+        .per_page(2)
+        .limit(Some(3))
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(keys.len(), 3);
+    assert_eq!(*keys[2].id(), 3);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].url().query(), Some("per_page=2"));
+    assert_eq!(requests[1].url().query(), Some("page=2&per_page=2"));
+
+    // A limit below `per_page` shrinks the only page needed.
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/account/keys",
+        StatusCode::OK,
+        json!({ "ssh_keys": [ssh_key(1)], "links": {}, "meta": { "total": 6 } }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    SshKey::list()
+        .per_page(50)
+        .limit(Some(1))
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(mock.requests()[0].url().query(), Some("per_page=1"));
+}
+
+#[test]
+fn deletions_send_their_body() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::POST,
+            "/v2/firewalls/123/droplets",
+            StatusCode::NO_CONTENT,
+            json!(null),
+        )
+        .respond(
+            Method::DELETE,
+            "/v2/firewalls/123/droplets",
+            StatusCode::NO_CONTENT,
+            json!(null),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    Firewall::get("123")
+        .add_droplets(vec![1])
+        .execute(&client)
+        .unwrap();
+    Firewall::get("123")
+        .remove_droplets(vec![1])
+        .execute(&client)
+        .unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(*requests[1].body(), Some(json!({ "droplet_ids": [1] })));
+    assert_eq!(requests[1].headers()["content-type"], "application/json");
+}
+
+#[test]
+fn status_codes_surface_as_errors() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(Method::GET, "/v2/account", StatusCode::NOT_FOUND, json!(null))
+        .respond(
+            Method::POST,
+            "/v2/account/keys",
+            StatusCode::UNPROCESSABLE_ENTITY,
+            json!({ "id": "unprocessable_entity", "message": "SSH Key is already in use on your account" }),
+        );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let error = Account::get().execute(&client).unwrap_err();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::NotFound) => (),
+        _ => panic!("Unexpected error: {}", error),
+    }
+
+    let error = SshKey::create("foo", "bar").execute(&client).unwrap_err();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Api {
+            status,
+            id,
+            message,
+        }) => {
+            assert_eq!(*status, 422);
+            assert_eq!(id, "unprocessable_entity");
+            assert_eq!(message, "SSH Key is already in use on your account");
+        }
+        _ => panic!("Unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn not_found_is_the_same_for_every_method() {
+    before();
+
+    let not_found = json!({ "id": "not_found", "message": "The resource you were accessing could not be found." });
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::NOT_FOUND,
+            not_found.clone(),
+        )
+        .respond(
+            Method::POST,
+            "/v2/account/keys",
+            StatusCode::NOT_FOUND,
+            not_found.clone(),
+        )
+        .respond(
+            Method::PUT,
+            "/v2/account/keys/1",
+            StatusCode::NOT_FOUND,
+            not_found.clone(),
+        )
+        .respond(
+            Method::DELETE,
+            "/v2/account/keys/1",
+            StatusCode::NOT_FOUND,
+            not_found,
+        );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let errors = vec![
+        SshKey::list().execute(&client).unwrap_err(),
+        SshKey::create("foo", "bar").execute(&client).unwrap_err(),
+        SshKey::update(1, "foo").execute(&client).unwrap_err(),
+        SshKey::delete(1).execute(&client).unwrap_err(),
+    ];
+    for error in errors {
+        match error.downcast_ref::<ErrorKind>() {
+            Some(ErrorKind::NotFound) => (),
+            _ => panic!("Unexpected error: {}", error),
+        }
+    }
+}
+
+#[test]
+fn unexpected_error_bodies_are_kept() {
+    before();
+
+    let mock = MockClient::new().respond_with(
+        Method::GET,
+        "/v2/account",
+        RawResponse::new(
+            StatusCode::BAD_GATEWAY,
+            HeaderMap::new(),
+            b"<html>Bad Gateway</html>".to_vec(),
+        ),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let error = Account::get().execute(&client).unwrap_err();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Api {
+            status,
+            id,
+            message,
+        }) => {
+            assert_eq!(*status, 502);
+            assert_eq!(id, "");
+            assert_eq!(message, "<html>Bad Gateway</html>");
+        }
+        _ => panic!("Unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn deserialize_errors_keep_the_body() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account",
+            StatusCode::OK,
+            json!({ "account": { "droplet_limit": "many" } }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            json!({ "ssh_keys": [{ "id": "x".repeat(10_000) }] }),
+        );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let error = Account::get().execute(&client).unwrap_err();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Deserialize { body, .. }) => {
+            assert_eq!(body, r#"{"account":{"droplet_limit":"many"}}"#);
+        }
+        _ => panic!("Unexpected error: {}", error),
+    }
+
+    let error = SshKey::list().execute(&client).unwrap_err();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Deserialize { body, .. }) => {
+            assert_eq!(body.len(), MAX_DESERIALIZE_BODY + "...".len());
+            assert!(body.starts_with(r#"{"ssh_keys":[{"id":"xxx"#));
+            assert!(body.ends_with("..."));
+        }
+        _ => panic!("Unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn transport_errors_are_returned() {
+    before();
+
+    let mock = MockClient::new().fail(Method::GET, "/v2/account", "connection reset");
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let error = Account::get().execute(&client).unwrap_err();
+    assert_eq!(error.to_string(), "connection reset");
+}
+
+#[test]
+fn unregistered_requests_fail() {
+    before();
+
+    let client = DigitalOcean::with_client("token", MockClient::new()).unwrap();
+
+    assert!(Account::get().execute(&client).is_err());
+}
+
+#[test]
+fn retries_rate_limited_requests() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account",
+            StatusCode::TOO_MANY_REQUESTS,
+            json!(null),
+        )
+        .respond(Method::GET, "/v2/account", StatusCode::OK, account());
+    let client = DigitalOcean::with_client("token", mock.clone())
+        .unwrap()
+        .with_retry(RetryPolicy::new(1, Duration::from_millis(1)));
+
+    assert!(Account::get().execute(&client).is_ok());
+    assert_eq!(mock.requests().len(), 2);
+}
+
+// Fails the first `failures` requests with `kind`, before sending the rest on.
+struct Flaky {
+    mock: MockClient,
+    kind: io::ErrorKind,
+    failures: AtomicUsize,
+}
+
+impl HttpClient for Flaky {
+    fn execute(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+    ) -> Result<RawResponse, Error> {
+        let failures = self.failures.load(Ordering::SeqCst);
+        if failures > 0 {
+            self.failures.store(failures - 1, Ordering::SeqCst);
+            return Err(io::Error::new(self.kind, "flaky network").into());
+        }
+        self.mock.execute(method, url, headers, body)
+    }
+}
+
+#[test]
+fn retries_transient_transport_errors() {
+    before();
+
+    let mock = MockClient::new().respond(Method::GET, "/v2/account", StatusCode::OK, account());
+    let flaky = |kind, failures| Flaky {
+        mock: mock.clone(),
+        kind,
+        failures: AtomicUsize::new(failures),
+    };
+    let policy = RetryPolicy::new(2, Duration::from_millis(1));
+
+    let client = DigitalOcean::with_client("token", flaky(io::ErrorKind::ConnectionReset, 2))
+        .unwrap()
+        .with_retry(policy);
+    assert!(Account::get().execute(&client).is_ok());
+    assert_eq!(mock.requests().len(), 1);
+
+    // Retries run out.
+    let client = DigitalOcean::with_client("token", flaky(io::ErrorKind::ConnectionReset, 3))
+        .unwrap()
+        .with_retry(policy);
+    assert!(Account::get().execute(&client).is_err());
+    assert_eq!(mock.requests().len(), 1);
+
+    // Errors which are not transient are not retried.
+    let client = DigitalOcean::with_client("token", flaky(io::ErrorKind::InvalidInput, 1))
+        .unwrap()
+        .with_retry(policy);
+    let error = Account::get().execute(&client).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<io::Error>().unwrap().kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn retries_creates_only_with_an_idempotency_key() {
+    before();
+
+    let created = json!({ "ssh_key": ssh_key(512190) });
+    let failing = || {
+        MockClient::new()
+            .respond(
+                Method::POST,
+                "/v2/account/keys",
+                StatusCode::INTERNAL_SERVER_ERROR,
+                json!(null),
+            )
+            .respond(
+                Method::POST,
+                "/v2/account/keys",
+                StatusCode::CREATED,
+                created.clone(),
+            )
+    };
+    let policy = RetryPolicy::new(2, Duration::from_millis(1));
+
+    // The server may have created the key before failing.
+    let mock = failing();
+    let client = DigitalOcean::with_client("token", mock.clone())
+        .unwrap()
+        .with_retry(policy);
+    assert!(SshKey::create("foo", "bar").execute(&client).is_err());
+    assert_eq!(mock.requests().len(), 1);
+    assert!(mock.requests()[0]
+        .headers()
+        .get("idempotency-key")
+        .is_none());
+
+    let mock = failing();
+    let client = DigitalOcean::with_client("token", mock.clone())
+        .unwrap()
+        .with_retry(policy);
+    let key = SshKey::create("foo", "bar")
+        .idempotency_key("0d3d1a80-3c7b-4b8e-9a1f-4a2c7e3a9b10")
+        .execute(&client)
+        .unwrap();
+    assert_eq!(*key.id(), 512190);
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    for request in requests.iter() {
+        assert_eq!(
+            request.headers()["idempotency-key"],
+            "0d3d1a80-3c7b-4b8e-9a1f-4a2c7e3a9b10"
+        );
+    }
+
+    // Rate limited creates were not acted on.
+    let mock = MockClient::new()
+        .respond(
+            Method::POST,
+            "/v2/account/keys",
+            StatusCode::TOO_MANY_REQUESTS,
+            json!(null),
+        )
+        .respond(
+            Method::POST,
+            "/v2/account/keys",
+            StatusCode::CREATED,
+            created,
+        );
+    let client = DigitalOcean::with_client("token", mock.clone())
+        .unwrap()
+        .with_retry(policy);
+    assert!(SshKey::create("foo", "bar").execute(&client).is_ok());
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[test]
+fn retries_creates_which_were_not_sent() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/account/keys",
+        StatusCode::CREATED,
+        json!({ "ssh_key": ssh_key(512190) }),
+    );
+    let flaky = |kind| Flaky {
+        mock: mock.clone(),
+        kind,
+        failures: AtomicUsize::new(1),
+    };
+    let policy = RetryPolicy::new(2, Duration::from_millis(1));
+
+    let client = DigitalOcean::with_client("token", flaky(io::ErrorKind::ConnectionRefused))
+        .unwrap()
+        .with_retry(policy);
+    assert!(SshKey::create("foo", "bar").execute(&client).is_ok());
+    assert_eq!(mock.requests().len(), 1);
+
+    // A reset connection may have been acted on.
+    let client = DigitalOcean::with_client("token", flaky(io::ErrorKind::ConnectionReset))
+        .unwrap()
+        .with_retry(policy);
+    assert!(SshKey::create("foo", "bar").execute(&client).is_err());
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/account",
+        StatusCode::FORBIDDEN,
+        json!(null),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone())
+        .unwrap()
+        .with_retry(RetryPolicy::new(3, Duration::from_millis(1)));
+
+    assert!(Account::get().execute(&client).is_err());
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn gives_up_after_max_retries() {
+    before();
+
+    let mut headers = HeaderMap::new();
+    headers.insert("retry-after", "0".parse().unwrap());
+    let mock = MockClient::new().respond_with(
+        Method::GET,
+        "/v2/account",
+        RawResponse::new(StatusCode::SERVICE_UNAVAILABLE, headers, Vec::new()),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone())
+        .unwrap()
+        .with_retry(RetryPolicy::new(2, Duration::from_secs(60)));
+
+    assert!(Account::get().execute(&client).is_err());
+    assert_eq!(mock.requests().len(), 3);
+}
+
+#[test]
+fn requests_are_sent_to_the_base_url() {
+    before();
+
+    let mock = MockClient::new().respond(Method::GET, "/api/v2/account", StatusCode::OK, account());
+    let client = DigitalOcean::with_client("token", mock.clone())
+        .unwrap()
+        .with_base_url(url::Url::parse("http://localhost:8080/api/v2").unwrap());
+
+    assert!(Account::get().execute(&client).is_ok());
+    assert_eq!(
+        mock.requests()[0].url().as_str(),
+        "http://localhost:8080/api/v2/account"
+    );
+}
+
+#[test]
+fn builder_configures_the_client() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/api/v2/account",
+            StatusCode::SERVICE_UNAVAILABLE,
+            json!(null),
+        )
+        .respond(Method::GET, "/api/v2/account", StatusCode::OK, account());
+    let client = DigitalOcean::builder("token")
+        .retry(RetryPolicy::new(1, Duration::from_millis(1)))
+        .base_url("http://localhost:8080/api/v2")
+        .user_agent("my-tool/1.0")
+        .build_with_client(mock.clone())
+        .unwrap();
+
+    assert!(Account::get().execute(&client).is_ok());
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1].url().as_str(),
+        "http://localhost:8080/api/v2/account"
+    );
+    assert_eq!(requests[1].headers()["user-agent"], "my-tool/1.0");
+
+    assert!(DigitalOcean::builder("token")
+        .timeout(Duration::from_secs(30))
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn builder_rejects_invalid_options() {
+    before();
+
+    for builder in [
+        DigitalOcean::builder(""),
+        DigitalOcean::builder("  "),
+        DigitalOcean::builder("token").base_url("not a url"),
+        DigitalOcean::builder("token").user_agent("bad\nagent"),
+        DigitalOcean::builder("token").proxy("http://[::1"),
+    ] {
+        let error = builder.build().err().unwrap();
+        match error.downcast_ref::<ErrorKind>() {
+            Some(ErrorKind::InvalidConfig(_)) => (),
+            _ => panic!("Unexpected error: {:?}", error),
+        }
+    }
+}
+
+#[test]
+fn builder_sends_requests_through_the_proxy() {
+    before();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let proxy = format!("http://{}", listener.local_addr().unwrap());
+    let accepted = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        line
+    });
+
+    let client = DigitalOcean::builder("token")
+        .proxy(proxy)
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    // The proxy hangs up without answering, so the request itself fails.
+    assert!(Account::get().execute(&client).is_err());
+
+    let line = accepted.join().unwrap();
+    assert!(
+        line.starts_with("CONNECT api.digitalocean.com:443"),
+        "{}",
+        line
+    );
+}
+
+#[test]
+fn requests_can_override_the_timeout() {
+    before();
+
+    let page = |id, next: serde_json::Value| {
+        json!({
+            "ssh_keys": [ssh_key(id)],
+            "links": { "pages": { "next": next } },
+            "meta": { "total": 2 }
+        })
+    };
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            page(
+                1,
+                json!("https://api.digitalocean.com/v2/account/keys?page=2"),
+            ),
+        )
+        .respond(
+            Method::GET,
+            "/v2/account/keys",
+            StatusCode::OK,
+            page(2, json!(null)),
+        )
+        .respond(
+            Method::POST,
+            "/v2/account/keys",
+            StatusCode::CREATED,
+            json!({ "ssh_key": ssh_key(3) }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let keys = SshKey::list()
+        .timeout(Duration::from_secs(5))
+        .execute(&client)
+        .unwrap();
+    assert_eq!(keys.len(), 2);
+    SshKey::create("foo", "bar")
+        .timeout(Duration::from_secs(120))
+        .execute(&client)
+        .unwrap();
+    SshKey::create("foo", "bar").execute(&client).unwrap();
+
+    let timeouts = mock
+        .requests()
+        .iter()
+        .map(|request| *request.timeout())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        timeouts,
+        vec![
+            Some(Duration::from_secs(5)),
+            Some(Duration::from_secs(5)),
+            Some(Duration::from_secs(120)),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn request_timeouts_take_precedence_over_the_client() {
+    before();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/v2", listener.local_addr().unwrap());
+    // Accept the connection, but never answer.
+    let accepted = thread::spawn(move || listener.accept().unwrap());
+
+    let client = DigitalOcean::builder("token")
+        .base_url(base_url)
+        .timeout(Duration::from_secs(60))
+        .build()
+        .unwrap();
+    let error = Account::get()
+        .timeout(Duration::from_millis(200))
+        .execute(&client)
+        .unwrap_err();
+
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Timeout) => (),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+    drop(accepted.join().unwrap());
+}
+
+#[test]
+fn validate_rejects_unauthorized_tokens() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(Method::GET, "/v2/account", StatusCode::OK, account())
+        .respond(
+            Method::GET,
+            "/v2/account",
+            StatusCode::UNAUTHORIZED,
+            json!({ "id": "unauthorized", "message": "Unable to authenticate you." }),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    assert!(client.validate().is_ok());
+
+    let error = client.validate().err().unwrap();
+    match error.downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::Unauthorized) => (),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+    assert_eq!(mock.requests().len(), 2);
+}
+
+fn droplet_spec_client(image: StatusCode) -> DigitalOcean<MockClient> {
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/regions",
+            StatusCode::OK,
+            json!({
+                "regions": [
+                    {
+                        "slug": "nyc3",
+                        "name": "New York 3",
+                        "sizes": ["s-1vcpu-1gb"],
+                        "available": true,
+                        "features": [],
+                    },
+                    {
+                        "slug": "nyc2",
+                        "name": "New York 2",
+                        "sizes": [],
+                        "available": false,
+                        "features": [],
+                    },
+                ],
+                "links": {},
+                "meta": { "total": 2 },
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/sizes",
+            StatusCode::OK,
+            json!({
+                "sizes": [
+                    {
+                        "slug": "s-1vcpu-1gb",
+                        "memory": 1024,
+                        "vcpus": 1,
+                        "disk": 25,
+                        "transfer": 1.0,
+                        "price_monthly": 5.0,
+                        "price_hourly": 0.00744,
+                        "regions": ["nyc3"],
+                        "available": true,
+                    },
+                    {
+                        "slug": "s-8vcpu-16gb",
+                        "memory": 16384,
+                        "vcpus": 8,
+                        "disk": 320,
+                        "transfer": 6.0,
+                        "price_monthly": 80.0,
+                        "price_hourly": 0.11905,
+                        "regions": ["sfo3"],
+                        "available": true,
+                    },
+                ],
+                "links": {},
+                "meta": { "total": 2 },
+            }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/images/ubuntu-20-04-x64",
+            image,
+            json!({
+                "image": {
+                    "id": 63663980,
+                    "name": "20.04 (LTS) x64",
+                    "type": "base",
+                    "distribution": "Ubuntu",
+                    "slug": "ubuntu-20-04-x64",
+                    "public": true,
+                    "regions": ["nyc3"],
+                    "min_disk_size": 15,
+                    "size_gigabytes": 2.36,
+                    "created_at": "2020-05-15T05:47:50Z",
+                },
+            }),
+        );
+    DigitalOcean::with_client("token", mock).unwrap()
+}
+
+#[test]
+fn validate_droplet_spec_cross_checks_listings() {
+    before();
+
+    let client = droplet_spec_client(StatusCode::OK);
+    assert!(client
+        .validate_droplet_spec("nyc3", "s-1vcpu-1gb", "ubuntu-20-04-x64")
+        .is_ok());
+
+    let cases = [
+        (
+            "ams9",
+            "s-1vcpu-1gb",
+            StatusCode::OK,
+            "region ams9 does not exist",
+        ),
+        (
+            "nyc2",
+            "s-1vcpu-1gb",
+            StatusCode::OK,
+            "region nyc2 does not accept new droplets",
+        ),
+        (
+            "nyc3",
+            "s-2vcpu-2gb",
+            StatusCode::OK,
+            "size s-2vcpu-2gb does not exist",
+        ),
+        (
+            "nyc3",
+            "s-8vcpu-16gb",
+            StatusCode::OK,
+            "size s-8vcpu-16gb is not available in region nyc3",
+        ),
+        (
+            "nyc3",
+            "s-1vcpu-1gb",
+            StatusCode::NOT_FOUND,
+            "image ubuntu-20-04-x64 does not exist",
+        ),
+    ];
+    for (region, size, image, message) in cases {
+        let error = droplet_spec_client(image)
+            .validate_droplet_spec(region, size, "ubuntu-20-04-x64")
+            .unwrap_err();
+        match error.downcast_ref::<ErrorKind>() {
+            Some(ErrorKind::InvalidDropletSpec(found)) => assert_eq!(found, message),
+            _ => panic!("Unexpected error: {:?}", error),
+        }
+    }
+}
+
+#[test]
+fn deletes_succeed_without_a_body() {
+    before();
+
+    const ID: &str = "4de7ac8b-495b-4884-9a69-1050c6793cd6";
+    let requests: Vec<Request<Delete, ()>> = vec![
+        AlertPolicy::delete(ID),
+        CdnEndpoint::delete(ID),
+        Certificate::delete(ID),
+        DatabaseCluster::delete(ID),
+        Domain::delete("example.com"),
+        Domain::get("example.com").record(1).delete(),
+        Droplet::delete(1),
+        Droplet::delete_by_tag("web"),
+        Firewall::delete(ID),
+        FloatingIp::delete([45, 55, 96, 47]),
+        Image::delete(1),
+        KubernetesCluster::delete(ID),
+        LoadBalancer::delete(ID),
+        Project::delete(ID),
+        Registry::delete(),
+        ReservedIp::delete([45, 55, 96, 47]),
+        Snapshot::delete(1),
+        SpacesKey::delete(ID),
+        SshKey::delete(1),
+        Tag::delete("web"),
+        UptimeCheck::delete(ID),
+        Volume::delete(ID),
+        Vpc::delete(ID),
+    ];
+
+    for request in requests {
+        let path = request.url().path().to_string();
+        let mock = MockClient::new().respond(
+            Method::DELETE,
+            path.as_str(),
+            StatusCode::NO_CONTENT,
+            json!(null),
+        );
+        let client = DigitalOcean::with_client("token", mock).unwrap();
+
+        if let Err(error) = request.execute(&client) {
+            panic!("DELETE {} failed: {:?}", path, error);
+        }
+    }
+
+    let mock = MockClient::new().respond(
+        Method::DELETE,
+        "/v2/droplets/1",
+        StatusCode::ACCEPTED,
+        json!(null),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+    assert!(Droplet::delete(1).execute(&client).is_ok());
+}
+
+#[test]
+fn empty_results_ignore_the_body() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::POST,
+            "/v2/tags/web/resources",
+            StatusCode::NO_CONTENT,
+            json!(null),
+        )
+        .respond(
+            Method::POST,
+            "/v2/tags/web/resources",
+            StatusCode::NO_CONTENT,
+            json!({ "unexpected": true }),
+        );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    for _ in 0..2 {
+        let req: Request<Create, ()> = Tag::get("web").add_resources(vec![("1", "droplet")]);
+        assert!(req.execute(&client).is_ok());
+    }
+}
+
+#[test]
+fn execute_batch_keeps_the_order_of_requests() {
+    before();
+
+    let mut mock = MockClient::new();
+    for id in 1..=5 {
+        mock = mock.respond(
+            Method::GET,
+            format!("/v2/account/keys/{}", id),
+            StatusCode::OK,
+            json!({ "ssh_key": ssh_key(id) }),
+        );
+    }
+    let mock = mock.respond(
+        Method::GET,
+        "/v2/account/keys/6",
+        StatusCode::NOT_FOUND,
+        json!({ "id": "not_found", "message": "The resource you were accessing could not be found." }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let results = client.execute_batch((1..=6).map(SshKey::get), 2);
+
+    assert_eq!(results.len(), 6);
+    for (index, result) in results[..5].iter().enumerate() {
+        assert_eq!(*result.as_ref().unwrap().id(), index + 1);
+    }
+    match results[5].as_ref().unwrap_err().downcast_ref::<ErrorKind>() {
+        Some(ErrorKind::NotFound) => (),
+        _ => panic!("Unexpected result: {:?}", results[5]),
+    }
+    assert_eq!(mock.requests().len(), 6);
+
+    let results = client.execute_batch(Vec::<Request<Delete, ()>>::new(), 0);
+    assert!(results.is_empty());
+}
+
+#[test]
+fn token_is_never_printed() {
+    before();
+
+    let token = "dop_v1_4fcd60c9fd2c3ce1dbbe4b9e5a8e4d5b";
+    let mock = MockClient::new()
+        .respond(
+            Method::GET,
+            "/v2/account",
+            StatusCode::UNAUTHORIZED,
+            json!({ "id": "unauthorized", "message": format!("Unknown token {}", token) }),
+        )
+        .respond(
+            Method::GET,
+            "/v2/account",
+            StatusCode::OK,
+            json!({ "account": { "droplet_limit": token } }),
+        );
+    let client = DigitalOcean::with_client(token, mock.clone()).unwrap();
+
+    let debug = format!("{:?}", client);
+    assert!(debug.contains("token: \"***\""), "{}", debug);
+    assert!(!debug.contains(token));
+    let debug = format!("{:?}", DigitalOcean::builder(token));
+    assert!(!debug.contains(token));
+
+    for _ in 0..2 {
+        let error = Account::get().execute(&client).unwrap_err();
+        for output in [format!("{}", error), format!("{:?}", error)] {
+            assert!(!output.contains(token), "{}", output);
+        }
+    }
+    assert!(!format!("{:?}", mock.requests()).contains(token));
+}
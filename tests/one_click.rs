@@ -0,0 +1,110 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::{OneClick, OneClickInstall};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, List};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/1-clicks";
+
+    let req: Request<List, Vec<OneClick>> = OneClick::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn list_filter_type_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/1-clicks?type=kubernetes";
+
+    let req: Request<List, Vec<OneClick>> = OneClick::list().filter_type("kubernetes");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn install_kubernetes_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/1-clicks/kubernetes";
+    let cluster_uuid = "50a994b6-c303-438f-9495-7e896cfe6b08";
+
+    let req: Request<Create, OneClickInstall> =
+        OneClick::install_kubernetes(cluster_uuid, &["wordpress", "monitoring"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "addon_slugs": ["wordpress", "monitoring"],
+            "cluster_uuid": cluster_uuid,
+        })
+    );
+}
+
+#[test]
+fn list_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/1-clicks",
+        StatusCode::OK,
+        json!({
+            "1_clicks": [
+                { "slug": "monitoring", "type": "kubernetes" },
+                { "slug": "wordpress-18-04", "type": "droplet" },
+            ],
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let one_clicks = OneClick::list().execute(&client).unwrap();
+
+    assert_eq!(one_clicks.len(), 2);
+    assert_eq!(one_clicks[0].slug(), "monitoring");
+    assert_eq!(one_clicks[1].kind(), "droplet");
+}
+
+#[test]
+fn install_kubernetes_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/1-clicks/kubernetes",
+        StatusCode::OK,
+        json!({ "message": "Successfully kicked off addon job." }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let install =
+        OneClick::install_kubernetes("50a994b6-c303-438f-9495-7e896cfe6b08", &["monitoring"])
+            .execute(&client)
+            .unwrap();
+
+    assert_eq!(install.message(), "Successfully kicked off addon job.");
+}
@@ -0,0 +1,202 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::{Project, ProjectResource};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/projects";
+
+    let req: Request<List, Vec<Project>> = Project::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let project_id = "4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679";
+    let correct_url = format!("https://api.digitalocean.com/v2/projects/{}", project_id);
+
+    let req: Request<Get, Project> = Project::get(project_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn default_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/projects/default";
+
+    let req: Request<Get, Project> = Project::default();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/projects";
+
+    let req: Request<Create, Project> = Project::create(
+        "my-web-api",
+        "Service or API",
+        "My website API",
+        "Production",
+    );
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "my-web-api",
+            "purpose": "Service or API",
+            "description": "My website API",
+            "environment": "Production",
+        })
+    );
+}
+
+#[test]
+fn update_produces_correct_request() {
+    before();
+
+    let project_id = "4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679";
+    let correct_url = format!("https://api.digitalocean.com/v2/projects/{}", project_id);
+
+    let req: Request<Update, Project> = Project::update(
+        project_id,
+        "my-web-api",
+        "Service or API",
+        "My website API",
+        "Staging",
+        false,
+    );
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "my-web-api",
+            "purpose": "Service or API",
+            "description": "My website API",
+            "environment": "Staging",
+            "is_default": false,
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let project_id = "4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679";
+    let correct_url = format!("https://api.digitalocean.com/v2/projects/{}", project_id);
+
+    let req: Request<Delete, ()> = Project::delete(project_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn resources_produces_correct_request() {
+    before();
+
+    let project_id = "4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/projects/{}/resources",
+        project_id
+    );
+
+    let req: Request<List, Vec<ProjectResource>> = Project::get(project_id).resources();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn assign_resources_produces_correct_request() {
+    before();
+
+    let project_id = "4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/projects/{}/resources",
+        project_id
+    );
+
+    let req: Request<Create, Vec<ProjectResource>> = Project::get(project_id)
+        .assign_resources(&["do:droplet:13457723", "do:domain:example.com"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "resources": ["do:droplet:13457723", "do:domain:example.com"],
+        })
+    );
+}
+
+#[test]
+fn assign_resources_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/projects/4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679/resources",
+        StatusCode::OK,
+        json!({
+            "resources": [
+                {
+                    "urn": "do:droplet:13457723",
+                    "assigned_at": "2018-09-28T19:26:37Z",
+                    "links": {
+                        "self": "https://api.digitalocean.com/v2/droplets/13457723",
+                    },
+                    "status": "ok",
+                },
+            ],
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let resources = Project::get("4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679")
+        .assign_resources(&["do:droplet:13457723"])
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(resources.len(), 1);
+    assert_eq!(resources[0].urn(), "do:droplet:13457723");
+    assert_eq!(resources[0].status(), "ok");
+}
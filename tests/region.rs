@@ -1,6 +1,7 @@
 extern crate digitalocean;
 #[macro_use]
 extern crate log;
+#[macro_use]
 extern crate serde_json;
 extern crate url;
 extern crate url_serde;
@@ -10,7 +11,9 @@ mod utils;
 use serde_json::Value;
 
 use digitalocean::api::Region;
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::List;
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -27,3 +30,60 @@ fn list_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn list_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/regions",
+        StatusCode::OK,
+        json!({
+            "regions": [
+                {
+                    "slug": "nyc3",
+                    "name": "New York 3",
+                    "sizes": ["s-1vcpu-1gb", "s-1vcpu-2gb"],
+                    "available": true,
+                    "features": ["private_networking", "backups", "ipv6"],
+                },
+            ],
+            "links": {},
+            "meta": { "total": 1 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let regions = Region::list().execute(&client).unwrap();
+
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].slug(), "nyc3");
+    assert_eq!(regions[0].to_string(), "nyc3");
+    assert_eq!(regions[0].name(), "New York 3");
+    assert_eq!(regions[0].sizes().len(), 2);
+    assert!(regions[0].available());
+    assert!(regions[0].features().contains(&"ipv6".to_string()));
+}
+
+#[test]
+fn supports_size_checks_availability() {
+    before();
+
+    let mut region = json!({
+        "slug": "nyc3",
+        "name": "New York 3",
+        "sizes": ["s-1vcpu-1gb"],
+        "available": true,
+        "features": [],
+    });
+    let available: Region = serde_json::from_value(region.clone()).unwrap();
+
+    assert!(available.supports_size("s-1vcpu-1gb"));
+    assert!(!available.supports_size("s-8vcpu-16gb"));
+
+    region["available"] = json!(false);
+    let unavailable: Region = serde_json::from_value(region).unwrap();
+
+    assert!(!unavailable.supports_size("s-1vcpu-1gb"));
+}
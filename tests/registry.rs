@@ -0,0 +1,178 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::{
+    DockerCredentials, GarbageCollection, Registry, Repository, RepositoryTag,
+};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::{assert_timestamp, before};
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/registry";
+
+    let req: Request<Get, Registry> = Registry::get();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/registry";
+
+    let req: Request<Create, Registry> = Registry::create("example", "basic").region("fra1");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "example",
+            "subscription_tier_slug": "basic",
+            "region": "fra1",
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/registry";
+
+    let req: Request<Delete, ()> = Registry::delete();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn docker_credentials_produces_correct_request() {
+    before();
+
+    let req: Request<Get, DockerCredentials> = Registry::get().docker_credentials(true);
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/registry/docker-credentials?read_write=true"
+    );
+
+    let req: Request<Get, DockerCredentials> = Registry::get()
+        .docker_credentials(false)
+        .expiry_seconds(3600);
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/registry/docker-credentials?read_write=false&expiry_seconds=3600"
+    );
+}
+
+#[test]
+fn docker_credentials_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/registry/docker-credentials",
+        StatusCode::OK,
+        json!({
+            "auths": {
+                "registry.digitalocean.com": {
+                    "auth": "YjdkMDNhNjk0N2IyMTdlZmI2ZjNlYzNiZDM1MDQ1ODI6YjdkMDNhNjk0N2IyMTdlZmI2ZjNlYzNiZDM1MDQ1ODIK",
+                },
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let credentials = Registry::get()
+        .docker_credentials(false)
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(
+        credentials.auths()["registry.digitalocean.com"].auth,
+        "YjdkMDNhNjk0N2IyMTdlZmI2ZjNlYzNiZDM1MDQ1ODI6YjdkMDNhNjk0N2IyMTdlZmI2ZjNlYzNiZDM1MDQ1ODIK"
+    );
+}
+
+#[test]
+fn repositories_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/registry/example/repositoriesV2";
+
+    let req: Request<List, Vec<Repository>> = Registry::repositories("example");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn tags_produces_correct_request() {
+    before();
+
+    let correct_url =
+        "https://api.digitalocean.com/v2/registry/example/repositories/team%2Frepo/tags";
+
+    let req: Request<List, Vec<RepositoryTag>> = Registry::tags("example", "team/repo");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn garbage_collection_sends_no_body() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/registry/example/garbage-collection",
+        StatusCode::CREATED,
+        json!({
+            "garbage_collection": {
+                "uuid": "eff0feee-49c7-4e8f-ba5c-a320c109c8a8",
+                "registry_name": "example",
+                "status": "requested",
+                "created_at": "2020-10-30T21:03:24Z",
+                "updated_at": "2020-10-30T21:03:44Z",
+                "blobs_deleted": 42,
+                "freed_bytes": 667,
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let req: Request<Create, GarbageCollection> = Registry::garbage_collection("example");
+    let garbage_collection = req.execute(&client).unwrap();
+
+    assert_eq!(garbage_collection.status(), "requested");
+    assert_eq!(*garbage_collection.blobs_deleted(), 42);
+    assert_timestamp(garbage_collection.created_at(), "2020-10-30T21:03:24Z");
+    assert_timestamp(garbage_collection.updated_at(), "2020-10-30T21:03:44Z");
+    assert_eq!(*mock.requests()[0].body(), None);
+}
@@ -0,0 +1,121 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::{Droplet, SshKey, Tag};
+use digitalocean::client::Method;
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn requests_can_be_inspected() {
+    before();
+
+    let req: Request<Create, Droplet> =
+        Droplet::create("bear", "tor1", "5gb", "ubuntu-14-04-x64").tags(vec!["web"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.http_method(), Method::POST);
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/droplets"
+    );
+    assert_eq!(req.body()["tags"], json!(["web"]));
+
+    let req: Request<List, Vec<Droplet>> = Droplet::list_by_tag("web");
+    assert_eq!(req.http_method(), Method::GET);
+    assert_eq!(req.url().query(), Some("tag_name=web"));
+    assert_eq!(*req.body(), Value::Null);
+
+    let req: Request<Get, Tag> = Tag::get("web");
+    assert_eq!(req.http_method(), Method::GET);
+
+    let req: Request<Update, SshKey> = SshKey::update(1, "renamed");
+    assert_eq!(req.http_method(), Method::PUT);
+
+    let req: Request<Delete, ()> = Droplet::delete(1);
+    assert_eq!(req.http_method(), Method::DELETE);
+}
+
+#[test]
+fn dry_run_describes_the_call_without_sending_it() {
+    before();
+
+    let dry_run = Droplet::create("bear", "tor1", "5gb", "ubuntu-14-04-x64").dry_run();
+    info!("{:#?}", dry_run);
+
+    assert_eq!(*dry_run.method(), Method::POST);
+    assert_eq!(
+        dry_run.url().as_str(),
+        "https://api.digitalocean.com/v2/droplets"
+    );
+    assert_eq!(dry_run.headers()["content-type"], "application/json");
+    assert_eq!(dry_run.headers()["authorization"], "Bearer ***");
+    assert!(dry_run.headers()["user-agent"]
+        .to_str()
+        .unwrap()
+        .starts_with("digitalocean-rs/"));
+    assert_eq!(dry_run.body().as_ref().unwrap()["name"], json!("bear"));
+
+    let dry_run = Droplet::list_by_tag("web").dry_run();
+    assert_eq!(*dry_run.method(), Method::GET);
+    assert_eq!(dry_run.url().query(), Some("tag_name=web&per_page=200"));
+    assert_eq!(dry_run.headers()["authorization"], "Bearer ***");
+    assert!(dry_run.headers().contains_key("user-agent"));
+    assert!(!dry_run.headers().contains_key("content-type"));
+    assert_eq!(*dry_run.body(), None);
+
+    let dry_run = Droplet::delete(1).dry_run();
+    assert_eq!(*dry_run.method(), Method::DELETE);
+    assert_eq!(*dry_run.body(), None);
+}
+
+#[test]
+fn per_page_is_clamped_and_replaced() {
+    before();
+
+    let req: Request<List, Vec<Droplet>> = Droplet::list_by_tag("web").per_page(50);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().query(), Some("tag_name=web&per_page=50"));
+    assert_eq!(
+        req.dry_run().url().query(),
+        Some("tag_name=web&per_page=50")
+    );
+
+    let req = req.per_page(1000);
+    assert_eq!(req.url().query(), Some("tag_name=web&per_page=200"));
+
+    let req = req.per_page(0);
+    assert_eq!(req.url().query(), Some("tag_name=web&per_page=1"));
+}
+
+#[test]
+fn query_appends_parameters() {
+    before();
+
+    let req: Request<List, Vec<Droplet>> = Droplet::list_by_tag("web")
+        .query("sort", "created_at")
+        .query("order", "desc & newest")
+        .query("sort", "name");
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().query(),
+        Some("tag_name=web&sort=created_at&order=desc+%26+newest&sort=name")
+    );
+    assert_eq!(
+        req.dry_run().url().query(),
+        Some("tag_name=web&sort=created_at&order=desc+%26+newest&sort=name&per_page=200")
+    );
+}
@@ -0,0 +1,139 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use digitalocean::api::ReservedIp;
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/reserved_ips";
+
+    let req: Request<List, Vec<ReservedIp>> = ReservedIp::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn for_droplet_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/reserved_ips";
+    let droplet_id = 123;
+
+    let req: Request<Create, ReservedIp> = ReservedIp::for_droplet(droplet_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "droplet_id": droplet_id,
+        })
+    );
+}
+
+#[test]
+fn for_region_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/reserved_ips";
+    let region_id = "tor1";
+
+    let req: Request<Create, ReservedIp> = ReservedIp::for_region(region_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "region": region_id,
+        })
+    );
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let reserved_ip = IpAddr::from_str("192.168.0.1").unwrap();
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/reserved_ips/{}",
+        reserved_ip
+    );
+
+    let req: Request<Get, ReservedIp> = ReservedIp::get(reserved_ip);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let reserved_ip = IpAddr::from_str("192.168.0.1").unwrap();
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/reserved_ips/{}",
+        reserved_ip
+    );
+
+    let req: Request<Delete, ()> = ReservedIp::delete(reserved_ip);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn for_region_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/reserved_ips",
+        StatusCode::ACCEPTED,
+        json!({
+            "reserved_ip": {
+                "ip": "45.55.96.47",
+                "droplet": null,
+                "region": {
+                    "name": "New York 3",
+                    "slug": "nyc3",
+                    "sizes": ["s-1vcpu-1gb"],
+                    "features": ["metadata"],
+                    "available": true,
+                },
+                "locked": false,
+            },
+            "links": {},
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let reserved_ip = ReservedIp::for_region("nyc3").execute(&client).unwrap();
+
+    assert_eq!(*reserved_ip.ip(), IpAddr::from_str("45.55.96.47").unwrap());
+    assert!(reserved_ip.droplet().is_none());
+    assert_eq!(reserved_ip.region().slug(), "nyc3");
+}
@@ -0,0 +1,163 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use digitalocean::api::{Action, ActionStatus, ReservedIp};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Get, List};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+use digitalocean::wait::WaitPolicy;
+use std::time::Duration;
+
+use crate::utils::before;
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let reserved_ip = IpAddr::from_str("192.168.0.1").unwrap();
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/reserved_ips/{}/actions",
+        reserved_ip
+    );
+
+    let req: Request<List, Vec<Action>> = ReservedIp::get(reserved_ip).actions();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let reserved_ip = IpAddr::from_str("192.168.0.1").unwrap();
+    let action_id = 123;
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/reserved_ips/{}/actions/{}",
+        reserved_ip, action_id
+    );
+
+    let req: Request<Get, Action> = ReservedIp::get(reserved_ip).action(action_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn assign_produces_correct_request() {
+    before();
+
+    let reserved_ip = IpAddr::from_str("192.168.0.1").unwrap();
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/reserved_ips/{}/actions",
+        reserved_ip
+    );
+    let droplet_id = 123;
+
+    let req: Request<Create, Action> = ReservedIp::get(reserved_ip).assign(droplet_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "assign",
+            "droplet_id": droplet_id
+        })
+    );
+}
+
+#[test]
+fn unassign_produces_correct_request() {
+    before();
+
+    let reserved_ip = IpAddr::from_str("192.168.0.1").unwrap();
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/reserved_ips/{}/actions",
+        reserved_ip
+    );
+
+    let req: Request<Create, Action> = ReservedIp::get(reserved_ip).unassign();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "type": "unassign",
+        })
+    );
+}
+
+fn action(status: &str) -> Value {
+    json!({
+        "action": {
+            "id": 72531856,
+            "status": status,
+            "type": "assign_ip",
+            "started_at": "2015-11-12T17:51:03Z",
+            "completed_at": null,
+            "resource_id": 758604968,
+            "resource_type": "reserved_ip",
+            "region_slug": "nyc3",
+        }
+    })
+}
+
+#[test]
+fn assign_can_be_confirmed() {
+    before();
+
+    let mock = MockClient::new()
+        .respond(
+            Method::POST,
+            "/v2/reserved_ips/45.55.96.47/actions",
+            StatusCode::CREATED,
+            action("in-progress"),
+        )
+        .respond(
+            Method::GET,
+            "/v2/reserved_ips/45.55.96.47/actions/72531856",
+            StatusCode::OK,
+            action("in-progress"),
+        )
+        .respond(
+            Method::GET,
+            "/v2/actions/72531856",
+            StatusCode::OK,
+            action("completed"),
+        );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let ip = IpAddr::from_str("45.55.96.47").unwrap();
+    let assigned = ReservedIp::get(ip).assign(123).execute(&client).unwrap();
+
+    let polled = ReservedIp::get(ip)
+        .action(*assigned.id())
+        .execute(&client)
+        .unwrap();
+    assert_eq!(*polled.status(), ActionStatus::InProgress);
+
+    let completed = polled
+        .wait(
+            &client,
+            WaitPolicy::new(Duration::from_millis(1), Duration::from_secs(5)),
+        )
+        .unwrap();
+    assert_eq!(*completed.status(), ActionStatus::Completed);
+    assert_eq!(mock.requests().len(), 3);
+}
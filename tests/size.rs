@@ -1,6 +1,7 @@
 extern crate digitalocean;
 #[macro_use]
 extern crate log;
+#[macro_use]
 extern crate serde_json;
 extern crate url;
 extern crate url_serde;
@@ -9,8 +10,10 @@ mod utils;
 
 use serde_json::Value;
 
-use digitalocean::api::Size;
+use digitalocean::api::{Amount, Size};
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::List;
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -27,3 +30,100 @@ fn list_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn list_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/sizes",
+        StatusCode::OK,
+        json!({
+            "sizes": [
+                {
+                    "slug": "s-1vcpu-1gb",
+                    "memory": 1024,
+                    "vcpus": 1,
+                    "disk": 25,
+                    "transfer": 1.0,
+                    "price_monthly": 5.0,
+                    "price_hourly": 0.00744,
+                    "regions": ["nyc1", "nyc3"],
+                    "available": true,
+                },
+            ],
+            "links": {},
+            "meta": { "total": 1 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let sizes = Size::list().execute(&client).unwrap();
+
+    assert_eq!(sizes.len(), 1);
+    assert_eq!(sizes[0].slug(), "s-1vcpu-1gb");
+    assert_eq!(sizes[0].to_string(), "s-1vcpu-1gb");
+    assert_eq!(*sizes[0].memory(), 1024);
+    assert_eq!(*sizes[0].vcpus(), 1);
+    assert_eq!(*sizes[0].disk(), 25);
+    assert_eq!(*sizes[0].price_monthly(), 5.0);
+    assert_eq!(*sizes[0].price_hourly(), 0.00744);
+    assert_eq!(sizes[0].regions().len(), 2);
+}
+
+#[test]
+fn prices_convert_to_exact_amounts() {
+    before();
+
+    let size: Size = serde_json::from_value(json!({
+        "slug": "s-1vcpu-1gb",
+        "memory": 1024,
+        "vcpus": 1,
+        "disk": 25,
+        "transfer": 1.0,
+        "price_monthly": 5.0,
+        "price_hourly": 0.00744,
+        "regions": ["nyc1", "nyc3"],
+        "available": true,
+    }))
+    .unwrap();
+
+    assert_eq!(size.price_monthly_cents(), 500);
+    assert_eq!(size.price_monthly_amount().to_string(), "5.00");
+    assert_eq!(size.price_hourly_amount().micros(), 7_440);
+
+    // Exact amounts can be summed without accumulating rounding errors.
+    let hours = 300;
+    let exact = (0..hours)
+        .map(|_| size.price_hourly_amount().micros())
+        .sum::<i64>();
+    assert_eq!(exact, 2_232_000);
+    assert_eq!(Amount::from_micros(exact).cents(), 223);
+}
+
+#[test]
+fn long_fraction_prices_round_to_micros() {
+    before();
+
+    // The API returns some hourly prices with more places than an `Amount`
+    // keeps.
+    let size: Size = serde_json::from_value(json!({
+        "slug": "s-1vcpu-1gb",
+        "memory": 1024,
+        "vcpus": 1,
+        "disk": 25,
+        "transfer": 1.0,
+        "price_monthly": 5.0,
+        "price_hourly": 0.00743999984115362,
+        "regions": ["nyc1", "nyc3"],
+        "available": true,
+    }))
+    .unwrap();
+
+    assert_eq!(size.price_hourly_amount().micros(), 7_440);
+    assert_eq!(size.price_hourly_amount().to_string(), "0.00744");
+
+    let price: Amount = serde_json::from_value(json!(0.00743999984115362)).unwrap();
+    assert_eq!(price, size.price_hourly_amount());
+}
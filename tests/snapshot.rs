@@ -1,6 +1,7 @@
 extern crate digitalocean;
 #[macro_use]
 extern crate log;
+#[macro_use]
 extern crate serde_json;
 extern crate url;
 extern crate url_serde;
@@ -10,7 +11,9 @@ mod utils;
 use serde_json::Value;
 
 use digitalocean::api::Snapshot;
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::{Delete, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -81,3 +84,95 @@ fn delete_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn list_by_resource_type_produces_correct_request() {
+    before();
+
+    let req: Request<List, Vec<Snapshot>> = Snapshot::list_droplet_snapshots();
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/snapshots?resource_type=droplet"
+    );
+
+    let req: Request<List, Vec<Snapshot>> = Snapshot::list_volume_snapshots();
+    info!("{:#?}", req);
+
+    assert_eq!(
+        req.url().as_str(),
+        "https://api.digitalocean.com/v2/snapshots?resource_type=volume"
+    );
+}
+
+#[test]
+fn volume_snapshot_produces_correct_request() {
+    before();
+
+    let snapshot_id = "fbe805e8-866b-11e6-96bf-000f53315a41";
+    let correct_url = format!("https://api.digitalocean.com/v2/snapshots/{}", snapshot_id);
+
+    let req: Request<Get, Snapshot> = Snapshot::get(snapshot_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+
+    let req: Request<Delete, ()> = Snapshot::delete(snapshot_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+}
+
+#[test]
+fn list_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/snapshots",
+        StatusCode::OK,
+        json!({
+            "snapshots": [
+                {
+                    "id": "6372321",
+                    "name": "web-01-1595954862243",
+                    "created_at": "2020-07-28T16:47:44Z",
+                    "regions": ["nyc3", "sfo3"],
+                    "resource_id": "200776916",
+                    "resource_type": "droplet",
+                    "min_disk_size": 25,
+                    "size_gigabytes": 2.34,
+                    "tags": ["web", "env:prod"],
+                },
+                {
+                    "id": "fbe805e8-866b-11e6-96bf-000f53315a41",
+                    "name": "pvc-01-1595954862243",
+                    "created_at": "2019-09-28T23:14:30Z",
+                    "regions": ["nyc1"],
+                    "resource_id": "89bcc42f-85cf-11e9-a7b5-0a58ac14a1d3",
+                    "resource_type": "volume",
+                    "min_disk_size": 2,
+                    "size_gigabytes": 0.1008,
+                    "tags": ["k8s"],
+                },
+            ],
+            "links": {},
+            "meta": { "total": 2 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let snapshots = Snapshot::list().execute(&client).unwrap();
+
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(*snapshots[0].min_disk_size(), 25);
+    assert_eq!(*snapshots[0].size_gigabytes(), 2.34);
+    assert_eq!(snapshots[0].regions(), &vec!["nyc3", "sfo3"]);
+    assert_eq!(snapshots[1].resource_type(), "volume");
+    assert_eq!(
+        snapshots[0].created_at().to_rfc3339(),
+        "2020-07-28T16:47:44+00:00"
+    );
+    assert!(snapshots[1].created_at() < snapshots[0].created_at());
+}
@@ -0,0 +1,168 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::spaces_key_fields::Grant;
+use digitalocean::api::SpacesKey;
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::{assert_timestamp, before};
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/spaces/keys";
+
+    let req: Request<List, Vec<SpacesKey>> = SpacesKey::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let access_key = "DOACCESSKEYEXAMPLE";
+    let correct_url = format!("https://api.digitalocean.com/v2/spaces/keys/{}", access_key);
+
+    let req: Request<Get, SpacesKey> = SpacesKey::get(access_key);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/spaces/keys";
+
+    let req: Request<Create, SpacesKey> = SpacesKey::create("backups")
+        .grant(("my-bucket", "readwrite"))
+        .grant(Grant::new("logs", "read"));
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "backups",
+            "grants": [
+                { "bucket": "my-bucket", "permission": "readwrite" },
+                { "bucket": "logs", "permission": "read" },
+            ],
+        })
+    );
+}
+
+#[test]
+fn update_produces_correct_request() {
+    before();
+
+    let access_key = "DOACCESSKEYEXAMPLE";
+    let correct_url = format!("https://api.digitalocean.com/v2/spaces/keys/{}", access_key);
+
+    let req: Request<Update, SpacesKey> =
+        SpacesKey::update(access_key, "backups").grant(("", "fullaccess"));
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "backups",
+            "grants": [
+                { "bucket": "", "permission": "fullaccess" },
+            ],
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let access_key = "DOACCESSKEYEXAMPLE";
+    let correct_url = format!("https://api.digitalocean.com/v2/spaces/keys/{}", access_key);
+
+    let req: Request<Delete, ()> = SpacesKey::delete(access_key);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_parses_secret_key() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::POST,
+        "/v2/spaces/keys",
+        StatusCode::CREATED,
+        json!({
+            "key": {
+                "name": "backups",
+                "access_key": "DOACCESSKEYEXAMPLE",
+                "secret_key": "secretkeyexample",
+                "grants": [
+                    { "bucket": "my-bucket", "permission": "readwrite" },
+                ],
+                "created_at": "2024-03-01T12:00:00Z",
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let key = SpacesKey::create("backups")
+        .grant(("my-bucket", "readwrite"))
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(key.access_key(), "DOACCESSKEYEXAMPLE");
+    assert_eq!(key.secret_key().as_deref(), Some("secretkeyexample"));
+    assert_eq!(key.grants()[0].bucket, "my-bucket");
+}
+
+#[test]
+fn get_parses_without_secret_key() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/spaces/keys/DOACCESSKEYEXAMPLE",
+        StatusCode::OK,
+        json!({
+            "key": {
+                "name": "backups",
+                "access_key": "DOACCESSKEYEXAMPLE",
+                "grants": [],
+                "created_at": "2024-03-01T12:00:00Z",
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let key = SpacesKey::get("DOACCESSKEYEXAMPLE")
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(key.name(), "backups");
+    assert!(key.secret_key().is_none());
+    assert_timestamp(key.created_at(), "2024-03-01T12:00:00Z");
+}
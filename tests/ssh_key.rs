@@ -71,7 +71,7 @@ fn update_produces_correct_request() {
     let correct_url = format!("https://api.digitalocean.com/v2/account/keys/{}", key_id);
     let name = "new name";
 
-    let req: Request<Update, SshKey> = SshKey::update(key_id).name(name);
+    let req: Request<Update, SshKey> = SshKey::update(key_id, name);
     info!("{:#?}", req);
 
     assert_eq!(req.url().as_str(), correct_url);
@@ -96,3 +96,32 @@ fn delete_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), Value::Null);
 }
+
+#[test]
+fn fingerprint_produces_correct_requests() {
+    before();
+
+    let fingerprint = "3b:16:bf:e4:8b:00:8b:b8:59:8c:a9:d3:f0:19:45:fa";
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/account/keys/{}",
+        fingerprint
+    );
+
+    let req: Request<Get, SshKey> = SshKey::get(fingerprint);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+
+    let req: Request<Update, SshKey> = SshKey::update(fingerprint, "renamed");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), json!({ "name": "renamed" }));
+
+    let req: Request<Delete, ()> = SshKey::delete(fingerprint);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
@@ -10,8 +10,11 @@ mod utils;
 
 use serde_json::Value;
 
+use digitalocean::api::tag_fields::Resource;
 use digitalocean::api::Tag;
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::{Create, Delete, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
 use crate::utils::before;
@@ -22,7 +25,7 @@ fn list_produces_correct_request() {
 
     let correct_url = "https://api.digitalocean.com/v2/tags";
 
-    let req: Request<List, Tag> = Tag::list();
+    let req: Request<List, Vec<Tag>> = Tag::list();
     info!("{:#?}", req);
 
     assert_eq!(req.url().as_str(), correct_url);
@@ -133,3 +136,76 @@ fn remove_resources_produces_correct_request() {
         })
     );
 }
+
+#[test]
+fn tag_resources_produces_correct_request() {
+    before();
+
+    let tag = "awesome";
+    let correct_url = format!("https://api.digitalocean.com/v2/tags/{}/resources", tag);
+    let resources = vec![
+        Resource::new("9569411", "droplet"),
+        ("7555620", "image").into(),
+        Resource::new("3d80cb72-342b-4aaa-b92e-4e4abb24a933", "volume"),
+    ];
+    let body = json!({
+        "resources": [
+            { "resource_id": "9569411", "resource_type": "droplet" },
+            { "resource_id": "7555620", "resource_type": "image" },
+            { "resource_id": "3d80cb72-342b-4aaa-b92e-4e4abb24a933", "resource_type": "volume" },
+        ],
+    });
+
+    let req: Request<Create, ()> = Tag::get(tag).tag_resources(&resources);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), body);
+
+    let req: Request<Delete, ()> = Tag::get(tag).untag_resources(&resources);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), body);
+}
+
+#[test]
+fn get_parses_resource_counts() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/tags/awesome",
+        StatusCode::OK,
+        json!({
+            "tag": {
+                "name": "awesome",
+                "resources": {
+                    "count": 3,
+                    "last_tagged_uri": "https://api.digitalocean.com/v2/images/7555620",
+                    "droplets": {
+                        "count": 1,
+                        "last_tagged_uri": "https://api.digitalocean.com/v2/droplets/3164444",
+                    },
+                    "images": {
+                        "count": 2,
+                        "last_tagged_uri": "https://api.digitalocean.com/v2/images/7555620",
+                    },
+                    "volumes": { "count": 0 },
+                    "volume_snapshots": { "count": 0 },
+                    "databases": { "count": 0 },
+                },
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let tag = Tag::get("awesome").execute(&client).unwrap();
+
+    let resources = tag.resources();
+    assert_eq!(resources.count, 3);
+    assert_eq!(resources.droplets.count, 1);
+    assert_eq!(resources.images.count, 2);
+    assert_eq!(resources.volumes.count, 0);
+    assert_eq!(resources.volumes.last_tagged_uri, None);
+}
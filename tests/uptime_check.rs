@@ -0,0 +1,289 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::uptime_check_fields::RegionStatus;
+use digitalocean::api::{UptimeAlert, UptimeCheck, UptimeCheckState};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::before;
+
+const CHECK_ID: &str = "5a4981aa-9653-4bd1-bef5-d6bff52042e4";
+const ALERT_ID: &str = "17f0f0ae-b7e5-4ef6-86e3-aa569db58284";
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/uptime/checks";
+
+    let req: Request<List, Vec<UptimeCheck>> = UptimeCheck::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/uptime/checks";
+
+    let req: Request<Create, UptimeCheck> = UptimeCheck::create(
+        "Landing page",
+        "https",
+        "https://www.landingpage.com",
+        &["us_east", "eu_west"],
+    )
+    .enabled(false);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "Landing page",
+            "type": "https",
+            "target": "https://www.landingpage.com",
+            "regions": ["us_east", "eu_west"],
+            "enabled": false,
+        })
+    );
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let correct_url = format!("https://api.digitalocean.com/v2/uptime/checks/{}", CHECK_ID);
+
+    let req: Request<Get, UptimeCheck> = UptimeCheck::get(CHECK_ID);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn update_produces_correct_request() {
+    before();
+
+    let correct_url = format!("https://api.digitalocean.com/v2/uptime/checks/{}", CHECK_ID);
+
+    let req: Request<Update, UptimeCheck> = UptimeCheck::update(CHECK_ID)
+        .name("Renamed")
+        .regions(["us_west"]);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "Renamed",
+            "regions": ["us_west"],
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let correct_url = format!("https://api.digitalocean.com/v2/uptime/checks/{}", CHECK_ID);
+
+    let req: Request<Delete, ()> = UptimeCheck::delete(CHECK_ID);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn state_produces_correct_request() {
+    before();
+
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/uptime/checks/{}/state",
+        CHECK_ID
+    );
+
+    let req: Request<Get, UptimeCheckState> = UptimeCheck::get(CHECK_ID).state();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn alerts_produce_correct_requests() {
+    before();
+
+    let alerts_url = format!(
+        "https://api.digitalocean.com/v2/uptime/checks/{}/alerts",
+        CHECK_ID
+    );
+    let alert_url = format!("{}/{}", alerts_url, ALERT_ID);
+
+    let req: Request<List, Vec<UptimeAlert>> = UptimeCheck::get(CHECK_ID).alerts();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), alerts_url);
+    assert_eq!(*req.body(), Value::Null);
+
+    let req: Request<Get, UptimeAlert> = UptimeCheck::get(CHECK_ID).alerts().get(ALERT_ID);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), alert_url);
+    assert_eq!(*req.body(), Value::Null);
+
+    let req: Request<Update, UptimeAlert> = UptimeCheck::get(CHECK_ID)
+        .alerts()
+        .update(ALERT_ID)
+        .threshold(500);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), alert_url);
+    assert_eq!(*req.body(), json!({ "threshold": 500 }));
+
+    let req: Request<Delete, ()> = UptimeCheck::get(CHECK_ID).alerts().delete(ALERT_ID);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), alert_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_alert_produces_correct_request() {
+    before();
+
+    let correct_url = format!(
+        "https://api.digitalocean.com/v2/uptime/checks/{}/alerts",
+        CHECK_ID
+    );
+
+    let req: Request<Create, UptimeAlert> = UptimeCheck::get(CHECK_ID)
+        .alerts()
+        .create("Landing page slow", "latency", "2m")
+        .threshold(300)
+        .comparison("greater_than")
+        .email(["bob@example.com"])
+        .slack((
+            "Production Alerts",
+            "https://hooks.slack.com/services/T1234567/AAAAAAAA",
+        ));
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "Landing page slow",
+            "type": "latency",
+            "period": "2m",
+            "threshold": 300,
+            "comparison": "greater_than",
+            "notifications": {
+                "email": ["bob@example.com"],
+                "slack": [{
+                    "channel": "Production Alerts",
+                    "url": "https://hooks.slack.com/services/T1234567/AAAAAAAA",
+                }],
+            },
+        })
+    );
+}
+
+#[test]
+fn state_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        format!("/v2/uptime/checks/{}/state", CHECK_ID),
+        StatusCode::OK,
+        json!({
+            "state": {
+                "regions": {
+                    "us_east": {
+                        "status": "UP",
+                        "status_changed_at": "2022-03-17T22:28:51Z",
+                        "thirty_day_uptime_percentage": 97.99,
+                    },
+                    "eu_west": {
+                        "status": "DOWN",
+                        "status_changed_at": "2022-03-17T22:28:51Z",
+                        "thirty_day_uptime_percentage": 97.99,
+                    },
+                },
+                "previous_outage": {
+                    "region": "us_east",
+                    "started_at": "2022-03-17T18:04:55Z",
+                    "ended_at": "2022-03-17T18:06:55Z",
+                    "duration_seconds": 120,
+                },
+            }
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let state = UptimeCheck::get(CHECK_ID).state().execute(&client).unwrap();
+
+    assert_eq!(state.regions()["us_east"].status, "UP");
+    assert_eq!(state.regions()["eu_west"].status, "DOWN");
+    assert_eq!(state.regions()["eu_west"].status, RegionStatus::Down);
+    assert_eq!(
+        state.previous_outage().as_ref().unwrap().duration_seconds,
+        120
+    );
+}
+
+#[test]
+fn alert_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        format!("/v2/uptime/checks/{}/alerts/{}", CHECK_ID, ALERT_ID),
+        StatusCode::OK,
+        json!({
+            "alert": {
+                "id": ALERT_ID,
+                "name": "Landing page degraded performance",
+                "type": "latency",
+                "threshold": 300,
+                "comparison": "greater_than",
+                "notifications": {
+                    "email": ["bob@example.com"],
+                    "slack": [{
+                        "channel": "Production Alerts",
+                        "url": "https://hooks.slack.com/services/T1234567/AAAAAAAA",
+                    }],
+                },
+                "period": "2m",
+            }
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let alert = UptimeCheck::get(CHECK_ID)
+        .alerts()
+        .get(ALERT_ID)
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(alert.kind(), "latency");
+    assert_eq!(*alert.threshold(), Some(300));
+    assert_eq!(alert.notifications().slack[0].channel, "Production Alerts");
+}
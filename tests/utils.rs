@@ -1,8 +1,17 @@
 extern crate dotenv;
 extern crate env_logger;
 
+use chrono::{DateTime, Utc};
+
 pub fn before() {
     // Setup for tests
     dotenv::dotenv().ok();
     env_logger::try_init().ok();
 }
+
+/// Asserts that `timestamp` was parsed from the RFC 3339 `expected`.
+#[allow(dead_code)]
+pub fn assert_timestamp(timestamp: &DateTime<Utc>, expected: &str) {
+    let expected = DateTime::parse_from_rfc3339(expected).unwrap();
+    assert_eq!(*timestamp, expected.with_timezone(&Utc));
+}
@@ -9,12 +9,15 @@ extern crate url_serde;
 mod utils;
 
 use serde_json::Value;
+use std::collections::HashMap;
 
 use digitalocean::api::{Snapshot, Volume};
+use digitalocean::client::{Method, MockClient, StatusCode};
 use digitalocean::method::{Create, Delete, Get, List};
+use digitalocean::prelude::*;
 use digitalocean::request::Request;
 
-use crate::utils::before;
+use crate::utils::{assert_timestamp, before};
 
 #[test]
 fn list_produces_correct_request() {
@@ -60,6 +63,29 @@ fn create_produces_correct_request() {
     );
 }
 
+#[test]
+fn create_from_snapshot_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/volumes";
+    let (name, snapshot_id, region) = ("bear", "fbe805e8-866b-11e6-96bf-000f53315a41", "tor1");
+
+    let req: Request<Create, Volume> =
+        Volume::create_from_snapshot(name, snapshot_id, region).description("restored");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": name,
+            "snapshot_id": snapshot_id,
+            "region": region,
+            "description": "restored",
+        })
+    );
+}
+
 #[test]
 fn get_produces_correct_request() {
     before();
@@ -85,7 +111,7 @@ fn get_by_name_produces_correct_request() {
         name, region
     );
 
-    let req: Request<Get, Volume> = Volume::get_by_name(name, region);
+    let req: Request<List, Vec<Volume>> = Volume::get_by_name(name, region);
     info!("{:#?}", req);
 
     assert_eq!(req.url().as_str(), correct_url);
@@ -158,3 +184,149 @@ fn snapshot_produces_correct_request() {
     assert_eq!(req.url().as_str(), correct_url);
     assert_eq!(*req.body(), json!({ "name": snapshot_name }));
 }
+
+#[test]
+fn snapshot_parses_response() {
+    before();
+
+    let volume_id = "82a48a18-873f-11e6-96bf-000f53315a41";
+    let mock = MockClient::new().respond(
+        Method::POST,
+        format!("/v2/volumes/{}/snapshots", volume_id),
+        StatusCode::CREATED,
+        json!({
+            "snapshot": {
+                "id": "8fa70202-873f-11e6-8b68-000f533176b1",
+                "name": "big-data-snapshot1475261774",
+                "regions": ["nyc1"],
+                "created_at": "2016-09-30T18:56:14Z",
+                "resource_id": volume_id,
+                "resource_type": "volume",
+                "min_disk_size": 10,
+                "size_gigabytes": 0,
+                "tags": ["aninterestingtag"],
+            },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let snapshot = Volume::get(volume_id)
+        .snapshot("big-data-snapshot1475261774")
+        .tags(["aninterestingtag"])
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(snapshot.resource_id(), volume_id);
+    assert_eq!(snapshot.tags(), &["aninterestingtag"]);
+    assert_eq!(
+        mock.requests()[0].body().as_ref().unwrap()["tags"],
+        json!(["aninterestingtag"])
+    );
+}
+
+#[test]
+fn get_by_name_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/volumes",
+        StatusCode::OK,
+        json!({
+            "volumes": [
+                {
+                    "id": "506f78a4-e098-11e5-ad9f-000f53306ae1",
+                    "region": {
+                        "name": "New York 1",
+                        "slug": "nyc1",
+                        "sizes": ["s-1vcpu-1gb"],
+                        "features": ["private_networking"],
+                        "available": true,
+                    },
+                    "droplet_ids": [],
+                    "name": "example",
+                    "description": "Block store for examples",
+                    "size_gigabytes": 10,
+                    "created_at": "2016-03-02T17:00:49Z",
+                },
+            ],
+            "links": {},
+            "meta": { "total": 1 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock.clone()).unwrap();
+
+    let volumes = Volume::get_by_name("example", "nyc1")
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(volumes.len(), 1);
+    assert_eq!(volumes[0].name(), "example");
+    assert_timestamp(volumes[0].created_at(), "2016-03-02T17:00:49Z");
+    assert_eq!(
+        mock.requests()[0].url().query(),
+        Some("name=example&region=nyc1&per_page=200")
+    );
+}
+
+#[test]
+fn round_trips_through_serde() {
+    before();
+
+    let fixture = json!({
+        "id": "506f78a4-e098-11e5-ad9f-000f53306ae1",
+        "region": {
+            "name": "New York 1",
+            "slug": "nyc1",
+            "sizes": ["s-1vcpu-1gb"],
+            "features": ["private_networking"],
+            "available": true,
+        },
+        "droplet_ids": [123],
+        "name": "example",
+        "description": "Block store for examples",
+        "size_gigabytes": 10,
+        "created_at": "2016-03-02T17:00:49Z",
+    });
+
+    let parsed: Volume = serde_json::from_value(fixture.clone()).unwrap();
+    let serialized = serde_json::to_value(&parsed).unwrap();
+    let reparsed: Volume = serde_json::from_value(serialized.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(&reparsed).unwrap(), serialized);
+    assert_eq!(serialized["id"], fixture["id"]);
+    assert_eq!(serialized["droplet_ids"], fixture["droplet_ids"]);
+    assert_eq!(serialized["created_at"], fixture["created_at"]);
+}
+
+#[test]
+fn volumes_may_key_maps() {
+    before();
+
+    let volume = |name: &str| -> Volume {
+        serde_json::from_value(json!({
+            "id": "506f78a4-e098-11e5-ad9f-000f53306ae1",
+            "region": {
+                "name": "New York 1",
+                "slug": "nyc1",
+                "sizes": ["s-1vcpu-1gb"],
+                "features": ["private_networking"],
+                "available": true,
+            },
+            "droplet_ids": [],
+            "name": name,
+            "description": "Block store for examples",
+            "size_gigabytes": 10,
+            "created_at": "2016-03-02T17:00:49Z",
+        }))
+        .unwrap()
+    };
+
+    let mut attached = HashMap::new();
+    attached.insert(volume("example"), 123);
+    // A renamed volume is still the same volume.
+    attached.insert(volume("renamed"), 456);
+
+    assert_eq!(attached.len(), 1);
+    assert_eq!(attached[&volume("example")], 456);
+}
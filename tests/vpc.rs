@@ -0,0 +1,154 @@
+extern crate digitalocean;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+extern crate url_serde;
+
+mod utils;
+
+use serde_json::Value;
+
+use digitalocean::api::{Vpc, VpcMember};
+use digitalocean::client::{Method, MockClient, StatusCode};
+use digitalocean::method::{Create, Delete, Get, List, Update};
+use digitalocean::prelude::*;
+use digitalocean::request::Request;
+
+use crate::utils::{assert_timestamp, before};
+
+#[test]
+fn list_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/vpcs";
+
+    let req: Request<List, Vec<Vpc>> = Vpc::list();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn get_produces_correct_request() {
+    before();
+
+    let vpc_id = "5a4981aa-9653-4bd1-bef5-d6bff52042e4";
+    let correct_url = format!("https://api.digitalocean.com/v2/vpcs/{}", vpc_id);
+
+    let req: Request<Get, Vpc> = Vpc::get(vpc_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn create_produces_correct_request() {
+    before();
+
+    let correct_url = "https://api.digitalocean.com/v2/vpcs";
+
+    let req: Request<Create, Vpc> = Vpc::create("env.prod-vpc", "nyc1")
+        .description("VPC for production environment")
+        .ip_range("10.10.10.0/24");
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "env.prod-vpc",
+            "region": "nyc1",
+            "description": "VPC for production environment",
+            "ip_range": "10.10.10.0/24",
+        })
+    );
+}
+
+#[test]
+fn update_produces_correct_request() {
+    before();
+
+    let vpc_id = "5a4981aa-9653-4bd1-bef5-d6bff52042e4";
+    let correct_url = format!("https://api.digitalocean.com/v2/vpcs/{}", vpc_id);
+
+    let req: Request<Update, Vpc> = Vpc::update(vpc_id, "env.staging-vpc").default(true);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(
+        *req.body(),
+        json!({
+            "name": "env.staging-vpc",
+            "default": true,
+        })
+    );
+}
+
+#[test]
+fn delete_produces_correct_request() {
+    before();
+
+    let vpc_id = "5a4981aa-9653-4bd1-bef5-d6bff52042e4";
+    let correct_url = format!("https://api.digitalocean.com/v2/vpcs/{}", vpc_id);
+
+    let req: Request<Delete, ()> = Vpc::delete(vpc_id);
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn members_produces_correct_request() {
+    before();
+
+    let vpc_id = "5a4981aa-9653-4bd1-bef5-d6bff52042e4";
+    let correct_url = format!("https://api.digitalocean.com/v2/vpcs/{}/members", vpc_id);
+
+    let req: Request<List, Vec<VpcMember>> = Vpc::get(vpc_id).members();
+    info!("{:#?}", req);
+
+    assert_eq!(req.url().as_str(), correct_url);
+    assert_eq!(*req.body(), Value::Null);
+}
+
+#[test]
+fn members_parses_response() {
+    before();
+
+    let mock = MockClient::new().respond(
+        Method::GET,
+        "/v2/vpcs/5a4981aa-9653-4bd1-bef5-d6bff52042e4/members",
+        StatusCode::OK,
+        json!({
+            "members": [
+                {
+                    "urn": "do:loadbalancer:fb294d78-d193-4cb2-8737-ea620993591b",
+                    "name": "nyc1-load-balancer-01",
+                    "created_at": "2020-03-13T19:30:48Z",
+                },
+                {
+                    "urn": "do:droplet:13457723",
+                    "name": "ubuntu-s-1vcpu-1gb-nyc1-01",
+                    "created_at": "2020-03-13T19:29:20Z",
+                },
+            ],
+            "links": {},
+            "meta": { "total": 2 },
+        }),
+    );
+    let client = DigitalOcean::with_client("token", mock).unwrap();
+
+    let members = Vpc::get("5a4981aa-9653-4bd1-bef5-d6bff52042e4")
+        .members()
+        .execute(&client)
+        .unwrap();
+
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[1].urn(), "do:droplet:13457723");
+    assert_timestamp(members[1].created_at(), "2020-03-13T19:29:20Z");
+}